@@ -0,0 +1,57 @@
+use std::{
+    hint::black_box,
+    process,
+    time::{Duration, Instant},
+};
+
+use fps_timer::Timer;
+
+/// frames measured per run
+const FRAMES: u32 = 200_000;
+/// runs per measurement, the fastest one is kept to filter out noise
+const RUNS: u32 = 5;
+
+/// Most clock reads a frame may cost without the instrumentation
+/// features (`stats`, `hooks`, `scopes` and `sinks`), pinning the
+/// contract that disabled instrumentation does no work per frame.
+/// Checked by `cargo bench --bench hot_path --no-default-features --features std`.
+const MAX_CLOCK_READS: f64 = 4.;
+
+/// fastest average duration of `f` over [`RUNS`] runs of [`FRAMES`] calls
+fn fastest(mut f: impl FnMut()) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..FRAMES {
+                f();
+            }
+            start.elapsed() / FRAMES
+        })
+        .min()
+        .unwrap_or_default()
+}
+
+/// Cost of `Timer::frame` on an uncapped timer relative to reading
+/// the clock, which every frame has to do once.
+fn main() {
+    let clock = fastest(|| {
+        black_box(Instant::now());
+    });
+    let mut timer = Timer::default().fps(0.);
+    let frame = fastest(|| {
+        black_box(timer.frame());
+    });
+
+    let ratio = frame.as_secs_f64() / clock.as_secs_f64();
+    println!("frame: {frame:?}, clock read: {clock:?} ({ratio:.1} clock reads per frame)");
+    let instrumented = cfg!(any(
+        feature = "stats",
+        feature = "hooks",
+        feature = "scopes",
+        feature = "sinks"
+    ));
+    if !instrumented && ratio > MAX_CLOCK_READS {
+        eprintln!("a frame costs more than {MAX_CLOCK_READS} clock reads without instrumentation");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,57 @@
+// TypeScript wrapper around the `WebTimer` of the `wasm` feature,
+// exported to JavaScript with wasm-bindgen. Generate `./pkg` with
+//   cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib
+//   wasm-bindgen --target web --out-dir bindings/web/pkg target/wasm32-unknown-unknown/release/fps_timer.wasm
+import { WebTimer } from "./pkg/fps_timer";
+
+/** statistics of a logging interval by name, durations in nanoseconds */
+export type Log = Record<string, number>;
+
+function parseLog(line: string): Log {
+  return Object.fromEntries(
+    line.split(" ").map((pair) => {
+      const [key, value] = pair.split("=", 2);
+      return [key, Number(value)];
+    }),
+  );
+}
+
+/** frame pacing timer whose `frame()` resolves once the next frame is due */
+export class Timer {
+  private inner: WebTimer;
+
+  /** @param fps framerate target, `0` for uncapped */
+  constructor(fps: number) {
+    this.inner = new WebTimer(fps, performance.now());
+  }
+
+  /** waits for the next frame and resolves to the frametime in milliseconds */
+  async frame(): Promise<number> {
+    const delay = this.inner.delay_ms(performance.now());
+    if (delay > 0) {
+      await new Promise((resolve) => setTimeout(resolve, delay));
+    }
+    return this.inner.frame(performance.now());
+  }
+
+  /** sets the framerate target, `0` for uncapped */
+  setFps(fps: number): void {
+    this.inner.set_fps(fps);
+  }
+
+  /** sets the duration of a logging interval in milliseconds, 1 second by default */
+  setLogInterval(intervalMs: number): void {
+    this.inner.set_log_interval_ms(intervalMs);
+  }
+
+  /** statistics of the last logging interval, `undefined` until it has ended */
+  log(): Log | undefined {
+    const line = this.inner.log(performance.now());
+    return line === undefined ? undefined : parseLog(line);
+  }
+
+  /** releases the memory of the timer */
+  free(): void {
+    this.inner.free();
+  }
+}
@@ -0,0 +1,18 @@
+use std::env;
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let frames = args.get(1).and_then(|arg| arg.parse().ok()).unwrap_or(500);
+    // including input polling and haptics rates, see Timer::high_rate
+    let rates = [30., 60., 144., 240., 1000., 4000., 8000.];
+
+    // machine readable CSV report on stdout
+    let report = fps_timer::compare_sleepers(&rates, frames);
+    print!("{report}");
+
+    // wakeup error histograms on stderr
+    for result in report.results() {
+        eprintln!("{} @ {}fps", result.sleeper().name(), result.fps());
+        eprintln!("{}", result.wakeup_error());
+    }
+}
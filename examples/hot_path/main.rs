@@ -0,0 +1,27 @@
+use std::{env, time::Instant};
+
+use fps_timer::Timer;
+
+/// Measures the overhead of `Timer::frame` on an uncapped timer.
+///
+/// Compare `cargo run --release --example hot_path` with
+/// `cargo run --release --example hot_path --no-default-features`
+/// to see the cost of the instrumentation.
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let frames: u32 = args
+        .get(1)
+        .and_then(|arg| arg.parse().ok())
+        .unwrap_or(1_000_000);
+
+    let mut timer = Timer::default().fps(0.);
+    let start = Instant::now();
+    for _ in 0..frames {
+        timer.frame();
+    }
+    let elapsed = start.elapsed();
+    println!(
+        "{frames} frames in {elapsed:?} ({:?} per frame)",
+        elapsed / frames.max(1)
+    );
+}
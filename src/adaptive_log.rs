@@ -0,0 +1,110 @@
+use std::time::Duration;
+
+use crate::{FrameTimeStats, Timer, MAX_INTERVAL};
+
+/// increase of the 99th percentile frametime over the previous
+/// interval that counts as rising
+const P99_RISE: f64 = 1.25;
+
+/// bounds of the logging interval and the state of its adaptation
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct AdaptiveLog {
+    /// shortest interval, used while pacing is unstable
+    min: Duration,
+    /// longest interval, used while pacing is stable
+    max: Duration,
+    /// 99th percentile frametime of the previous interval
+    previous_p99: Option<Duration>,
+}
+
+impl AdaptiveLog {
+    /// the interval following an interval of `current` with `stats`
+    fn next_interval(&mut self, current: Duration, stats: &FrameTimeStats) -> Duration {
+        let p99 = stats.p99();
+        let rising = self
+            .previous_p99
+            .is_some_and(|previous| p99.as_secs_f64() > previous.as_secs_f64() * P99_RISE);
+        self.previous_p99 = Some(p99);
+        let interval = match stats.hitches() > 0 || rising {
+            true => current / 2,
+            false => current.saturating_mul(2),
+        };
+        interval.clamp(self.min, self.max)
+    }
+}
+
+impl Timer {
+    /// Adapts the logging interval to the pacing: it is halved after
+    /// every interval with hitches or a rising 99th percentile
+    /// frametime, and doubled after every stable interval.
+    ///
+    /// Logs are thus frequent exactly while problems occur and rare
+    /// during normal operation, keeping telemetry detailed without
+    /// flooding sinks. The interval starts at [`Self::log_interval`]
+    /// clamped to the bounds. The current interval is returned by
+    /// [`Self::current_log_interval`].
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Arguments
+    /// * `min` - shortest logging interval
+    /// * `max` - longest logging interval
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let ms = Duration::from_millis;
+    /// let mut timer = Timer::default()
+    ///     .fps(0.)
+    ///     .log_interval(ms(400))
+    ///     .adaptive_log_interval(ms(100), ms(1600));
+    /// let start = timer.timebase_epoch();
+    /// let mut now = start;
+    /// let mut run = |timer: &mut Timer, frame_time: Duration, frames: u32| {
+    ///     for _ in 0..frames {
+    ///         now += frame_time;
+    ///         timer.frame_at(now);
+    ///         timer.log();
+    ///     }
+    /// };
+    ///
+    /// // stable pacing lengthens the interval up to the maximum
+    /// run(&mut timer, ms(10), 300);
+    /// assert_eq!(timer.current_log_interval(), ms(1600));
+    ///
+    /// // hitches shorten it down to the minimum
+    /// for _ in 0..40 {
+    ///     run(&mut timer, ms(10), 3);
+    ///     run(&mut timer, ms(100), 1);
+    /// }
+    /// assert_eq!(timer.current_log_interval(), ms(100));
+    /// # }
+    /// ```
+    pub fn adaptive_log_interval(mut self, min: Duration, max: Duration) -> Self {
+        let max = max.min(MAX_INTERVAL);
+        let min = min.min(max);
+        self.adaptive_log = Some(AdaptiveLog {
+            min,
+            max,
+            previous_p99: None,
+        });
+        self.log_interval = self.log_interval.clamp(min, max);
+        self.log_target = self.next_log_target(self.previous);
+        self
+    }
+
+    /// adapts the logging interval to the `stats` of the interval
+    /// that just ended, if enabled
+    pub(crate) fn adapt_log_interval(&mut self, stats: &FrameTimeStats) {
+        if let Some(adaptive) = &mut self.adaptive_log {
+            self.log_interval = adaptive.next_interval(self.log_interval, stats);
+        }
+    }
+}
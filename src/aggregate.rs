@@ -0,0 +1,207 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[cfg(feature = "stats")]
+use crate::LatenessBreakdown;
+use crate::{FrameTimeStats, Log, LogSink, WaitTime};
+
+/// shared state of a [`StatsAggregator`]
+#[derive(Default)]
+struct Aggregate {
+    /// name and pending log of every registered thread
+    threads: Vec<(String, Option<Log>)>,
+    /// destinations of the process-level reports
+    sinks: Vec<Box<dyn LogSink>>,
+}
+
+impl Aggregate {
+    /// delivers the pending logs and their combination to all sinks
+    fn publish(&mut self) {
+        let sections: Vec<Log> = self
+            .threads
+            .iter_mut()
+            .filter_map(|(name, log)| {
+                let mut log = log.take()?;
+                log.thread = Some(name.clone());
+                Some(log)
+            })
+            .collect();
+        let combined = Log::combine(&sections);
+        for sink in &mut self.sinks {
+            for section in &sections {
+                sink.log(section);
+            }
+            sink.log(&combined);
+        }
+    }
+}
+
+/// Merges the interval statistics of timers running on different
+/// threads (e.g. render, audio and network) into process-level reports.
+///
+/// Every thread attaches a [`Self::thread`] sink to its own [`crate::Timer`].
+/// Once all threads delivered a log, or a thread delivers its next log
+/// before the others caught up, the aggregator publishes a report to its
+/// own [`LogSink`]s: one log per thread, labelled with the thread name
+/// (see [`Log::thread`]), followed by the combined log of all threads,
+/// which has no thread name.
+///
+/// Requires the `sinks` feature.
+///
+/// # Example
+/// ```rust
+/// use std::{sync::mpsc, thread, time::Duration};
+/// use fps_timer::{Log, StatsAggregator, Timer};
+///
+/// let (tx, rx) = mpsc::channel();
+/// let aggregator = StatsAggregator::new()
+///     .log_sink(move |log: &Log| tx.send(log.clone()).unwrap());
+/// let threads: Vec<_> = [("render", 500.), ("audio", 1000.)]
+///     .into_iter()
+///     .map(|(name, fps)| {
+///         let mut timer = Timer::default()
+///             .fps(fps)
+///             .log_interval(Duration::from_millis(10))
+///             .log_sink(aggregator.thread(name));
+///         thread::spawn(move || {
+///             for _ in 0..20 {
+///                 timer.frame();
+///             }
+///         })
+///     })
+///     .collect();
+/// threads.into_iter().for_each(|thread| thread.join().unwrap());
+///
+/// let report: Vec<Log> = rx.try_iter().collect();
+/// assert!(report.iter().any(|log| log.thread() == Some("render")));
+/// let combined = report.iter().find(|log| log.thread().is_none()).unwrap();
+/// assert!(combined.frame_time_stats().frames() > 0);
+/// ```
+#[derive(Clone, Default)]
+pub struct StatsAggregator {
+    shared: Arc<Mutex<Aggregate>>,
+}
+
+impl StatsAggregator {
+    /// Creates an aggregator without threads and sinks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attaches a [`LogSink`] receiving the process-level reports.
+    /// Multiple sinks can be attached.
+    ///
+    /// # Arguments
+    /// * `sink` - destination for process-level reports
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) aggregator
+    pub fn log_sink(self, sink: impl LogSink + 'static) -> Self {
+        self.lock().sinks.push(Box::new(sink));
+        self
+    }
+
+    /// Registers a thread named `name`, returning the sink
+    /// to attach to its timer with [`crate::Timer::log_sink`].
+    pub fn thread(&self, name: impl Into<String>) -> ThreadSink {
+        let mut aggregate = self.lock();
+        aggregate.threads.push((name.into(), None));
+        ThreadSink {
+            shared: self.shared.clone(),
+            index: aggregate.threads.len() - 1,
+        }
+    }
+
+    /// Publishes the logs received so far without waiting
+    /// for the remaining threads, e.g. at shutdown.
+    pub fn flush(&self) {
+        let mut aggregate = self.lock();
+        if aggregate.threads.iter().any(|(_, log)| log.is_some()) {
+            aggregate.publish();
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Aggregate> {
+        self.shared.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// [`LogSink`] of a single thread of a [`StatsAggregator`],
+/// as returned by [`StatsAggregator::thread`]
+pub struct ThreadSink {
+    shared: Arc<Mutex<Aggregate>>,
+    /// index of the thread in the aggregate
+    index: usize,
+}
+
+impl LogSink for ThreadSink {
+    fn log(&mut self, log: &Log) {
+        let mut aggregate = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        // don't let a slow thread hold back the report
+        if aggregate.threads[self.index].1.is_some() {
+            aggregate.publish();
+        }
+        aggregate.threads[self.index].1 = Some(log.clone());
+        if aggregate.threads.iter().all(|(_, log)| log.is_some()) {
+            aggregate.publish();
+        }
+    }
+
+    fn flush(&mut self, timeout: Duration) {
+        let mut aggregate = self.shared.lock().unwrap_or_else(|e| e.into_inner());
+        if aggregate.threads[self.index].1.is_some() {
+            aggregate.publish();
+        }
+        for sink in &mut aggregate.sinks {
+            sink.flush(timeout);
+        }
+    }
+}
+
+impl Log {
+    /// combined log of concurrent `sections`
+    fn combine(sections: &[Log]) -> Log {
+        let frames = |log: &Log| log.stats.frames() as f64;
+        let total: f64 = sections.iter().map(frames).sum();
+        let weighted = |value: fn(&Log) -> Duration| match total {
+            0. => Duration::ZERO,
+            _ => Duration::from_secs_f64(
+                sections
+                    .iter()
+                    .map(|log| value(log).as_secs_f64() * frames(log))
+                    .sum::<f64>()
+                    / total,
+            ),
+        };
+        let parts: Vec<(FrameTimeStats, Duration)> = sections
+            .iter()
+            .map(|log| (log.stats, log.delta_avg))
+            .collect();
+        let mut wait_time = WaitTime::default();
+        #[cfg(feature = "stats")]
+        let mut lateness = LatenessBreakdown::default();
+        for log in sections {
+            wait_time.add(log.wait_time);
+            #[cfg(feature = "stats")]
+            lateness.add(&log.lateness);
+        }
+        Log {
+            delta_avg: weighted(|log| log.delta_avg),
+            smoothed_delta: weighted(|log| log.smoothed_delta),
+            stats: FrameTimeStats::merge(&parts),
+            wait_time,
+            profile: None,
+            scopes: sections.iter().flat_map(|log| log.scopes.clone()).collect(),
+            thread: None,
+            #[cfg(feature = "stats")]
+            long_frame: sections
+                .iter()
+                .filter_map(|log| log.long_frame.clone())
+                .max_by_key(|long_frame| long_frame.work()),
+            #[cfg(feature = "stats")]
+            lateness,
+        }
+    }
+}
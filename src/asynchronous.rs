@@ -0,0 +1,359 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        mpsc::{self, Receiver, RecvTimeoutError, Sender},
+        Arc, Mutex, OnceLock,
+    },
+    task::{Context, Poll, Waker},
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{Pacing, Precision, Timer};
+
+/// Source of asynchronous sleeps used by [`Timer::frame_async`].
+///
+/// Implement this trait to plug in the timer of an async runtime.
+/// Sleepers for tokio, smol and futures-timer are available behind
+/// the `tokio`, `smol` and `futures-timer` features.
+///
+/// # Example
+/// ```rust
+/// use std::{future::Ready, time::Instant};
+/// use fps_timer::AsyncSleeper;
+///
+/// /// never sleeps, e.g. for tests
+/// struct NoSleep;
+///
+/// impl AsyncSleeper for NoSleep {
+///     type Sleep = Ready<()>;
+///
+///     fn sleep_until(&self, _deadline: Instant) -> Self::Sleep {
+///         std::future::ready(())
+///     }
+/// }
+/// ```
+pub trait AsyncSleeper {
+    /// future returned by [`Self::sleep_until`]
+    type Sleep: Future<Output = ()>;
+
+    /// Returns a future that completes once `deadline` has passed.
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep;
+}
+
+/// Runtime independent [`AsyncSleeper`] that waits on a helper thread.
+///
+/// All sleeps share a single helper thread, which is started on
+/// first use and wakes the sleeping futures at their deadlines.
+/// Prefer a sleeper backed by the timer of your async runtime
+/// where possible.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadSleeper;
+
+impl AsyncSleeper for ThreadSleeper {
+    type Sleep = ThreadSleep;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        ThreadSleep {
+            deadline,
+            waker: None,
+        }
+    }
+}
+
+/// waker of a sleeping future, cleared once woken or dropped
+type SharedWaker = Arc<Mutex<Option<Waker>>>;
+
+/// sleep registered with the helper thread
+struct Registration {
+    /// instant at which the waker is woken
+    deadline: Instant,
+    /// waker of the future
+    waker: SharedWaker,
+}
+
+/// sends sleeps to the helper thread, starting it on first use
+fn register(registration: Registration) {
+    static SLEEPER: OnceLock<Sender<Registration>> = OnceLock::new();
+    let sender = SLEEPER.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        thread::Builder::new()
+            .name("fps-timer-sleeper".into())
+            .spawn(move || run_sleeper(receiver))
+            .expect("failed to spawn sleeper thread");
+        sender
+    });
+    // the helper thread only exits if it panicked
+    if let Err(mpsc::SendError(registration)) = sender.send(registration) {
+        if let Some(waker) = registration.waker.lock().unwrap().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// wakes the registered sleeps at their deadlines
+fn run_sleeper(receiver: Receiver<Registration>) {
+    let mut sleeps: Vec<Registration> = Vec::new();
+    loop {
+        let now = Instant::now();
+        sleeps.retain(|sleep| {
+            if sleep.deadline > now {
+                return true;
+            }
+            if let Some(waker) = sleep.waker.lock().unwrap().take() {
+                waker.wake();
+            }
+            false
+        });
+        let next = sleeps.iter().map(|sleep| sleep.deadline).min();
+        let received = match next {
+            Some(deadline) => receiver.recv_timeout(deadline.saturating_duration_since(now)),
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match received {
+            Ok(registration) => sleeps.push(registration),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return,
+        }
+    }
+}
+
+/// Future returned by [`ThreadSleeper::sleep_until`]
+#[derive(Debug)]
+pub struct ThreadSleep {
+    /// instant at which the future completes
+    deadline: Instant,
+    /// waker shared with the helper thread, once registered
+    waker: Option<SharedWaker>,
+}
+
+impl Future for ThreadSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        match &self.waker {
+            Some(waker) => {
+                *waker.lock().unwrap() = Some(cx.waker().clone());
+            }
+            None => {
+                let waker = Arc::new(Mutex::new(Some(cx.waker().clone())));
+                register(Registration {
+                    deadline: self.deadline,
+                    waker: waker.clone(),
+                });
+                self.waker = Some(waker);
+            }
+        }
+        Poll::Pending
+    }
+}
+
+impl Drop for ThreadSleep {
+    fn drop(&mut self) {
+        // a cancelled sleep must not wake its task later on
+        if let Some(waker) = &self.waker {
+            waker.lock().unwrap().take();
+        }
+    }
+}
+
+/// [`AsyncSleeper`] backed by the timer of the tokio runtime.
+///
+/// Requires the `tokio` feature.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{Timer, TokioSleeper};
+///
+/// let runtime = tokio::runtime::Builder::new_current_thread()
+///     .enable_time()
+///     .build()
+///     .unwrap();
+/// runtime.block_on(async {
+///     let mut timer = Timer::default().fps(100.);
+///     timer.frame_async(&TokioSleeper).await;
+///     let dt = timer.frame_async(&TokioSleeper).await;
+///     assert!(dt >= Duration::from_millis(9));
+/// });
+/// ```
+#[cfg(feature = "tokio")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TokioSleeper;
+
+#[cfg(feature = "tokio")]
+impl AsyncSleeper for TokioSleeper {
+    type Sleep = tokio::time::Sleep;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        tokio::time::sleep_until(deadline.into())
+    }
+}
+
+/// [`AsyncSleeper`] backed by the timer of smol (`smol::Timer`,
+/// provided by `async-io`).
+///
+/// Requires the `smol` feature.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{SmolSleeper, Timer};
+///
+/// async_io::block_on(async {
+///     let mut timer = Timer::default().fps(100.);
+///     timer.frame_async(&SmolSleeper).await;
+///     let dt = timer.frame_async(&SmolSleeper).await;
+///     assert!(dt >= Duration::from_millis(9));
+/// });
+/// ```
+#[cfg(feature = "smol")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SmolSleeper;
+
+#[cfg(feature = "smol")]
+impl AsyncSleeper for SmolSleeper {
+    type Sleep = SmolSleep;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        SmolSleep {
+            timer: async_io::Timer::at(deadline),
+        }
+    }
+}
+
+/// Future returned by [`SmolSleeper::sleep_until`]
+///
+/// Requires the `smol` feature.
+#[cfg(feature = "smol")]
+#[derive(Debug)]
+pub struct SmolSleep {
+    /// the underlying timer
+    timer: async_io::Timer,
+}
+
+#[cfg(feature = "smol")]
+impl Future for SmolSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        Pin::new(&mut self.timer).poll(cx).map(|_| ())
+    }
+}
+
+/// Runtime independent [`AsyncSleeper`] backed by `futures-timer`.
+///
+/// Requires the `futures-timer` feature.
+///
+/// # Example
+/// ```rust
+/// use std::{future::Future, pin::pin, task::{Context, Poll, Waker}, time::Duration};
+/// use fps_timer::{FuturesTimerSleeper, Timer};
+///
+/// # fn block_on<F: Future>(fut: F) -> F::Output {
+/// #     let mut fut = pin!(fut);
+/// #     let mut cx = Context::from_waker(Waker::noop());
+/// #     loop {
+/// #         if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+/// #             return out;
+/// #         }
+/// #         std::thread::sleep(Duration::from_micros(100));
+/// #     }
+/// # }
+/// let mut timer = Timer::default().fps(100.);
+/// block_on(timer.frame_async(&FuturesTimerSleeper));
+/// let dt = block_on(timer.frame_async(&FuturesTimerSleeper));
+/// assert!(dt >= Duration::from_millis(9));
+/// ```
+#[cfg(feature = "futures-timer")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FuturesTimerSleeper;
+
+#[cfg(feature = "futures-timer")]
+impl AsyncSleeper for FuturesTimerSleeper {
+    type Sleep = futures_timer::Delay;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        futures_timer::Delay::new(deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+impl Timer {
+    /// Asynchronous version of [`Self::frame`].
+    ///
+    /// The bulk of the wait is done through `sleeper`, up to the spin
+    /// margin before the target in high precision mode, after which
+    /// the frame is completed like with [`Self::frame`], including the
+    /// high precision part of the wait if enabled. The wait targets
+    /// the same instant as [`Self::frame`] (including the hard cap,
+    /// the swapchain and quantization) on the clock of the timer, and
+    /// does not sleep on [`crate::Clock::Synthetic`].
+    ///
+    /// # Cancellation safety
+    /// This method is cancellation safe: the frame schedule is only
+    /// updated once the wait has completed. Dropping the future
+    /// mid-wait (e.g. because another branch of a `select!` completed)
+    /// leaves the timer untouched, so the next call waits for the
+    /// same target again.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{future::Future, pin::pin, task::{Context, Poll, Waker}, time::{Duration, Instant}};
+    /// use fps_timer::{Clock, ThreadSleeper, Timer};
+    ///
+    /// # fn block_on<F: Future>(fut: F) -> F::Output {
+    /// #     let mut fut = pin!(fut);
+    /// #     let mut cx = Context::from_waker(Waker::noop());
+    /// #     loop {
+    /// #         if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+    /// #             return out;
+    /// #         }
+    /// #         std::thread::sleep(Duration::from_micros(100));
+    /// #     }
+    /// # }
+    /// let mut timer = Timer::default().fps(50.);
+    /// block_on(timer.frame_async(&ThreadSleeper));
+    ///
+    /// // cancel a frame mid-wait
+    /// {
+    ///     let mut frame = pin!(timer.frame_async(&ThreadSleeper));
+    ///     let mut cx = Context::from_waker(Waker::noop());
+    ///     assert!(frame.as_mut().poll(&mut cx).is_pending());
+    /// }
+    ///
+    /// // the schedule is still intact
+    /// let dt = block_on(timer.frame_async(&ThreadSleeper));
+    /// assert!(dt < Duration::from_millis(40));
+    ///
+    /// // synthetic frames end at their target without sleeping
+    /// let mut simulated = Timer::default().fps(1.).clock(Clock::Synthetic);
+    /// let start = Instant::now();
+    /// assert_eq!(block_on(simulated.frame_async(&ThreadSleeper)), Duration::from_secs(1));
+    /// assert!(start.elapsed() < Duration::from_millis(500));
+    /// ```
+    pub async fn frame_async<S: AsyncSleeper>(&mut self, sleeper: &S) -> Duration {
+        let now = self.clock_now();
+        let waits = self.pacing == Pacing::Internal && !self.skip_next_wait;
+        if waits && !self.is_synthetic() {
+            let (wait_target, _) = self.wait_target(now);
+            // leave the end of the wait to `frame`, which spins in
+            // high precision mode
+            let high_precision = match self.next_precision {
+                Some(precision) => precision != Precision::Sleep,
+                None => self.high_precision,
+            };
+            let sleep_target = match high_precision {
+                true => wait_target.checked_sub(self.effective_spin_margin()),
+                false => Some(wait_target),
+            };
+            if let Some(sleep_target) = sleep_target.filter(|&target| now < target) {
+                sleeper.sleep_until(sleep_target).await;
+            }
+        }
+        self.frame()
+    }
+}
@@ -0,0 +1,120 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// share of the frametime the smoothed oversleep has to exceed
+/// to enable spinning
+const ENABLE_SHARE: f64 = 0.02;
+/// share of the frametime the smoothed oversleep has to fall below
+/// to disable spinning again
+const DISABLE_SHARE: f64 = 0.005;
+/// minimum number of frames between two switches
+const MIN_DWELL: u32 = 60;
+
+/// state of the automatic high precision mode
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct AutoPrecision {
+    /// smoothed oversleep of the sleep phase of recent waits
+    oversleep: Duration,
+    /// frames since the last switch
+    frames: u32,
+}
+
+/// Estimated time the sleep of a wait from `start` until `target`,
+/// which ended at `woke` after spinning for `spinning`, overslept.
+/// [`None`] if the wait did not sleep.
+///
+/// With a `spin_margin`, the sleep is meant to end `spin_margin`
+/// before the target, so the shorter the spin, the later the sleep
+/// ended. This measures the accuracy of sleeping while spinning.
+pub(crate) fn oversleep(
+    start: Instant,
+    target: Instant,
+    woke: Instant,
+    spin_margin: Option<Duration>,
+    spinning: Duration,
+) -> Option<Duration> {
+    let wait = target.saturating_duration_since(start);
+    let late = woke.saturating_duration_since(target);
+    match spin_margin {
+        None if !wait.is_zero() => Some(late),
+        Some(margin) if wait > margin => Some(margin.saturating_sub(spinning) + late),
+        _ => None,
+    }
+}
+
+impl Timer {
+    /// Enables or disables switching [`Self::high_precision`]
+    /// automatically, depending on how accurate sleeping is.
+    ///
+    /// The timer measures how much the thread oversleeps, also while
+    /// spinning, from the time left to spin after waking up. Spinning is
+    /// enabled once the smoothed oversleep exceeds 2% of the frametime
+    /// and disabled again once it falls below 0.5%, with at least 60
+    /// frames between two switches to avoid flapping. Applications thus
+    /// get precision when the sleep of the OS is too coarse for the
+    /// frametime, and don't waste CPU time spinning when it is accurate
+    /// enough. Starts from the configured [`Self::high_precision`].
+    /// Waits with a custom [`crate::WaitStrategy`], in
+    /// [`Self::low_rate`] mode or overridden with
+    /// [`Self::next_frame_precision`] are not measured.
+    ///
+    /// Defaults to `false`
+    ///
+    /// # Arguments
+    /// * `enabled` - whether to switch high precision automatically
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(200.)
+    ///     .high_precision(false)
+    ///     .auto_high_precision(true);
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// // e.g. shown in a debug overlay
+    /// let _spinning = timer.is_high_precision();
+    /// ```
+    pub fn auto_high_precision(mut self, enabled: bool) -> Self {
+        self.auto_precision = enabled.then(AutoPrecision::default);
+        self
+    }
+
+    /// `true` if waits currently spin for the last part of the wait,
+    /// see [`Self::high_precision`] and [`Self::auto_high_precision`]
+    pub fn is_high_precision(&self) -> bool {
+        self.high_precision
+    }
+
+    /// `true` if the sleep accuracy of waits is measured
+    pub(crate) fn measures_oversleep(&self) -> bool {
+        self.auto_precision.is_some()
+    }
+
+    /// switches high precision depending on the `oversleep`
+    /// of the most recent wait
+    pub(crate) fn update_auto_precision(&mut self, oversleep: Duration) {
+        let Some(auto) = &mut self.auto_precision else {
+            return;
+        };
+        auto.oversleep = auto.oversleep * 15 / 16 + oversleep / 16;
+        auto.frames = auto.frames.saturating_add(1);
+        if auto.frames < MIN_DWELL || self.delta_time.is_zero() {
+            return;
+        }
+        let switch = match self.high_precision {
+            true => auto.oversleep < self.delta_time.mul_f64(DISABLE_SHARE),
+            false => auto.oversleep > self.delta_time.mul_f64(ENABLE_SHARE),
+        };
+        if switch {
+            self.high_precision = !self.high_precision;
+            auto.frames = 0;
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::time::{Duration, Instant};
+
+use crate::{duration_from_nanos, pacing_core::Schedule, Timer, MAX_INTERVAL};
+
+/// schedule guaranteeing an exact long-term average rate
+#[derive(Clone, Debug)]
+pub(crate) struct AverageRate {
+    /// instant of frame 0
+    epoch: Instant,
+    /// exact rational frame targets in nanoseconds since `epoch`
+    schedule: Schedule,
+}
+
+impl AverageRate {
+    /// schedule of `numerator / denominator` frames per second
+    /// starting at `epoch`
+    pub(crate) fn new(numerator: u64, denominator: u64, epoch: Instant) -> Self {
+        Self {
+            epoch,
+            schedule: Schedule::from_rate(numerator, denominator),
+        }
+    }
+
+    /// target of the most recently scheduled frame
+    fn target(&self) -> Instant {
+        self.epoch + duration_from_nanos(self.schedule.target()).min(MAX_INTERVAL)
+    }
+
+    /// restarts the schedule at `epoch` and returns the first target
+    pub(crate) fn restart(&mut self, epoch: Instant) -> Instant {
+        self.epoch = epoch;
+        self.schedule.start(0);
+        self.target()
+    }
+
+    /// advances the schedule by one frame and returns the next target
+    pub(crate) fn advance(&mut self) -> Instant {
+        self.schedule.advance();
+        self.target()
+    }
+
+    /// frametime of the rate, rounded down to nanoseconds
+    pub(crate) fn frame_time(&self) -> Duration {
+        self.schedule.frame_time()
+    }
+}
+
+impl Timer {
+    /// Paces the timer at exactly `numerator / denominator` frames per
+    /// second on average, over arbitrarily long horizons.
+    ///
+    /// Frame targets are computed as `start + k * denominator / numerator`
+    /// by a [`Schedule::from_rate`] instead of accumulating a rounded
+    /// frametime, so rounding errors are diffused instead of adding up. Late frames never cause the schedule
+    /// to be re-based: missed frames are caught up back to back, so the
+    /// number of frames per minute is exact, at the cost of per-frame
+    /// regularity. Intended for camera capture and data acquisition loops.
+    ///
+    /// Setting a framerate through [`Self::fps`] or
+    /// [`Self::frame_time`] disables this mode again.
+    ///
+    /// # Arguments
+    /// * `numerator` - frame rate numerator
+    /// * `denominator` - frame rate denominator
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Panics
+    /// if `numerator` or `denominator` is zero
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    ///
+    /// // 29.97 fps
+    /// let mut timer = Timer::default().average_rate(30_000, 1001);
+    /// let start = Instant::now() + Duration::from_millis(50);
+    /// // a hitch of 200ms is caught up instead of skipped
+    /// timer.frame_at(start + Duration::from_millis(200));
+    /// assert_eq!(timer.frame_at(start + Duration::from_millis(200)), Duration::ZERO);
+    /// ```
+    pub fn average_rate(mut self, numerator: u64, denominator: u64) -> Self {
+        assert!(numerator > 0 && denominator > 0, "invalid frame rate");
+        let mut rate = AverageRate::new(numerator, denominator, self.previous);
+        self.set_frame_time(rate.frame_time());
+        self.target = rate.restart(self.previous);
+        self.average_rate = Some(rate);
+        self
+    }
+}
@@ -0,0 +1,313 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    hint, thread,
+    time::{Duration, Instant},
+};
+
+use crate::{frame_time_from_fps, Timer, WakeupHistogram};
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use std::time::Instant;
+
+    /// sleeps until `target` with an absolute `clock_nanosleep`
+    pub(super) fn sleep_until(target: Instant) {
+        let mut now = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `now` is a valid, writable timespec
+        if unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, &mut now) } != 0 {
+            return;
+        }
+        let remaining = target.saturating_duration_since(Instant::now());
+        let nanos = now.tv_nsec as i128 + remaining.subsec_nanos() as i128;
+        let secs = remaining.as_secs() as libc::time_t + (nanos / 1_000_000_000) as libc::time_t;
+        let request = libc::timespec {
+            tv_sec: now.tv_sec.saturating_add(secs),
+            tv_nsec: (nanos % 1_000_000_000) as _,
+        };
+        // SAFETY: `request` is a valid timespec, `remain` is unused for TIMER_ABSTIME
+        let sleep = || unsafe {
+            libc::clock_nanosleep(
+                libc::CLOCK_MONOTONIC,
+                libc::TIMER_ABSTIME,
+                &request,
+                std::ptr::null_mut(),
+            )
+        };
+        // restart if interrupted by a signal
+        while sleep() == libc::EINTR {}
+    }
+}
+
+/// Wait method compared by [`benchmark_sleeper`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Sleeper {
+    /// naive `thread::sleep(frame_time)` after every frame
+    ThreadSleep,
+    /// busy waiting until the frame target
+    Spin,
+    /// absolute `clock_nanosleep` until the frame target (Linux only)
+    Native,
+    /// `spin_sleep::SpinSleeper` with its default accuracy until the
+    /// frame target, requires the `spin_sleep` feature
+    #[cfg(feature = "spin_sleep")]
+    SpinSleep,
+    /// [`Timer`] without high precision
+    Timer,
+    /// [`Timer`] with high precision
+    TimerHighPrecision,
+    /// [`Timer`] in high-rate mode, see [`Timer::high_rate`]
+    TimerHighRate,
+}
+
+impl Sleeper {
+    /// all wait methods supported on the current platform
+    pub fn all() -> Vec<Sleeper> {
+        let mut sleepers = vec![Sleeper::ThreadSleep, Sleeper::Spin];
+        if cfg!(target_os = "linux") {
+            sleepers.push(Sleeper::Native);
+        }
+        #[cfg(feature = "spin_sleep")]
+        sleepers.push(Sleeper::SpinSleep);
+        sleepers.extend([
+            Sleeper::Timer,
+            Sleeper::TimerHighPrecision,
+            Sleeper::TimerHighRate,
+        ]);
+        sleepers
+    }
+
+    /// short machine readable name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Sleeper::ThreadSleep => "thread_sleep",
+            Sleeper::Spin => "spin",
+            Sleeper::Native => "native",
+            #[cfg(feature = "spin_sleep")]
+            Sleeper::SpinSleep => "spin_sleep",
+            Sleeper::Timer => "timer",
+            Sleeper::TimerHighPrecision => "timer_high_precision",
+            Sleeper::TimerHighRate => "timer_high_rate",
+        }
+    }
+
+    /// measures `frames` frame intervals at `frame_time`
+    /// and the wakeup errors of the waits
+    fn measure(&self, frame_time: Duration, frames: u32) -> (Vec<Duration>, WakeupHistogram) {
+        let mut intervals = Vec::with_capacity(frames as usize);
+        let mut wakeups = WakeupHistogram::default();
+        if let Sleeper::Timer | Sleeper::TimerHighPrecision | Sleeper::TimerHighRate = self {
+            let mut timer = Timer::default()
+                .frame_time(frame_time)
+                .high_precision(*self == Sleeper::TimerHighPrecision)
+                .high_rate(*self == Sleeper::TimerHighRate);
+            timer.frame();
+            for _ in 0..frames {
+                intervals.push(timer.frame());
+            }
+            #[cfg(feature = "stats")]
+            {
+                wakeups = timer.wakeup_histogram().clone();
+            }
+            return (intervals, wakeups);
+        }
+
+        #[cfg(feature = "spin_sleep")]
+        let spin_sleeper = spin_sleep::SpinSleeper::default();
+        let mut previous = Instant::now();
+        let mut target = previous + frame_time;
+        for _ in 0..frames {
+            match self {
+                Sleeper::ThreadSleep => thread::sleep(frame_time),
+                Sleeper::Spin => {
+                    while Instant::now() < target {
+                        hint::spin_loop();
+                    }
+                }
+                #[cfg(target_os = "linux")]
+                Sleeper::Native => sys::sleep_until(target),
+                #[cfg(feature = "spin_sleep")]
+                Sleeper::SpinSleep => {
+                    spin_sleeper.sleep(target.saturating_duration_since(Instant::now()))
+                }
+                _ => unreachable!("unsupported sleeper"),
+            }
+            let now = Instant::now();
+            let expected = match self {
+                Sleeper::ThreadSleep => previous + frame_time,
+                _ => target,
+            };
+            wakeups.record(now.saturating_duration_since(expected));
+            intervals.push(now - previous);
+            previous = now;
+            target += frame_time;
+        }
+        (intervals, wakeups)
+    }
+}
+
+/// Accuracy of a single wait method at a single target rate
+#[derive(Clone, Debug, PartialEq)]
+pub struct BenchmarkResult {
+    /// measured wait method
+    sleeper: Sleeper,
+    /// target framerate
+    fps: f64,
+    /// number of measured frames
+    frames: u32,
+    /// mean achieved frame interval
+    mean: Duration,
+    /// mean absolute deviation from the target frametime
+    mean_error: Duration,
+    /// 99th percentile absolute deviation from the target frametime
+    p99_error: Duration,
+    /// largest absolute deviation from the target frametime
+    max_error: Duration,
+    /// wakeup errors of the waits
+    wakeups: WakeupHistogram,
+}
+
+impl BenchmarkResult {
+    /// measured wait method
+    pub fn sleeper(&self) -> Sleeper {
+        self.sleeper
+    }
+
+    /// target framerate
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+
+    /// number of measured frames
+    pub fn frames(&self) -> u32 {
+        self.frames
+    }
+
+    /// mean achieved frame interval
+    pub fn mean(&self) -> Duration {
+        self.mean
+    }
+
+    /// achieved framerate
+    pub fn achieved_fps(&self) -> f64 {
+        1. / self.mean.as_secs_f64()
+    }
+
+    /// mean absolute deviation from the target frametime
+    pub fn mean_error(&self) -> Duration {
+        self.mean_error
+    }
+
+    /// 99th percentile absolute deviation from the target frametime
+    pub fn p99_error(&self) -> Duration {
+        self.p99_error
+    }
+
+    /// largest absolute deviation from the target frametime
+    pub fn max_error(&self) -> Duration {
+        self.max_error
+    }
+
+    /// Wakeup errors of the waits, i.e. how late each wait returned.
+    /// Empty for the [`Timer`] based sleepers without the `stats` feature.
+    pub fn wakeup_error(&self) -> &WakeupHistogram {
+        &self.wakeups
+    }
+}
+
+/// Measures how accurately `sleeper` achieves `fps` over `frames` frames.
+///
+/// # Panics
+/// if `frames` is zero or `fps` is not positive
+pub fn benchmark_sleeper(sleeper: Sleeper, fps: f64, frames: u32) -> BenchmarkResult {
+    assert!(frames > 0, "at least one frame is required");
+    assert!(fps > 0., "fps must be positive");
+    let frame_time = frame_time_from_fps(fps);
+    let (intervals, wakeups) = sleeper.measure(frame_time, frames);
+
+    let mut errors: Vec<Duration> = intervals
+        .iter()
+        .map(|&interval| interval.abs_diff(frame_time))
+        .collect();
+    errors.sort_unstable();
+    let total: Duration = intervals.iter().sum();
+    let total_error: Duration = errors.iter().sum();
+    let p99 = (errors.len() * 99).div_ceil(100).saturating_sub(1);
+    BenchmarkResult {
+        sleeper,
+        fps,
+        frames,
+        mean: total / frames,
+        mean_error: total_error / frames,
+        p99_error: errors[p99],
+        max_error: errors[errors.len() - 1],
+        wakeups,
+    }
+}
+
+/// Results of [`compare_sleepers`], printable as CSV through [`Display`]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BenchmarkReport {
+    results: Vec<BenchmarkResult>,
+}
+
+impl BenchmarkReport {
+    /// all results, grouped by target rate
+    pub fn results(&self) -> &[BenchmarkResult] {
+        &self.results
+    }
+}
+
+impl Display for BenchmarkReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "sleeper,fps,frames,achieved_fps,mean_error_ns,p99_error_ns,max_error_ns,\
+             wakeup_p50_ns,wakeup_p99_ns,wakeup_max_ns"
+        )?;
+        for r in &self.results {
+            writeln!(
+                f,
+                "{},{},{},{:.3},{},{},{},{},{},{}",
+                r.sleeper.name(),
+                r.fps,
+                r.frames,
+                r.achieved_fps(),
+                r.mean_error.as_nanos(),
+                r.p99_error.as_nanos(),
+                r.max_error.as_nanos(),
+                r.wakeups.percentile(0.5).as_nanos(),
+                r.wakeups.percentile(0.99).as_nanos(),
+                r.wakeups.max().as_nanos(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Benchmarks all wait methods supported on the current platform
+/// (see [`Sleeper::all`]) at each of the target `rates`.
+///
+/// `spin_sleep` is only compared with the `spin_sleep` feature enabled.
+///
+/// # Example
+/// ```rust
+/// let report = fps_timer::compare_sleepers(&[500.], 10);
+/// assert!(report.to_string().starts_with("sleeper,fps,"));
+/// for result in report.results() {
+///     println!("{}: {:?}", result.sleeper().name(), result.mean_error());
+/// }
+/// ```
+pub fn compare_sleepers(rates: &[f64], frames: u32) -> BenchmarkReport {
+    let results = rates
+        .iter()
+        .flat_map(|&fps| {
+            Sleeper::all()
+                .into_iter()
+                .map(move |sleeper| benchmark_sleeper(sleeper, fps, frames))
+        })
+        .collect();
+    BenchmarkReport { results }
+}
@@ -0,0 +1,367 @@
+//! Live terminal monitor for the statistics stream of a running process.
+//!
+//! ```text
+//! fps-timer-top udp <listen-addr>   # NetSink::udp
+//! fps-timer-top tcp <listen-addr>   # NetSink::tcp
+//! fps-timer-top http <server-addr>  # StatsServer
+//! ```
+
+use std::{
+    collections::VecDeque,
+    env,
+    io::{self, BufRead, BufReader, Read, Write},
+    iter::Peekable,
+    net::{TcpListener, TcpStream, UdpSocket},
+    process,
+    str::Chars,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use fps_timer::Timer;
+
+/// number of frametimes kept for the graph and percentiles
+const HISTORY: usize = 600;
+/// number of hitches kept in the hitch log
+const HITCHES: usize = 8;
+/// height of the frametime graph in rows
+const GRAPH_ROWS: usize = 8;
+/// width of the frametime graph in columns
+const GRAPH_COLUMNS: usize = 72;
+/// how often the HTTP endpoint is polled
+const HTTP_POLL: Duration = Duration::from_millis(500);
+
+/// a message received from the monitored process
+enum Message {
+    /// frametime of a single frame
+    Frame(Duration),
+    /// interval statistics as `key=value` pairs
+    Log(Vec<(String, String)>),
+    /// the source failed
+    Error(String),
+}
+
+/// parses `key=value` pairs separated by whitespace
+fn parse_log(line: &str) -> Vec<(String, String)> {
+    line.split_whitespace()
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        .collect()
+}
+
+/// parses a flat JSON object as returned by `/metrics.json`,
+/// non-string values are kept as their literal text
+fn parse_json(body: &str) -> Vec<(String, String)> {
+    let mut chars = body.trim().chars().peekable();
+    let mut fields = vec![];
+    if chars.next() != Some('{') {
+        return fields;
+    }
+    loop {
+        skip_whitespace(&mut chars);
+        let Some(key) = parse_json_string(&mut chars) else {
+            return fields;
+        };
+        skip_whitespace(&mut chars);
+        if chars.next() != Some(':') {
+            return fields;
+        }
+        skip_whitespace(&mut chars);
+        let value = match chars.peek() {
+            Some('"') => match parse_json_string(&mut chars) {
+                Some(value) => value,
+                None => return fields,
+            },
+            _ => {
+                let mut literal = String::new();
+                while let Some(c) = chars.next_if(|c| !matches!(c, ',' | '}')) {
+                    literal.push(c);
+                }
+                literal.trim_end().to_owned()
+            }
+        };
+        fields.push((key, value));
+        skip_whitespace(&mut chars);
+        if chars.next() != Some(',') {
+            return fields;
+        }
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+/// parses a quoted JSON string, resolving escapes
+fn parse_json_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+    let mut string = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(string),
+            '\\' => string.push(match chars.next()? {
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    let code = u32::from_str_radix(&hex, 16).ok()?;
+                    char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER)
+                }
+                // '"', '\\' and '/'
+                c => c,
+            }),
+            c => string.push(c),
+        }
+    }
+}
+
+/// decodes a length-prefixed record of a NetSink
+fn decode(record: &[u8]) -> Option<Message> {
+    let (&kind, payload) = record.split_first()?;
+    match kind {
+        b'F' => {
+            let nanos = u64::from_be_bytes(payload.try_into().ok()?);
+            Some(Message::Frame(Duration::from_nanos(nanos)))
+        }
+        b'L' => Some(Message::Log(parse_log(std::str::from_utf8(payload).ok()?))),
+        _ => None,
+    }
+}
+
+fn receive_udp(socket: UdpSocket, tx: Sender<Message>) -> io::Result<()> {
+    let mut datagram = vec![0; 64 * 1024];
+    loop {
+        let len = socket.recv(&mut datagram)?;
+        if let Some(message) = datagram[..len].get(4..).and_then(decode) {
+            if tx.send(message).is_err() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn receive_tcp(listener: TcpListener, tx: Sender<Message>) -> io::Result<()> {
+    // serve one process after the other
+    for stream in listener.incoming() {
+        let mut stream = BufReader::new(stream?);
+        let mut header = [0; 4];
+        while stream.read_exact(&mut header).is_ok() {
+            let mut record = vec![0; u32::from_be_bytes(header) as usize];
+            if stream.read_exact(&mut record).is_err() {
+                break;
+            }
+            if let Some(message) = decode(&record) {
+                if tx.send(message).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// fetches `/metrics.json` from the `StatsServer` at `addr`
+fn fetch_json(addr: &str) -> io::Result<Vec<(String, String)>> {
+    let mut stream = TcpStream::connect(addr)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    write!(stream, "GET /metrics.json HTTP/1.1\r\nHost: {addr}\r\n\r\n")?;
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    // skip the status line and headers
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+    let mut body = String::new();
+    reader.read_to_string(&mut body)?;
+    Ok(parse_json(&body))
+}
+
+fn poll_http(addr: String, tx: Sender<Message>) -> io::Result<()> {
+    loop {
+        let message = match fetch_json(&addr) {
+            Ok(fields) => Message::Log(fields),
+            Err(e) => Message::Error(format!("{addr}: {e}")),
+        };
+        if tx.send(message).is_err() {
+            return Ok(());
+        }
+        thread::sleep(HTTP_POLL);
+    }
+}
+
+/// starts receiving from the source given on the command line
+fn connect(mode: &str, addr: &str) -> io::Result<Receiver<Message>> {
+    let (tx, rx) = mpsc::channel();
+    let error = tx.clone();
+    let run: Box<dyn FnOnce() -> io::Result<()> + Send> = match mode {
+        "udp" => {
+            let socket = UdpSocket::bind(addr)?;
+            Box::new(move || receive_udp(socket, tx))
+        }
+        "tcp" => {
+            let listener = TcpListener::bind(addr)?;
+            Box::new(move || receive_tcp(listener, tx))
+        }
+        "http" => {
+            let addr = addr.to_owned();
+            Box::new(move || poll_http(addr, tx))
+        }
+        _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, "unknown mode")),
+    };
+    thread::spawn(move || {
+        if let Err(e) = run() {
+            let _ = error.send(Message::Error(e.to_string()));
+        }
+    });
+    Ok(rx)
+}
+
+/// state of the monitor
+#[derive(Default)]
+struct Monitor {
+    /// most recent frametimes
+    frames: VecDeque<Duration>,
+    /// statistics of the latest interval
+    log: Vec<(String, String)>,
+    /// recent hitches as (seconds since the unix epoch, frametime)
+    hitches: VecDeque<(u64, Duration)>,
+    /// last error of the source
+    error: Option<String>,
+}
+
+impl Monitor {
+    fn receive(&mut self, message: Message) {
+        match message {
+            Message::Frame(frame_time) => {
+                if let Some(median) = self.percentile(0.5) {
+                    if self.frames.len() >= 30 && frame_time > median * 2 {
+                        if self.hitches.len() == HITCHES {
+                            self.hitches.pop_front();
+                        }
+                        let now = SystemTime::now()
+                            .duration_since(UNIX_EPOCH)
+                            .unwrap_or_default();
+                        self.hitches.push_back((now.as_secs(), frame_time));
+                    }
+                }
+                if self.frames.len() == HISTORY {
+                    self.frames.pop_front();
+                }
+                self.frames.push_back(frame_time);
+            }
+            Message::Log(log) => {
+                self.log = log;
+                self.error = None;
+            }
+            Message::Error(e) => self.error = Some(e),
+        }
+    }
+
+    fn percentile(&self, p: f64) -> Option<Duration> {
+        let mut sorted: Vec<_> = self.frames.iter().copied().collect();
+        sorted.sort_unstable();
+        let index = ((sorted.len() as f64 - 1.) * p).round() as usize;
+        sorted.get(index).copied()
+    }
+
+    /// frametime graph of the most recent frames, one column per frame
+    fn graph(&self, out: &mut String) {
+        let columns: Vec<_> = self
+            .frames
+            .iter()
+            .skip(self.frames.len().saturating_sub(GRAPH_COLUMNS))
+            .collect();
+        let max = columns.iter().copied().max().copied().unwrap_or_default();
+        let max_ms = max.as_secs_f64() * 1000.;
+        const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+        for row in (0..GRAPH_ROWS).rev() {
+            let label = max_ms * (row + 1) as f64 / GRAPH_ROWS as f64;
+            out.push_str(&format!("{label:8.2}ms │"));
+            for frame_time in &columns {
+                let height = frame_time.as_secs_f64() * 1000. / max_ms.max(f64::MIN_POSITIVE)
+                    * GRAPH_ROWS as f64;
+                let fill = height - row as f64;
+                out.push(match fill {
+                    f if f >= 1. => '█',
+                    f if f > 0. => BLOCKS[((f * 8.) as usize).min(7)],
+                    _ => ' ',
+                });
+            }
+            out.push('\n');
+        }
+        out.push_str(&format!("{:>10} └{}\n", "", "─".repeat(columns.len())));
+    }
+
+    fn render(&self, source: &str) -> String {
+        let mut out = String::from("\x1b[H\x1b[2J");
+        out.push_str(&format!("fps-timer-top  {source}\n\n"));
+        if self.frames.is_empty() {
+            out.push_str("no frame records (register NetSink::frame_stream as frame hook)\n");
+        } else {
+            self.graph(&mut out);
+            let ms = |p| self.percentile(p).unwrap_or_default().as_secs_f64() * 1000.;
+            out.push_str(&format!(
+                "\nframes {:>5}  p50 {:7.3}ms  p90 {:7.3}ms  p99 {:7.3}ms  max {:7.3}ms\n",
+                self.frames.len(),
+                ms(0.5),
+                ms(0.9),
+                ms(0.99),
+                ms(1.),
+            ));
+        }
+        out.push_str("\nlatest interval\n");
+        for (key, value) in &self.log {
+            out.push_str(&format!("  {key:<28} {value}\n"));
+        }
+        out.push_str("\nhitches\n");
+        for (at, frame_time) in self.hitches.iter().rev() {
+            out.push_str(&format!(
+                "  {at}  {:8.3}ms\n",
+                frame_time.as_secs_f64() * 1000.
+            ));
+        }
+        if let Some(e) = &self.error {
+            out.push_str(&format!("\nerror: {e}\n"));
+        }
+        out
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let (Some(mode), Some(addr)) = (args.get(1), args.get(2)) else {
+        eprintln!("usage: fps-timer-top <udp|tcp|http> <addr>");
+        process::exit(2);
+    };
+    let rx = match connect(mode, addr) {
+        Ok(rx) => rx,
+        Err(e) => {
+            eprintln!("fps-timer-top: {e}");
+            process::exit(1);
+        }
+    };
+
+    let source = format!("{mode}://{addr}");
+    let mut monitor = Monitor::default();
+    let mut timer = Timer::default().fps(10.).high_precision(false);
+    let mut stdout = io::stdout();
+    loop {
+        timer.frame();
+        for message in rx.try_iter() {
+            monitor.receive(message);
+        }
+        if stdout
+            .write_all(monitor.render(&source).as_bytes())
+            .is_err()
+        {
+            return;
+        }
+        let _ = stdout.flush();
+    }
+}
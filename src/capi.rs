@@ -0,0 +1,312 @@
+use std::{
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use crate::{Timer, TimerConfig};
+
+/// Version of the stable C ABI, incremented on every breaking change.
+///
+/// The C ABI allows loading the timer as a plugin from engines. All its
+/// types are `#[repr(C)]` and suitable for cbindgen, a C header can be
+/// generated with `cbindgen --config cbindgen.toml --output fps_timer.h`
+/// and a shared library built with
+/// `cargo rustc --lib --release --features capi --crate-type cdylib`
+/// (see `cbindgen.toml`).
+///
+/// The layout of the structs is frozen for a given version. Callers
+/// pass the version they were compiled against in
+/// [`FpsTimerConfig::abi_version`], so a plugin built against a
+/// different ABI is rejected with [`FpsTimerStatus::AbiMismatch`]
+/// instead of misreading memory.
+///
+/// Requires the `capi` feature.
+pub const FPS_TIMER_ABI_VERSION: u32 = 1;
+
+/// Result of a C ABI call
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FpsTimerStatus {
+    /// the call succeeded
+    Ok = 0,
+    /// a required pointer was null
+    NullPointer = 1,
+    /// the caller was compiled against a different ABI version
+    AbiMismatch = 2,
+    /// an argument was out of range
+    InvalidArgument = 3,
+    /// no logging interval has completed yet
+    NoLog = 4,
+    /// the timer panicked, it must not be used anymore
+    Panicked = 5,
+}
+
+/// Configuration of a timer created with [`fps_timer_new`]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FpsTimerConfig {
+    /// must be [`FPS_TIMER_ABI_VERSION`]
+    pub abi_version: u32,
+    /// non-zero for improved accuracy, see [`Timer::high_precision`]
+    pub high_precision: u32,
+    /// target frametime in nanoseconds, `0` for uncapped
+    pub frame_time_ns: u64,
+    /// logging interval in nanoseconds
+    pub log_interval_ns: u64,
+}
+
+/// Statistics of a logging interval, see [`crate::Log`]
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FpsTimerLog {
+    /// average frametime in nanoseconds
+    pub delta_avg_ns: u64,
+    /// number of frames in the interval
+    pub frames: u64,
+    /// shortest frametime in nanoseconds
+    pub min_ns: u64,
+    /// longest frametime in nanoseconds
+    pub max_ns: u64,
+    /// median frametime in nanoseconds
+    pub median_ns: u64,
+    /// 99th percentile frametime in nanoseconds
+    pub p99_ns: u64,
+    /// frametime jitter in nanoseconds
+    pub jitter_ns: u64,
+    /// number of hitches
+    pub hitches: u64,
+    /// time spent sleeping in nanoseconds
+    pub sleeping_ns: u64,
+    /// time spent busy-spinning in nanoseconds
+    pub spinning_ns: u64,
+    /// average framerate
+    pub fps_average: f64,
+}
+
+/// Opaque timer handle, created with [`fps_timer_new`]
+/// and destroyed with [`fps_timer_free`]
+pub struct FpsTimer {
+    timer: Timer,
+    /// set once the timer panicked
+    poisoned: bool,
+}
+
+// ABI tests: the layout of the structs must never change
+// without incrementing FPS_TIMER_ABI_VERSION
+const _: () = {
+    use std::mem::{align_of, offset_of, size_of};
+    assert!(FPS_TIMER_ABI_VERSION == 1);
+    assert!(size_of::<FpsTimerStatus>() == 4);
+    assert!(size_of::<FpsTimerConfig>() == 24);
+    assert!(align_of::<FpsTimerConfig>() == 8);
+    assert!(offset_of!(FpsTimerConfig, abi_version) == 0);
+    assert!(offset_of!(FpsTimerConfig, high_precision) == 4);
+    assert!(offset_of!(FpsTimerConfig, frame_time_ns) == 8);
+    assert!(offset_of!(FpsTimerConfig, log_interval_ns) == 16);
+    assert!(size_of::<FpsTimerLog>() == 88);
+    assert!(align_of::<FpsTimerLog>() == 8);
+    assert!(offset_of!(FpsTimerLog, delta_avg_ns) == 0);
+    assert!(offset_of!(FpsTimerLog, hitches) == 56);
+    assert!(offset_of!(FpsTimerLog, spinning_ns) == 72);
+    assert!(offset_of!(FpsTimerLog, fps_average) == 80);
+};
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// runs `f` on the timer behind `timer`, turning panics into
+/// [`FpsTimerStatus::Panicked`]
+///
+/// # Safety
+/// `timer` must be null or a live handle
+unsafe fn with_timer(
+    timer: *mut FpsTimer,
+    f: impl FnOnce(&mut Timer) -> FpsTimerStatus,
+) -> FpsTimerStatus {
+    let Some(handle) = timer.as_mut() else {
+        return FpsTimerStatus::NullPointer;
+    };
+    if handle.poisoned {
+        return FpsTimerStatus::Panicked;
+    }
+    match panic::catch_unwind(AssertUnwindSafe(|| f(&mut handle.timer))) {
+        Ok(status) => status,
+        Err(_) => {
+            handle.poisoned = true;
+            FpsTimerStatus::Panicked
+        }
+    }
+}
+
+/// ABI version of the library, compare with [`FPS_TIMER_ABI_VERSION`]
+/// before resolving any other symbol of a dynamically loaded library
+#[no_mangle]
+pub extern "C" fn fps_timer_abi_version() -> u32 {
+    FPS_TIMER_ABI_VERSION
+}
+
+/// configuration with the same defaults as [`Timer::default`]
+#[no_mangle]
+pub extern "C" fn fps_timer_config_default() -> FpsTimerConfig {
+    FpsTimerConfig {
+        abi_version: FPS_TIMER_ABI_VERSION,
+        high_precision: 1,
+        frame_time_ns: TimerConfig::new().frame_time_nanos(),
+        log_interval_ns: TimerConfig::new().log_interval_nanos(),
+    }
+}
+
+/// Creates a timer from `config` and stores its handle in `out`.
+///
+/// # Safety
+/// `config` must be null or point to a valid [`FpsTimerConfig`],
+/// `out` must be null or valid for writes.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::*;
+///
+/// let mut config = fps_timer_config_default();
+/// config.frame_time_ns = 1_000_000;
+/// config.log_interval_ns = 5_000_000;
+/// let mut timer = std::ptr::null_mut();
+/// unsafe {
+///     assert_eq!(fps_timer_new(&config, &mut timer), FpsTimerStatus::Ok);
+///     let mut delta = 0;
+///     let mut log = FpsTimerLog::default();
+///     while fps_timer_log(timer, &mut log) == FpsTimerStatus::NoLog {
+///         assert_eq!(fps_timer_frame(timer, &mut delta), FpsTimerStatus::Ok);
+///     }
+///     assert!(log.frames > 0);
+///     fps_timer_free(timer);
+/// }
+///
+/// config.abi_version = 0;
+/// let status = unsafe { fps_timer_new(&config, &mut timer) };
+/// assert_eq!(status, FpsTimerStatus::AbiMismatch);
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn fps_timer_new(
+    config: *const FpsTimerConfig,
+    out: *mut *mut FpsTimer,
+) -> FpsTimerStatus {
+    let (Some(config), false) = (config.as_ref(), out.is_null()) else {
+        return FpsTimerStatus::NullPointer;
+    };
+    if config.abi_version != FPS_TIMER_ABI_VERSION {
+        return FpsTimerStatus::AbiMismatch;
+    }
+    let timer = TimerConfig::new()
+        .with_frame_time_nanos(config.frame_time_ns)
+        .with_log_interval_nanos(config.log_interval_ns)
+        .with_high_precision(config.high_precision != 0)
+        .build();
+    *out = Box::into_raw(Box::new(FpsTimer {
+        timer,
+        poisoned: false,
+    }));
+    FpsTimerStatus::Ok
+}
+
+/// Destroys a timer created with [`fps_timer_new`].
+///
+/// # Safety
+/// `timer` must be null or a handle returned by [`fps_timer_new`]
+/// that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn fps_timer_free(timer: *mut FpsTimer) {
+    if !timer.is_null() {
+        drop(Box::from_raw(timer));
+    }
+}
+
+/// Waits for the next frame, see [`Timer::frame`], and stores the
+/// frametime in nanoseconds in `out_delta_ns` unless it is null.
+///
+/// # Safety
+/// `timer` must be null or a live handle,
+/// `out_delta_ns` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fps_timer_frame(
+    timer: *mut FpsTimer,
+    out_delta_ns: *mut u64,
+) -> FpsTimerStatus {
+    with_timer(timer, |timer| {
+        let delta = nanos(timer.frame());
+        if let Some(out) = out_delta_ns.as_mut() {
+            *out = delta;
+        }
+        FpsTimerStatus::Ok
+    })
+}
+
+/// Sets the framerate target, see [`Timer::fps`]. A framerate of `0`
+/// disables the framerate cap, negative, infinite and NaN framerates
+/// are rejected with [`FpsTimerStatus::InvalidArgument`].
+///
+/// # Safety
+/// `timer` must be null or a live handle.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::*;
+///
+/// let config = fps_timer_config_default();
+/// let mut timer = std::ptr::null_mut();
+/// unsafe {
+///     assert_eq!(fps_timer_new(&config, &mut timer), FpsTimerStatus::Ok);
+///     assert_eq!(fps_timer_set_fps(timer, 0.), FpsTimerStatus::Ok);
+///     let status = fps_timer_set_fps(timer, f64::INFINITY);
+///     assert_eq!(status, FpsTimerStatus::InvalidArgument);
+///     fps_timer_free(timer);
+/// }
+/// ```
+#[no_mangle]
+pub unsafe extern "C" fn fps_timer_set_fps(timer: *mut FpsTimer, fps: f64) -> FpsTimerStatus {
+    if !fps.is_finite() || fps < 0. {
+        return FpsTimerStatus::InvalidArgument;
+    }
+    with_timer(timer, |timer| {
+        timer.set_fps(fps);
+        FpsTimerStatus::Ok
+    })
+}
+
+/// Stores the statistics of the last completed logging interval in
+/// `out`, see [`Timer::log`]. Returns [`FpsTimerStatus::NoLog`]
+/// if the interval has not completed yet.
+///
+/// # Safety
+/// `timer` must be null or a live handle,
+/// `out` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn fps_timer_log(
+    timer: *mut FpsTimer,
+    out: *mut FpsTimerLog,
+) -> FpsTimerStatus {
+    if out.is_null() {
+        return FpsTimerStatus::NullPointer;
+    }
+    with_timer(timer, |timer| {
+        let Some(log) = timer.log() else {
+            return FpsTimerStatus::NoLog;
+        };
+        let stats = log.frame_time_stats();
+        let wait_time = log.wait_time();
+        *out = FpsTimerLog {
+            delta_avg_ns: nanos(log.delta_time_avg()),
+            frames: stats.frames(),
+            min_ns: nanos(stats.min()),
+            max_ns: nanos(stats.max()),
+            median_ns: nanos(stats.median()),
+            p99_ns: nanos(stats.p99()),
+            jitter_ns: nanos(stats.jitter()),
+            hitches: stats.hitches(),
+            sleeping_ns: nanos(wait_time.sleeping()),
+            spinning_ns: nanos(wait_time.spinning()),
+            fps_average: log.fps_average(),
+        };
+        FpsTimerStatus::Ok
+    })
+}
@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// state of the capture of a frame
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Capture {
+    /// time a capture frame may exceed its deadline by
+    allowance: Duration,
+    /// whether the next frame was requested to be captured
+    requested: bool,
+    /// whether the current frame is captured
+    active: bool,
+}
+
+impl Timer {
+    /// Sets the time a capture frame may exceed its deadline by
+    /// without counting as a hitch, see
+    /// [`Self::request_capture_next_frame`]. Zero by default.
+    ///
+    /// # Arguments
+    /// * `allowance` - extra time granted to capture frames
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn capture_allowance(mut self, allowance: Duration) -> Self {
+        self.capture.allowance = allowance;
+        self
+    }
+
+    /// Marks the next frame as capture frame, e.g. to take a screenshot
+    /// or to record a video frame.
+    ///
+    /// The frame starting with the next call to [`Self::frame`] is the
+    /// capture frame (see [`Self::is_capture_frame`]): its deadline is
+    /// relaxed by the [`Self::capture_allowance`], so the cost of the
+    /// capture neither counts as a hitch nor makes the timer catch up
+    /// with a burst of short frames afterwards. With the `stats`
+    /// feature, capture frames are recorded under the tag `capture`,
+    /// see [`Self::tag_summary`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(100.)
+    ///     .capture_allowance(Duration::from_millis(30));
+    /// timer.frame();
+    /// timer.request_capture_next_frame();
+    /// timer.frame();
+    /// assert!(timer.is_capture_frame());
+    /// // encoding the screenshot
+    /// thread::sleep(Duration::from_millis(25));
+    /// timer.frame();
+    /// assert!(!timer.is_capture_frame());
+    ///
+    /// let capture = timer.tag_summary("capture").unwrap();
+    /// assert_eq!(capture.frame_time_stats().frames(), 1);
+    /// assert_eq!(timer.session_summary().frame_time_stats().hitches(), 0);
+    /// # }
+    /// ```
+    pub fn request_capture_next_frame(&mut self) {
+        self.capture.requested = true;
+    }
+
+    /// `true` while the current frame is a capture frame,
+    /// see [`Self::request_capture_next_frame`]
+    pub fn is_capture_frame(&self) -> bool {
+        self.capture.active
+    }
+
+    /// time the completing frame may exceed its deadline by
+    pub(crate) fn capture_slack(&self) -> Duration {
+        match self.capture.active {
+            true => self.capture.allowance,
+            false => Duration::ZERO,
+        }
+    }
+
+    /// moves the target of a capture frame that finished `behind` its
+    /// target, but within the allowance, to `current`, returning `true`
+    /// if the target was moved
+    pub(crate) fn relax_capture_deadline(&mut self, current: Instant, behind: Duration) -> bool {
+        let relaxed = !behind.is_zero() && behind <= self.capture_slack();
+        if relaxed {
+            self.target = current;
+        }
+        relaxed
+    }
+
+    /// starts the capture frame at the end of a frame, if requested
+    pub(crate) fn advance_capture(&mut self) {
+        let capture = &mut self.capture;
+        if capture.requested || capture.active {
+            capture.active = std::mem::take(&mut capture.requested);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// maximum number of checkpoints kept per frame
+const MAX_CHECKPOINTS: usize = 256;
+
+/// A checkpoint reached during a frame, see [`Timer::checkpoint`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// label passed to [`Timer::checkpoint`]
+    label: &'static str,
+    /// time since the end of the previous frame
+    offset: Duration,
+}
+
+impl Checkpoint {
+    /// label passed to [`Timer::checkpoint`]
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// time since the end of the previous frame
+    pub fn offset(&self) -> Duration {
+        self.offset
+    }
+}
+
+/// A frame whose work exceeded its budget, with the checkpoints it
+/// reached, see [`Timer::long_frame`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LongFrame {
+    /// index of the frame
+    frame: u64,
+    /// time between the end of the previous frame and the call to
+    /// [`Timer::frame`]
+    work: Duration,
+    /// budget the work exceeded
+    budget: Duration,
+    /// checkpoints reached during the frame, in order
+    checkpoints: Vec<Checkpoint>,
+}
+
+impl LongFrame {
+    /// index of the frame, see [`crate::FrameInfo::index`]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// time between the end of the previous frame and the call
+    /// to [`Timer::frame`]
+    pub fn work(&self) -> Duration {
+        self.work
+    }
+
+    /// Budget the work exceeded: the target frametime, or twice the
+    /// smoothed workload for uncapped timers.
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// checkpoints reached during the frame, in order
+    pub fn checkpoints(&self) -> &[Checkpoint] {
+        &self.checkpoints
+    }
+
+    /// Last checkpoint reached before the frame ended, if any.
+    /// The frame stalled between this checkpoint and the next one.
+    pub fn last_checkpoint(&self) -> Option<&Checkpoint> {
+        self.checkpoints.last()
+    }
+}
+
+/// checkpoints of the current frame and long frames of the interval
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Checkpoints {
+    /// checkpoints reached in the current frame
+    current: Vec<(&'static str, Instant)>,
+    /// most recent long frame
+    last: Option<LongFrame>,
+    /// longest long frame of the logging interval
+    interval: Option<LongFrame>,
+}
+
+impl Checkpoints {
+    /// returns and resets the longest long frame of the interval
+    pub(crate) fn take_interval(&mut self) -> Option<LongFrame> {
+        self.interval.take()
+    }
+}
+
+impl Timer {
+    /// Marks that the current frame reached the point `label`.
+    ///
+    /// Call this at the boundaries of the phases of long frames, e.g.
+    /// after input handling, simulation and render submission. When the
+    /// work of a frame exceeds its budget, the checkpoints it reached are
+    /// kept as a [`LongFrame`], see [`Self::long_frame`], and the longest
+    /// of each logging interval is reported in its [`crate::Log`],
+    /// showing where long frames stall.
+    ///
+    /// Checkpoints cost a clock reading, so they can stay in release
+    /// builds. At most 256 checkpoints are kept per frame.
+    ///
+    /// # Arguments
+    /// * `label` - name of the point in the frame
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(200.);
+    /// timer.frame();
+    ///
+    /// timer.checkpoint("input");
+    /// timer.checkpoint("simulation");
+    /// // e.g. a slow asset load
+    /// thread::sleep(Duration::from_millis(10));
+    /// timer.frame();
+    ///
+    /// let long_frame = timer.long_frame().unwrap();
+    /// assert_eq!(long_frame.frame(), 1);
+    /// assert_eq!(long_frame.last_checkpoint().unwrap().label(), "simulation");
+    /// ```
+    pub fn checkpoint(&mut self, label: &'static str) {
+        if self.checkpoints.current.len() < MAX_CHECKPOINTS {
+            let now = self.clock_now();
+            self.checkpoints.current.push((label, now));
+        }
+    }
+
+    /// The most recent frame whose work exceeded its budget,
+    /// with the checkpoints it reached, see [`Self::checkpoint`].
+    pub fn long_frame(&self) -> Option<&LongFrame> {
+        self.checkpoints.last.as_ref()
+    }
+
+    /// keeps the checkpoints of the frame ending after `work`,
+    /// if the work exceeded its budget
+    pub(crate) fn record_checkpoints(&mut self, work: Duration) {
+        let budget = match self.delta_time {
+            Duration::ZERO => self.work_avg.saturating_mul(2),
+            delta => delta,
+        };
+        let checkpoints = std::mem::take(&mut self.checkpoints.current);
+        if budget.is_zero() || work <= budget {
+            // reuse the allocation for the next frame
+            self.checkpoints.current = checkpoints;
+            self.checkpoints.current.clear();
+            return;
+        }
+        let long_frame = LongFrame {
+            frame: self.framecount.wrapping_sub(1),
+            work,
+            budget,
+            checkpoints: checkpoints
+                .into_iter()
+                .map(|(label, at)| Checkpoint {
+                    label,
+                    offset: at.saturating_duration_since(self.previous),
+                })
+                .collect(),
+        };
+        let longest = &mut self.checkpoints.interval;
+        if longest.as_ref().is_none_or(|longest| work > longest.work) {
+            *longest = Some(long_frame.clone());
+        }
+        self.checkpoints.last = Some(long_frame);
+    }
+}
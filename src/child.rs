@@ -0,0 +1,92 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// A subsystem paced at `1 / divisor` of the rate of a parent [`Timer`],
+/// as created by [`Timer::child`].
+///
+/// A child does not wait on its own. Instead, the frames of the parent
+/// drive it: [`Self::due`] reports the child as due on every
+/// `divisor`-th frame of the parent, counted from the start of the
+/// parent's counter epoch (see [`crate::FrameInfo::index`]). Children
+/// are therefore phase-consistent with each other, e.g. every frame of
+/// a child with divisor 4 is also a frame of a child with divisor 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ChildTimer {
+    /// number of parent frames per frame of the child
+    divisor: u64,
+    /// `(epoch, index)` of the last parent frame checked
+    seen: Option<(u64, u64)>,
+    /// end of the last frame of the child
+    previous: Instant,
+}
+
+impl ChildTimer {
+    /// Checks whether the child is due in the current frame of `parent`,
+    /// returning the frametime of the child, i.e. the time since it was
+    /// last due, measured with the clock of the parent.
+    ///
+    /// Returns [`None`] if the child is not due, or if `parent` did not
+    /// complete a frame since the last check.
+    pub fn due(&mut self, parent: &Timer) -> Option<Duration> {
+        let info = parent.frame_info();
+        let frame = Some((info.epoch(), info.index()));
+        if frame == self.seen {
+            return None;
+        }
+        self.seen = frame;
+        if !info.index().is_multiple_of(self.divisor) {
+            return None;
+        }
+        let delta = info.timestamp().saturating_duration_since(self.previous);
+        self.previous = info.timestamp();
+        Some(delta)
+    }
+
+    /// number of parent frames per frame of the child
+    pub fn divisor(&self) -> u64 {
+        self.divisor
+    }
+}
+
+impl Timer {
+    /// Creates a child timer for a subsystem running at `1 / divisor`
+    /// of the rate of this timer, e.g. a UI at half and a minimap at
+    /// a quarter of the render rate.
+    ///
+    /// # Arguments
+    /// * `divisor` - number of frames of this timer per frame of the child
+    ///
+    /// # Panics
+    /// if `divisor` is zero
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(240.);
+    /// let mut ui = timer.child(2);
+    /// let mut minimap = timer.child(4);
+    /// let (mut ui_frames, mut minimap_frames) = (0, 0);
+    /// for _ in 0..8 {
+    ///     timer.frame();
+    ///     if let Some(_delta) = ui.due(&timer) {
+    ///         ui_frames += 1;
+    ///     }
+    ///     if minimap.due(&timer).is_some() {
+    ///         minimap_frames += 1;
+    ///     }
+    /// }
+    /// assert_eq!((ui_frames, minimap_frames), (4, 2));
+    /// ```
+    pub fn child(&self, divisor: u32) -> ChildTimer {
+        assert!(divisor > 0, "divisor must be at least 1");
+        let info = self.frame_info();
+        ChildTimer {
+            divisor: u64::from(divisor),
+            // before the first frame, the frame info is a placeholder
+            seen: (self.framecount > 0).then_some((info.epoch(), info.index())),
+            previous: self.previous,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{sync::OnceLock, time::Instant};
+
+use crate::Timer;
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod sys {
+    /// nanoseconds on `CLOCK_MONOTONIC_RAW`, [`None`] if not supported
+    pub(super) fn monotonic_raw() -> Option<u128> {
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `ts` is a valid, writable timespec
+        let res = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC_RAW, &mut ts) };
+        (res == 0).then(|| ts.tv_sec as u128 * 1_000_000_000 + ts.tv_nsec as u128)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+mod sys {
+    pub(super) fn monotonic_raw() -> Option<u128> {
+        None
+    }
+}
+
+/// Clock a [`Timer`] measures frametimes and schedules frames against.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Clock {
+    /// [`Instant`], the platform's monotonic clock.
+    ///
+    /// On Linux this is `CLOCK_MONOTONIC`, whose rate is slewed by
+    /// NTP, so a nominal second may last slightly more or less than
+    /// a physical one.
+    #[default]
+    Monotonic,
+    /// A monotonic clock that is not steered by NTP
+    /// (`CLOCK_MONOTONIC_RAW` on Linux and Android).
+    ///
+    /// Prevents clock slewing during long captures from showing up
+    /// as frame-rate drift in the recorded output. On Windows,
+    /// [`Instant`] already uses the unsteered performance counter, and
+    /// on other platforms this falls back to [`Clock::Monotonic`],
+    /// see [`Self::is_unsteered`].
+    MonotonicRaw,
+    /// No clock at all: time only advances through the timestamps
+    /// passed to [`Timer::frame_at`] and through waits, which end at
+    /// their target immediately instead of sleeping.
+    ///
+    /// Runs simulations and tests driven with synthetic time faster
+    /// than real time. [`Timer::frame`] continues at the end of the
+    /// previous frame, so it advances by exactly one frametime.
+    /// Reading this clock outside of a timer with [`Self::now`]
+    /// falls back to [`Instant::now`].
+    Synthetic,
+}
+
+/// raw clock reading and the [`Instant`] it is mapped to
+struct RawBase {
+    instant: Instant,
+    raw: u128,
+}
+
+/// common base all raw clock readings are mapped relative to
+static RAW_BASE: OnceLock<Option<RawBase>> = OnceLock::new();
+
+/// reads the raw clock, mapped into the [`Instant`] timebase
+fn raw_now() -> Instant {
+    let base = RAW_BASE.get_or_init(|| {
+        sys::monotonic_raw().map(|raw| RawBase {
+            instant: Instant::now(),
+            raw,
+        })
+    });
+    match (base, sys::monotonic_raw()) {
+        (Some(base), Some(raw)) => {
+            base.instant + crate::duration_from_nanos(raw.saturating_sub(base.raw))
+        }
+        _ => Instant::now(),
+    }
+}
+
+impl Clock {
+    /// Returns `true` if this clock is not steered by NTP
+    /// on the current platform.
+    pub fn is_unsteered(self) -> bool {
+        match self {
+            Clock::Monotonic => cfg!(windows),
+            Clock::MonotonicRaw => cfg!(windows) || sys::monotonic_raw().is_some(),
+            Clock::Synthetic => true,
+        }
+    }
+
+    /// Reads the current time of this clock.
+    ///
+    /// Readings of [`Clock::MonotonicRaw`] are mapped into the
+    /// [`Instant`] timebase at the first reading, so they can be
+    /// compared with each other and passed to [`Timer::frame_at`],
+    /// but drift apart from [`Instant::now`] over time.
+    pub fn now(self) -> Instant {
+        self.reader()()
+    }
+
+    /// function reading the current time of this clock
+    pub(crate) fn reader(self) -> fn() -> Instant {
+        match self {
+            Clock::Monotonic | Clock::Synthetic => Instant::now,
+            Clock::MonotonicRaw => raw_now,
+        }
+    }
+}
+
+impl Timer {
+    /// Sets the clock frames are measured and scheduled against.
+    ///
+    /// # Arguments
+    /// * `clock` - the clock to use, [`Clock::Monotonic`] by default
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{Clock, Timer};
+    ///
+    /// // long running capture, immune to NTP slewing where supported
+    /// let mut timer = Timer::default()
+    ///     .fps(60.)
+    ///     .clock(Clock::MonotonicRaw);
+    /// timer.frame();
+    /// ```
+    pub fn clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// current time of the clock of the timer, the end of the
+    /// previous frame on [`Clock::Synthetic`]
+    pub(crate) fn clock_now(&self) -> Instant {
+        match self.clock {
+            Clock::Synthetic => self.previous,
+            clock => clock.now(),
+        }
+    }
+
+    /// `true` if waits end at their target without sleeping
+    pub(crate) fn is_synthetic(&self) -> bool {
+        self.clock == Clock::Synthetic
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::{Recording, ReplayOutput};
+
+/// percentiles compared by a [`RunComparison`]
+const PERCENTILES: [f64; 4] = [0.5, 0.9, 0.99, 1.];
+
+/// A frametime percentile of two runs, see [`RunComparison::percentiles`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PercentileDelta {
+    /// percentile in `[0, 1]`
+    percentile: f64,
+    /// frametime percentile of the baseline run
+    baseline: Duration,
+    /// frametime percentile of the candidate run
+    candidate: Duration,
+}
+
+impl PercentileDelta {
+    /// percentile in `[0, 1]`, e.g. `0.99`
+    pub fn percentile(&self) -> f64 {
+        self.percentile
+    }
+
+    /// frametime percentile of the baseline run
+    pub fn baseline(&self) -> Duration {
+        self.baseline
+    }
+
+    /// frametime percentile of the candidate run
+    pub fn candidate(&self) -> Duration {
+        self.candidate
+    }
+
+    /// relative change from baseline to candidate,
+    /// positive if the candidate is slower
+    pub fn change(&self) -> f64 {
+        match self.baseline {
+            Duration::ZERO => 0.,
+            baseline => self.candidate.as_secs_f64() / baseline.as_secs_f64() - 1.,
+        }
+    }
+}
+
+/// Frame-by-frame and statistical comparison of the frametimes of a
+/// baseline and a candidate run, e.g. before and after an engine
+/// change, as returned by [`Recording::compare`].
+///
+/// The [`Display`] implementation writes a plain text report with
+/// times in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RunComparison {
+    /// frames of the baseline run
+    baseline_frames: usize,
+    /// frames of the candidate run
+    candidate_frames: usize,
+    /// mean difference of paired frames in seconds
+    mean_shift: f64,
+    /// largest difference of paired frames
+    max_shift: Duration,
+    /// frametime percentiles of both runs
+    percentiles: Vec<PercentileDelta>,
+    /// confidence that candidate frametimes are longer
+    regression_confidence: f64,
+}
+
+impl RunComparison {
+    /// Compares the frametimes `baseline` and `candidate` of two runs.
+    ///
+    /// Frames are paired by index for the frame-by-frame comparison,
+    /// so runs should cover the same workload, e.g. a scripted
+    /// benchmark scene.
+    pub fn new(baseline: &[Duration], candidate: &[Duration]) -> Self {
+        let paired = baseline.len().min(candidate.len());
+        let shifts = baseline.iter().zip(candidate).map(|(a, b)| (*a, *b));
+        let mean_shift = match paired {
+            0 => 0.,
+            n => {
+                shifts
+                    .clone()
+                    .map(|(a, b)| b.as_secs_f64() - a.as_secs_f64())
+                    .sum::<f64>()
+                    / n as f64
+            }
+        };
+        let max_shift = shifts.map(|(a, b)| a.abs_diff(b)).max().unwrap_or_default();
+
+        let mut sorted_baseline = baseline.to_vec();
+        sorted_baseline.sort_unstable();
+        let mut sorted_candidate = candidate.to_vec();
+        sorted_candidate.sort_unstable();
+        let percentiles = PERCENTILES
+            .into_iter()
+            .map(|percentile| PercentileDelta {
+                percentile,
+                baseline: percentile_of(&sorted_baseline, percentile),
+                candidate: percentile_of(&sorted_candidate, percentile),
+            })
+            .collect();
+
+        Self {
+            baseline_frames: baseline.len(),
+            candidate_frames: candidate.len(),
+            mean_shift,
+            max_shift,
+            percentiles,
+            regression_confidence: regression_confidence(baseline, candidate),
+        }
+    }
+
+    /// frames of the baseline run
+    pub fn baseline_frames(&self) -> usize {
+        self.baseline_frames
+    }
+
+    /// frames of the candidate run
+    pub fn candidate_frames(&self) -> usize {
+        self.candidate_frames
+    }
+
+    /// mean difference of frames with the same index in seconds,
+    /// positive if the candidate is slower
+    pub fn mean_shift(&self) -> f64 {
+        self.mean_shift
+    }
+
+    /// largest difference of frames with the same index
+    pub fn max_shift(&self) -> Duration {
+        self.max_shift
+    }
+
+    /// median, 90th and 99th percentile and maximum frametime
+    /// of both runs
+    pub fn percentiles(&self) -> &[PercentileDelta] {
+        &self.percentiles
+    }
+
+    /// Confidence in `[0, 1]` that frametimes of the candidate tend to
+    /// be longer than those of the baseline, based on a one-sided
+    /// Mann-Whitney U test. Values close to `0` indicate an improvement.
+    pub fn regression_confidence(&self) -> f64 {
+        self.regression_confidence
+    }
+
+    /// `true` if the candidate is slower with at least `confidence`,
+    /// e.g. `0.99` to fail a CI job
+    pub fn is_regression(&self, confidence: f64) -> bool {
+        self.regression_confidence >= confidence
+    }
+}
+
+impl Display for RunComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "comparison baseline_frames={} candidate_frames={} mean_shift={:.0} max_shift={} regression_confidence={:.4}",
+            self.baseline_frames,
+            self.candidate_frames,
+            self.mean_shift * 1e9,
+            self.max_shift.as_nanos(),
+            self.regression_confidence,
+        )?;
+        for delta in &self.percentiles {
+            writeln!(
+                f,
+                "percentile p={} baseline={} candidate={} change={:+.4}",
+                delta.percentile,
+                delta.baseline.as_nanos(),
+                delta.candidate.as_nanos(),
+                delta.change(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// nearest-rank percentile of ascending `samples`
+fn percentile_of(samples: &[Duration], p: f64) -> Duration {
+    match samples.len() {
+        0 => Duration::ZERO,
+        n => samples[((n - 1) as f64 * p).round() as usize],
+    }
+}
+
+/// one-sided Mann-Whitney U test with normal approximation,
+/// returning the confidence that `candidate` is stochastically larger
+fn regression_confidence(baseline: &[Duration], candidate: &[Duration]) -> f64 {
+    let (n_a, n_b) = (baseline.len() as f64, candidate.len() as f64);
+    if baseline.is_empty() || candidate.is_empty() {
+        return 0.5;
+    }
+    let mut samples: Vec<(Duration, bool)> = baseline
+        .iter()
+        .map(|d| (*d, false))
+        .chain(candidate.iter().map(|d| (*d, true)))
+        .collect();
+    samples.sort_unstable();
+
+    // rank sum of the candidate, ties sharing their average rank
+    let mut rank_sum = 0.;
+    let mut ties = 0.;
+    let mut start = 0;
+    while start < samples.len() {
+        let end = start + samples[start..].partition_point(|s| s.0 == samples[start].0);
+        let count = (end - start) as f64;
+        let rank = (start + end + 1) as f64 / 2.;
+        let from_candidate = samples[start..end].iter().filter(|s| s.1).count() as f64;
+        rank_sum += rank * from_candidate;
+        ties += count * count * count - count;
+        start = end;
+    }
+    let u = rank_sum - n_b * (n_b + 1.) / 2.;
+    let n = n_a + n_b;
+    let variance = n_a * n_b / 12. * ((n + 1.) - ties / (n * (n - 1.)));
+    if variance <= 0. {
+        return 0.5;
+    }
+    normal_cdf((u - n_a * n_b / 2.) / variance.sqrt())
+}
+
+/// cumulative distribution function of the standard normal distribution
+fn normal_cdf(z: f64) -> f64 {
+    // Abramowitz and Stegun 7.1.26, absolute error below 1.5e-7
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1. / (1. + 0.3275911 * x);
+    let poly = t
+        * (0.254829592
+            + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1. - poly * (-x * x).exp();
+    match z >= 0. {
+        true => 0.5 * (1. + erf),
+        false => 0.5 * (1. - erf),
+    }
+}
+
+impl Recording {
+    /// frametimes returned by the timer when replaying the recording
+    pub fn frame_times(&self) -> Vec<Duration> {
+        self.replay()
+            .into_iter()
+            .filter_map(|output| match output {
+                ReplayOutput::Frame(frame_time) => Some(frame_time),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Compares the frametimes of this recording (the baseline) with
+    /// those of `candidate`, see [`RunComparison`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::{Recorder, Timer};
+    ///
+    /// let run = |work: Duration| {
+    ///     let mut recorder = Recorder::new(Timer::default().fps(0.));
+    ///     for _ in 0..30 {
+    ///         thread::sleep(work);
+    ///         recorder.frame();
+    ///     }
+    ///     recorder.finish()
+    /// };
+    /// let baseline = run(Duration::from_millis(1));
+    /// let candidate = run(Duration::from_millis(3));
+    ///
+    /// let comparison = baseline.compare(&candidate);
+    /// println!("{comparison}");
+    /// assert!(comparison.is_regression(0.99));
+    /// assert!(comparison.percentiles()[0].change() > 0.5);
+    /// ```
+    pub fn compare(&self, candidate: &Recording) -> RunComparison {
+        RunComparison::new(&self.frame_times(), &candidate.frame_times())
+    }
+}
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// state of the late-frame compensation of a [`Timer`]
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct LateCompensation {
+    /// maximum share of the frametime a returned delta is extended by
+    max_correction: f64,
+    /// measured time not yet returned as delta
+    debt: Duration,
+}
+
+impl Timer {
+    /// Spreads the time of late frames over the following frames.
+    ///
+    /// Without compensation, [`Self::frame`] returns the measured frametime,
+    /// so a single late frame shows up as one large delta, e.g. as a jump
+    /// of an animated clock or scoreboard. With compensation, a returned
+    /// delta exceeds the (smoothed) frametime by at most `max_correction`
+    /// of the frametime. The remainder is carried over and returned in
+    /// bounded steps during the next frames, so the sum of the returned
+    /// deltas tracks real time exactly once the debt is paid off, even
+    /// when the frame schedule is re-based.
+    ///
+    /// [`FrameInfo::delta`](crate::FrameInfo::delta) reports the returned
+    /// delta, while statistics and hooks keep using measured frametimes.
+    /// Has no effect on uncapped timers.
+    ///
+    /// # Arguments
+    /// * `max_correction` - maximum extension of a returned delta
+    ///   as share of the frametime, e.g. `0.25`
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::{Duration, Instant}};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(100.).late_compensation(0.25);
+    /// let start = Instant::now();
+    /// let mut simulated = timer.frame();
+    /// // a frame taking five frametimes
+    /// thread::sleep(Duration::from_millis(50));
+    /// let delta = timer.frame();
+    /// assert!(delta <= Duration::from_micros(12_500));
+    /// simulated += delta;
+    /// while !timer.compensation_debt().is_zero() {
+    ///     simulated += timer.frame();
+    /// }
+    /// let real = timer.frame_info().timestamp() - start;
+    /// assert!(real.abs_diff(simulated) < Duration::from_millis(15));
+    /// ```
+    pub fn late_compensation(mut self, max_correction: f64) -> Self {
+        self.compensation = Some(LateCompensation {
+            max_correction: max_correction.max(0.),
+            debt: Duration::ZERO,
+        });
+        self
+    }
+
+    /// Measured time not yet returned by [`Self::frame`] due to
+    /// [`Self::late_compensation`], zero without compensation.
+    pub fn compensation_debt(&self) -> Duration {
+        self.compensation
+            .map_or(Duration::ZERO, |compensation| compensation.debt)
+    }
+
+    /// delta to return for the measured `frame_time`
+    pub(crate) fn compensate(&mut self, frame_time: Duration) -> Duration {
+        let Some(compensation) = &mut self.compensation else {
+            return frame_time;
+        };
+        if self.delta_time.is_zero() {
+            return frame_time;
+        }
+        let frame = self.delta_time.max(self.work_avg);
+        let owed = frame_time + compensation.debt;
+        let bound = frame.mul_f64(1. + compensation.max_correction);
+        let delta = owed.min(bound);
+        compensation.debt = owed - delta;
+        delta
+    }
+}
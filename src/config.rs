@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use crate::{pacing_core::Schedule, Timer, DEFAULT_LOG_INTERVAL, DEFAULT_SCHEDULE};
+
+/// Timer configuration that can be built in `const` and `static` contexts.
+///
+/// Framerates are specified as rationals and kept exact, durations as
+/// nanoseconds, so pacing settings can be baked into the binary and
+/// turned into a [`Timer`] at runtime with [`Self::build`].
+///
+/// # Example
+/// ```rust
+/// use fps_timer::{Timer, TimerConfig};
+///
+/// // 59.94 fps
+/// const PACING: TimerConfig = TimerConfig::new()
+///     .with_fps(60_000, 1001)
+///     .with_log_interval_nanos(500_000_000)
+///     .with_high_precision(false);
+///
+/// let mut timer: Timer = PACING.build();
+/// # timer.frame();
+/// assert_eq!(PACING.frame_time_nanos(), 16_683_333);
+/// assert_eq!(PACING.log_interval_nanos(), 500_000_000);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerConfig {
+    /// schedule with the exact target frametime
+    schedule: Schedule,
+    /// logging interval in nanoseconds
+    log_interval: u64,
+    /// improved accuracy
+    high_precision: bool,
+}
+
+impl Default for TimerConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimerConfig {
+    /// Creates a configuration with the same defaults as [`Timer::default`]
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::TimerConfig;
+    ///
+    /// // exactly 60 fps, not a rounded frametime
+    /// assert_eq!(TimerConfig::new(), TimerConfig::new().with_fps(60, 1));
+    /// assert_eq!(TimerConfig::new().frame_time_nanos(), 16_666_666);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            schedule: DEFAULT_SCHEDULE,
+            log_interval: DEFAULT_LOG_INTERVAL.as_nanos() as u64,
+            high_precision: true,
+        }
+    }
+
+    /// Sets the framerate target to exactly `numerator / denominator`
+    /// frames per second, `0` meaning uncapped,
+    /// see [`Schedule::from_rate`].
+    ///
+    /// # Panics
+    /// if `denominator` is zero
+    pub const fn with_fps(mut self, numerator: u64, denominator: u64) -> Self {
+        self.schedule = Schedule::from_rate(numerator, denominator);
+        self
+    }
+
+    /// Sets the target frametime in nanoseconds, see [`Timer::frame_time`]
+    pub const fn with_frame_time_nanos(mut self, nanos: u64) -> Self {
+        self.schedule = Schedule::new(Duration::from_nanos(nanos));
+        self
+    }
+
+    /// Sets the logging interval in nanoseconds, see [`Timer::log_interval`]
+    pub const fn with_log_interval_nanos(mut self, nanos: u64) -> Self {
+        self.log_interval = nanos;
+        self
+    }
+
+    /// Enables or disables improved accuracy, see [`Timer::high_precision`]
+    pub const fn with_high_precision(mut self, enabled: bool) -> Self {
+        self.high_precision = enabled;
+        self
+    }
+
+    /// target frametime in nanoseconds, rounded down
+    pub const fn frame_time_nanos(&self) -> u64 {
+        self.schedule.frame_time().as_nanos() as u64
+    }
+
+    /// logging interval in nanoseconds
+    pub const fn log_interval_nanos(&self) -> u64 {
+        self.log_interval
+    }
+
+    /// whether improved accuracy is enabled
+    pub const fn is_high_precision(&self) -> bool {
+        self.high_precision
+    }
+
+    /// Creates a [`Timer`] with this configuration
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{Clock, TimerConfig};
+    ///
+    /// let mut timer = TimerConfig::new()
+    ///     .with_fps(60_000, 1001)
+    ///     .build()
+    ///     .clock(Clock::Synthetic);
+    /// let epoch = timer.timebase_epoch();
+    /// for _ in 0..60_000 {
+    ///     timer.frame_at(epoch);
+    /// }
+    /// // 60000 frames at 59.94 fps take exactly 1001 seconds
+    /// let elapsed = timer.frame_info().timestamp() - epoch;
+    /// assert_eq!(elapsed, Duration::from_secs(1001));
+    /// ```
+    pub fn build(&self) -> Timer {
+        let mut timer = Timer::default()
+            .log_interval(Duration::from_nanos(self.log_interval))
+            .high_precision(self.high_precision);
+        timer.set_schedule(self.schedule);
+        timer
+    }
+}
+
+impl From<TimerConfig> for Timer {
+    fn from(config: TimerConfig) -> Self {
+        config.build()
+    }
+}
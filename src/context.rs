@@ -0,0 +1,138 @@
+use std::{
+    sync::OnceLock,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{Clock, Timer, DEFAULT_SPIN_MARGIN};
+
+/// number of sleeps measured by [`TimingContext::calibrate`]
+const CALIBRATION_SAMPLES: usize = 20;
+/// duration of each calibration sleep
+const CALIBRATION_SLEEP: Duration = Duration::from_millis(1);
+/// range of calibrated spin margins
+const MIN_SPIN_MARGIN: Duration = Duration::from_micros(50);
+const MAX_SPIN_MARGIN: Duration = Duration::from_millis(2);
+
+/// oversleeps of [`CALIBRATION_SAMPLES`] short sleeps, shortest first
+pub(crate) fn measure_oversleeps() -> Vec<Duration> {
+    let mut overshoots: Vec<Duration> = (0..CALIBRATION_SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            thread::sleep(CALIBRATION_SLEEP);
+            start.elapsed().saturating_sub(CALIBRATION_SLEEP)
+        })
+        .collect();
+    overshoots.sort_unstable();
+    overshoots
+}
+
+/// Calibrated sleep characteristics and clock source shared by many
+/// [`Timer`]s, e.g. one per thread or subsystem.
+///
+/// Calibration measures how much `thread::sleep` oversleeps on the
+/// current system, so high precision waits spin for just as long as
+/// needed instead of a fixed, conservative margin. It runs once
+/// (see [`Self::global`]) and the resulting context is cheap to copy,
+/// so all timers of a process behave consistently.
+///
+/// # Example
+/// ```rust
+/// use std::thread;
+/// use fps_timer::{Clock, TimingContext};
+///
+/// let context = TimingContext::global().with_clock(Clock::MonotonicRaw);
+/// let render = thread::spawn(move || {
+///     let mut timer = context.timer().fps(144.);
+///     timer.frame();
+/// });
+/// let mut audio = context.timer().fps(375.);
+/// audio.frame();
+/// render.join().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimingContext {
+    /// clock frames are measured against
+    clock: Clock,
+    /// maximum busy wait of a high precision wait
+    spin_margin: Duration,
+}
+
+impl Default for TimingContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimingContext {
+    /// Creates an uncalibrated context with the same settings
+    /// as [`Timer::default`]
+    pub fn new() -> Self {
+        Self {
+            clock: Clock::Monotonic,
+            spin_margin: DEFAULT_SPIN_MARGIN,
+        }
+    }
+
+    /// Measures the sleep characteristics of the current system,
+    /// which takes about 25ms.
+    pub fn calibrate() -> Self {
+        let overshoots = measure_oversleeps();
+        // cover all but the rarest oversleeps
+        let overshoot = overshoots[CALIBRATION_SAMPLES * 9 / 10];
+        Self {
+            spin_margin: (overshoot + MIN_SPIN_MARGIN).clamp(MIN_SPIN_MARGIN, MAX_SPIN_MARGIN),
+            ..Self::new()
+        }
+    }
+
+    /// The context of the process, calibrated on first use.
+    pub fn global() -> Self {
+        static GLOBAL: OnceLock<TimingContext> = OnceLock::new();
+        *GLOBAL.get_or_init(Self::calibrate)
+    }
+
+    /// Sets the clock of all timers created from this context,
+    /// see [`Timer::clock`]
+    pub fn with_clock(mut self, clock: Clock) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Sets the maximum busy wait of high precision waits,
+    /// overriding the calibrated value
+    pub fn with_spin_margin(mut self, margin: Duration) -> Self {
+        self.spin_margin = margin;
+        self
+    }
+
+    /// clock of all timers created from this context
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// maximum busy wait of high precision waits
+    pub fn spin_margin(&self) -> Duration {
+        self.spin_margin
+    }
+
+    /// creates a [`Timer`] using this context
+    pub fn timer(&self) -> Timer {
+        Timer::default().timing_context(self)
+    }
+}
+
+impl Timer {
+    /// Applies the clock and sleep calibration of `context`.
+    ///
+    /// # Arguments
+    /// * `context` - shared timing context
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn timing_context(mut self, context: &TimingContext) -> Self {
+        self.clock = context.clock;
+        self.spin_margin = context.spin_margin;
+        self
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    array,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    seqlock::{nanos_since, SeqLock},
+    Timer,
+};
+
+/// number of upcoming deadlines published
+const DEADLINES: usize = 8;
+
+/// number of published words: the index of the frame the first
+/// deadline belongs to, the number of valid deadlines and the
+/// deadlines in nanoseconds since the base instant
+const WORDS: usize = 2 + DEADLINES;
+
+/// deadlines published by a [`Timer`] at the end of every frame
+#[derive(Debug)]
+pub(crate) struct Shared {
+    /// reference instant of the stored deadlines
+    base: Instant,
+    /// the published deadlines
+    deadlines: SeqLock<WORDS>,
+}
+
+/// The target of an upcoming frame, see [`DeadlineQueue`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deadline {
+    /// index of the frame, see [`crate::FrameInfo::index`]
+    frame: u64,
+    /// target of the frame
+    at: Instant,
+}
+
+impl Deadline {
+    /// index of the frame, see [`crate::FrameInfo::index`]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// target of the frame
+    pub fn instant(&self) -> Instant {
+        self.at
+    }
+}
+
+/// Consistent snapshot of the upcoming deadlines of a [`Timer`],
+/// see [`DeadlineQueue::read`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Deadlines {
+    /// index of the frame the first deadline belongs to
+    frame: u64,
+    /// number of valid deadlines
+    len: usize,
+    /// targets of the upcoming frames
+    at: [Instant; DEADLINES],
+}
+
+impl Deadlines {
+    /// number of deadlines, zero for uncapped timers
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// `true` if no deadlines are known, e.g. for uncapped timers
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// deadlines of the upcoming frames, soonest first
+    pub fn iter(&self) -> impl Iterator<Item = Deadline> + '_ {
+        self.at[..self.len]
+            .iter()
+            .zip(self.frame..)
+            .map(|(&at, frame)| Deadline { frame, at })
+    }
+
+    /// first deadline after `instant`, e.g. to schedule IO for the next
+    /// frame boundary
+    pub fn next_after(&self, instant: Instant) -> Option<Deadline> {
+        self.iter().find(|deadline| deadline.at > instant)
+    }
+}
+
+/// Read-only handle to the targets of the upcoming frames of a
+/// [`Timer`], as returned by [`Timer::deadline_queue`].
+///
+/// The deadlines are published lock-free at the end of every frame,
+/// so other subsystems, e.g. an audio mixer or a network sender, can
+/// schedule their own work relative to frame boundaries without access
+/// to the timer. Deadlines beyond the next one assume that the frames
+/// are on time and move when the timer catches up or is re-based.
+/// The handle is `Clone + Send + Sync`.
+///
+/// # Example
+/// ```rust
+/// use std::{thread, time::Duration};
+/// use fps_timer::Timer;
+///
+/// let mut timer = Timer::default().fps(100.);
+/// let deadlines = timer.deadline_queue();
+/// timer.frame();
+///
+/// let sender = thread::spawn(move || {
+///     let upcoming = deadlines.read().unwrap();
+///     let next = upcoming.iter().next().unwrap();
+///     // e.g. send the packet for the next frame right before its boundary
+///     let boundary = next.instant() - Duration::from_millis(1);
+///     (next.frame(), upcoming.len(), boundary)
+/// });
+/// let (frame, len, _) = sender.join().unwrap();
+/// assert_eq!(frame, 1);
+/// assert_eq!(len, 8);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DeadlineQueue {
+    shared: Arc<Shared>,
+}
+
+impl DeadlineQueue {
+    /// Reads all deadlines published at the end of the same frame.
+    ///
+    /// Retries a bounded number of times while the timer is updating
+    /// the deadlines, returning [`None`] if it did not finish.
+    pub fn read(&self) -> Option<Deadlines> {
+        let shared = &*self.shared;
+        let words = shared.deadlines.read()?;
+        let [frame, len, ..] = words;
+        Some(Deadlines {
+            frame,
+            len: (len as usize).min(DEADLINES),
+            at: array::from_fn(|i| shared.base + Duration::from_nanos(words[2 + i])),
+        })
+    }
+}
+
+impl Timer {
+    /// Returns a handle to the targets of the next 8 frames of this
+    /// timer, updated lock-free at the end of every frame,
+    /// see [`DeadlineQueue`].
+    pub fn deadline_queue(&mut self) -> DeadlineQueue {
+        let previous = self.previous;
+        let shared = self.deadline_queue.get_or_insert_with(|| {
+            Arc::new(Shared {
+                base: previous,
+                deadlines: SeqLock::new(),
+            })
+        });
+        let queue = DeadlineQueue {
+            shared: shared.clone(),
+        };
+        self.publish_deadlines();
+        queue
+    }
+
+    /// publishes the targets of the upcoming frames
+    pub(crate) fn publish_deadlines(&self) {
+        let Some(shared) = &self.deadline_queue else {
+            return;
+        };
+        let len = match self.delta_time {
+            Duration::ZERO => 0,
+            _ => DEADLINES,
+        };
+        let mut words = [0; WORDS];
+        words[0] = self.framecount;
+        words[1] = len as u64;
+        let mut deadline = self.target.max(self.previous);
+        for nanos in &mut words[2..2 + len] {
+            *nanos = nanos_since(shared.base, deadline);
+            deadline += self.delta_time;
+        }
+        shared.deadlines.write(words);
+    }
+}
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+impl Timer {
+    /// Clamps the deltas returned by [`Self::frame`] to at least `floor`.
+    ///
+    /// Two frames can complete at the same [`Instant`](std::time::Instant),
+    /// e.g. when uncapped on a coarse clock or when frames are driven
+    /// with [`Self::frame_at`], which makes the returned delta zero
+    /// and any division by it produce NaN or infinity. With a floor,
+    /// returned deltas are never shorter than `floor`, and never zero,
+    /// since a floor of zero is raised to one nanosecond.
+    ///
+    /// The floor applies after [`Self::delta_filter`], so
+    /// [`FrameInfo::delta`](crate::FrameInfo::delta) reports the
+    /// clamped delta, while statistics and hooks keep using measured
+    /// frametimes.
+    ///
+    /// # Arguments
+    /// * `floor` - minimum returned delta
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.).delta_floor(Duration::ZERO);
+    /// let now = timer.timebase_epoch();
+    /// timer.frame_at(now);
+    /// let delta = timer.frame_at(now);
+    /// assert_eq!(delta, Duration::from_nanos(1));
+    /// let speed = 1. / delta.as_secs_f64();
+    /// assert!(speed.is_finite());
+    /// ```
+    pub fn delta_floor(mut self, floor: Duration) -> Self {
+        self.delta_floor = Some(floor.max(Duration::from_nanos(1)));
+        self
+    }
+
+    /// `delta` raised to the delta floor, if any
+    pub(crate) fn floored(&self, delta: Duration) -> Duration {
+        match self.delta_floor {
+            Some(floor) => delta.max(floor),
+            None => delta,
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::{
+    env::consts,
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::{
+    context, is_virtualized, timer_slack, Clock, Pacing, SessionSummary, Timer, TimerEvent,
+    WakeupHistogram,
+};
+
+/// Platform, configuration and accuracy of a [`Timer`],
+/// as returned by [`Timer::diagnostics`].
+///
+/// The [`Display`] implementation writes a plain text report with
+/// one `key=value` line per value and times in nanoseconds, suited
+/// for attaching to bug reports about inaccurate timing.
+///
+/// Requires the `stats` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiagnosticsReport {
+    /// version of this crate
+    version: &'static str,
+    /// operating system, e.g. `linux`
+    os: &'static str,
+    /// CPU architecture, e.g. `x86_64`
+    arch: &'static str,
+    /// whether the system appears to be virtualized
+    virtualized: bool,
+    /// clock frames are measured against
+    clock: Clock,
+    /// median oversleep of a short sleep
+    sleep_resolution: Duration,
+    /// longest oversleep of a short sleep
+    sleep_oversleep_max: Duration,
+    /// timer slack of the current thread, if supported
+    timer_slack: Option<Duration>,
+    /// how the timer waits for frame targets
+    wait_strategy: &'static str,
+    /// maximum busy wait of a high precision wait
+    spin_margin: Duration,
+    /// target frametime, zero if uncapped
+    frame_time: Duration,
+    /// summary of all frames since the timer was created
+    summary: SessionSummary,
+    /// errors of all waits for frame targets
+    wakeups: WakeupHistogram,
+    /// recent notable events of the timer
+    events: Vec<TimerEvent>,
+}
+
+impl DiagnosticsReport {
+    /// version of this crate
+    pub fn version(&self) -> &'static str {
+        self.version
+    }
+
+    /// operating system, e.g. `linux`
+    pub fn os(&self) -> &'static str {
+        self.os
+    }
+
+    /// CPU architecture, e.g. `x86_64`
+    pub fn arch(&self) -> &'static str {
+        self.arch
+    }
+
+    /// whether the system appears to be virtualized,
+    /// see [`crate::is_virtualized`]
+    pub fn virtualized(&self) -> bool {
+        self.virtualized
+    }
+
+    /// clock frames are measured against
+    pub fn clock(&self) -> Clock {
+        self.clock
+    }
+
+    /// median oversleep of a short sleep, as measured when the
+    /// report was created
+    pub fn sleep_resolution(&self) -> Duration {
+        self.sleep_resolution
+    }
+
+    /// longest oversleep of a short sleep, as measured when the
+    /// report was created
+    pub fn sleep_oversleep_max(&self) -> Duration {
+        self.sleep_oversleep_max
+    }
+
+    /// timer slack of the current thread, [`None`] if not supported
+    pub fn timer_slack(&self) -> Option<Duration> {
+        self.timer_slack
+    }
+
+    /// how the timer waits for frame targets: `external`, `custom`,
+    /// `interruptible`, `spin`, `sleep+spin` or `sleep`
+    pub fn wait_strategy(&self) -> &'static str {
+        self.wait_strategy
+    }
+
+    /// maximum busy wait of a high precision wait,
+    /// see [`crate::TimingContext`]
+    pub fn spin_margin(&self) -> Duration {
+        self.spin_margin
+    }
+
+    /// target frametime, zero if uncapped
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// summary of all frames since the timer was created
+    pub fn summary(&self) -> &SessionSummary {
+        &self.summary
+    }
+
+    /// errors of all waits for frame targets
+    pub fn wakeups(&self) -> &WakeupHistogram {
+        &self.wakeups
+    }
+
+    /// recent notable events of the timer, see [`Timer::events`]
+    pub fn events(&self) -> &[TimerEvent] {
+        &self.events
+    }
+}
+
+impl Display for DiagnosticsReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let stats = self.summary.frame_time_stats();
+        let wait_time = self.summary.wait_time();
+        writeln!(f, "version={}", self.version)?;
+        writeln!(f, "os={}", self.os)?;
+        writeln!(f, "arch={}", self.arch)?;
+        writeln!(f, "virtualized={}", self.virtualized)?;
+        writeln!(f, "clock={:?}", self.clock)?;
+        writeln!(f, "clock_unsteered={}", self.clock.is_unsteered())?;
+        writeln!(f, "sleep_resolution={}", self.sleep_resolution.as_nanos())?;
+        writeln!(
+            f,
+            "sleep_oversleep_max={}",
+            self.sleep_oversleep_max.as_nanos()
+        )?;
+        match self.timer_slack {
+            Some(slack) => writeln!(f, "timer_slack={}", slack.as_nanos())?,
+            None => writeln!(f, "timer_slack=unsupported")?,
+        }
+        writeln!(f, "wait_strategy={}", self.wait_strategy)?;
+        writeln!(f, "spin_margin={}", self.spin_margin.as_nanos())?;
+        writeln!(f, "frame_time={}", self.frame_time.as_nanos())?;
+        writeln!(f, "frames={}", stats.frames())?;
+        writeln!(f, "elapsed={}", self.summary.elapsed().as_nanos())?;
+        writeln!(f, "frame_time_median={}", stats.median().as_nanos())?;
+        writeln!(f, "frame_time_p99={}", stats.p99().as_nanos())?;
+        writeln!(f, "frame_time_max={}", stats.max().as_nanos())?;
+        writeln!(f, "jitter={}", stats.jitter().as_nanos())?;
+        writeln!(f, "hitches={}", stats.hitches())?;
+        writeln!(f, "sleeping={}", wait_time.sleeping().as_nanos())?;
+        writeln!(f, "spinning={}", wait_time.spinning().as_nanos())?;
+        writeln!(f, "wakeups={}", self.wakeups.samples())?;
+        writeln!(f, "wakeup_p50={}", self.wakeups.percentile(0.5).as_nanos())?;
+        writeln!(f, "wakeup_p99={}", self.wakeups.percentile(0.99).as_nanos())?;
+        writeln!(f, "wakeup_max={}", self.wakeups.max().as_nanos())?;
+        for event in &self.events {
+            writeln!(f, "event {event}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Timer {
+    /// Collects the platform, sleep characteristics, wait
+    /// configuration and accuracy of the timer into a
+    /// [`DiagnosticsReport`], e.g. to attach to a bug report about
+    /// inaccurate timing.
+    ///
+    /// Measures the sleep resolution of the current thread,
+    /// which takes about 25ms. Call it from the thread running the
+    /// frames, after the frames in question.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(1000.);
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// let report = timer.diagnostics();
+    /// assert_eq!(report.wait_strategy(), "sleep+spin");
+    /// assert_eq!(report.summary().frame_time_stats().frames(), 10);
+    /// eprint!("{report}");
+    /// # }
+    /// ```
+    pub fn diagnostics(&self) -> DiagnosticsReport {
+        let oversleeps = context::measure_oversleeps();
+        DiagnosticsReport {
+            version: env!("CARGO_PKG_VERSION"),
+            os: consts::OS,
+            arch: consts::ARCH,
+            virtualized: is_virtualized(),
+            clock: self.clock,
+            sleep_resolution: oversleeps[oversleeps.len() / 2],
+            sleep_oversleep_max: oversleeps[oversleeps.len() - 1],
+            timer_slack: timer_slack::current(),
+            wait_strategy: self.wait_strategy_name(),
+            spin_margin: self.spin_margin,
+            frame_time: self.delta_time,
+            summary: self.session_summary(),
+            wakeups: self.wakeups.clone(),
+            events: self.events().copied().collect(),
+        }
+    }
+
+    /// name of the way the timer waits for frame targets,
+    /// in the order of precedence of [`Self::frame`]
+    fn wait_strategy_name(&self) -> &'static str {
+        if self.pacing == Pacing::External {
+            "external"
+        } else if self.wait_strategy.is_some() {
+            "custom"
+        } else if self.low_rate.is_some() {
+            "interruptible"
+        } else if self.high_rate {
+            "spin"
+        } else if self.high_precision {
+            "sleep+spin"
+        } else {
+            "sleep"
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// how often the monitor of the window is polled
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Monitor a window is shown on, as reported by a [`MonitorSource`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Monitor {
+    /// name or other identifier of the monitor
+    name: String,
+    /// refresh rate in hz
+    refresh_rate: f64,
+}
+
+impl Monitor {
+    /// Creates a monitor identified by `name` refreshing at
+    /// `refresh_rate` hz, e.g. from winit's `Window::current_monitor`
+    /// and `MonitorHandle::refresh_rate_millihertz`.
+    pub fn new(name: impl Into<String>, refresh_rate: f64) -> Self {
+        Self {
+            name: name.into(),
+            refresh_rate,
+        }
+    }
+
+    /// name or other identifier of the monitor
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// refresh rate in hz
+    pub fn refresh_rate(&self) -> f64 {
+        self.refresh_rate
+    }
+}
+
+/// Source of the [`Monitor`] polled by a [`Timer`],
+/// see [`Timer::monitor_source`]
+///
+/// Closures returning an `Option<Monitor>` implement this trait.
+pub trait MonitorSource: Send + Sync {
+    /// monitor the window is currently shown on,
+    /// [`None`] if it can not be determined
+    fn current_monitor(&mut self) -> Option<Monitor>;
+}
+
+impl<F: FnMut() -> Option<Monitor> + Send + Sync> MonitorSource for F {
+    fn current_monitor(&mut self) -> Option<Monitor> {
+        self()
+    }
+}
+
+/// A change of the [`Monitor`] a window is shown on
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorEvent {
+    /// the previous monitor, [`None`] for the first observed monitor
+    previous: Option<Monitor>,
+    /// the new monitor
+    monitor: Monitor,
+    /// the framerate target before the change
+    previous_fps: f64,
+    /// the framerate target after the change
+    fps: f64,
+}
+
+impl MonitorEvent {
+    /// the previous monitor, [`None`] for the first observed monitor
+    pub fn previous(&self) -> Option<&Monitor> {
+        self.previous.as_ref()
+    }
+
+    /// the new monitor
+    pub fn monitor(&self) -> &Monitor {
+        &self.monitor
+    }
+
+    /// the framerate target before the change
+    pub fn previous_fps(&self) -> f64 {
+        self.previous_fps
+    }
+
+    /// the framerate target after the change, equal to
+    /// [`Self::previous_fps`] without a [`Timer::monitor_policy`]
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+}
+
+type MonitorPolicy = Box<dyn FnMut(&Monitor) -> f64 + Send + Sync>;
+type MonitorHook = Box<dyn FnMut(&MonitorEvent) + Send + Sync>;
+
+/// monitor tracking state of a [`Timer`]
+pub(crate) struct MonitorTracking {
+    /// where the monitor is read from
+    source: Box<dyn MonitorSource>,
+    /// maps monitors to framerate targets, if configured
+    policy: Option<MonitorPolicy>,
+    /// called whenever the monitor changes
+    hooks: Vec<MonitorHook>,
+    /// last observed monitor
+    monitor: Option<Monitor>,
+    /// next time the monitor is polled
+    next_poll: Option<Instant>,
+}
+
+impl Timer {
+    /// Tracks the monitor the window is shown on, so moving the window
+    /// to a monitor with a different refresh rate can be detected,
+    /// see [`Self::on_monitor_change`] and [`Self::monitor_policy`].
+    ///
+    /// The monitor is polled from `source` every 100ms at the start of
+    /// a frame, e.g. from the windowing library of the application.
+    ///
+    /// Requires the `display` feature.
+    ///
+    /// # Arguments
+    /// * `source` - returns the monitor the window is shown on
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn monitor_source(mut self, source: impl MonitorSource + 'static) -> Self {
+        self.monitor = Some(MonitorTracking {
+            source: Box::new(source),
+            policy: None,
+            hooks: vec![],
+            monitor: None,
+            next_poll: None,
+        });
+        self
+    }
+
+    /// Adjusts the framerate target whenever the window lands on a
+    /// different monitor, e.g. to cap at the refresh rate of the
+    /// monitor instead of a fixed framerate that is wrong on others.
+    /// `policy` is called with the new [`Monitor`] and its result is
+    /// applied as the new framerate target, see [`Self::fps`].
+    /// Has no effect without a [`Self::monitor_source`].
+    ///
+    /// Requires the `display` feature.
+    ///
+    /// # Arguments
+    /// * `policy` - returns the framerate target for a monitor
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{sync::{Arc, Mutex}, time::Duration};
+    /// use fps_timer::{Monitor, Timer};
+    ///
+    /// let window = Arc::new(Mutex::new(Monitor::new("DP-1", 144.)));
+    /// let source = window.clone();
+    /// let mut timer = Timer::default()
+    ///     .fps(144.)
+    ///     .monitor_source(move || Some(source.lock().unwrap().clone()))
+    ///     .monitor_policy(|monitor| monitor.refresh_rate());
+    /// let events = Arc::new(Mutex::new(vec![]));
+    /// let log = events.clone();
+    /// timer.on_monitor_change(move |event| log.lock().unwrap().push(event.fps()));
+    ///
+    /// let start = timer.timebase_epoch();
+    /// timer.frame_at(start + Duration::from_millis(10));
+    /// // the window is moved to a 60hz monitor
+    /// *window.lock().unwrap() = Monitor::new("HDMI-1", 60.);
+    /// timer.frame_at(start + Duration::from_secs(1));
+    /// assert_eq!(*events.lock().unwrap(), [144., 60.]);
+    /// assert_eq!(timer.current_monitor().unwrap().name(), "HDMI-1");
+    /// ```
+    pub fn monitor_policy(
+        mut self,
+        policy: impl FnMut(&Monitor) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        if let Some(tracking) = &mut self.monitor {
+            tracking.policy = Some(Box::new(policy));
+        }
+        self
+    }
+
+    /// Registers a hook that is called with a [`MonitorEvent`] whenever
+    /// the window moves to a different monitor or the refresh rate of
+    /// its monitor changes, including the first observed monitor.
+    /// Has no effect without a [`Self::monitor_source`].
+    ///
+    /// Requires the `display` feature.
+    pub fn on_monitor_change(&mut self, hook: impl FnMut(&MonitorEvent) + Send + Sync + 'static) {
+        if let Some(tracking) = &mut self.monitor {
+            tracking.hooks.push(Box::new(hook));
+        }
+    }
+
+    /// Monitor the window was last observed on,
+    /// see [`Self::monitor_source`].
+    ///
+    /// Requires the `display` feature.
+    pub fn current_monitor(&self) -> Option<&Monitor> {
+        self.monitor.as_ref()?.monitor.as_ref()
+    }
+
+    /// polls the monitor if due and applies the policy
+    pub(crate) fn update_monitor(&mut self, now: Instant) {
+        let Some(tracking) = &mut self.monitor else {
+            return;
+        };
+        if tracking.next_poll.is_some_and(|next| now < next) {
+            return;
+        }
+        tracking.next_poll = Some(now + POLL_INTERVAL);
+        let Some(monitor) = tracking.source.current_monitor() else {
+            return;
+        };
+        if tracking.monitor.as_ref() == Some(&monitor) {
+            return;
+        }
+        let previous_fps = match self.delta_time {
+            Duration::ZERO => 0.,
+            delta => 1. / delta.as_secs_f64(),
+        };
+        let fps = match &mut tracking.policy {
+            Some(policy) => policy(&monitor),
+            None => previous_fps,
+        };
+        let event = MonitorEvent {
+            previous: tracking.monitor.replace(monitor.clone()),
+            monitor,
+            previous_fps,
+            fps,
+        };
+        for hook in &mut tracking.hooks {
+            hook(&event);
+        }
+        if tracking.policy.is_some() {
+            self.set_fps(fps);
+        }
+    }
+}
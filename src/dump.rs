@@ -0,0 +1,567 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Formatter},
+    fs, panic,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, Once, TryLockError, Weak,
+    },
+    time::{Duration, Instant},
+};
+
+use crate::{
+    high_rate::HIGH_RATE_BATCH,
+    seqlock::{nanos, nanos_since, SeqLock},
+    stats::{SessionSummary, SUMMARY_WORDS},
+    FrameInfo, Timer,
+};
+
+/// number of frames kept in the frame history by default
+const DEFAULT_HISTORY: usize = 120;
+
+/// number of words of a published [`FrameInfo`]
+const FRAME_WORDS: usize = 7;
+
+/// postmortems of all timers, written by the panic hook
+static POSTMORTEMS: Mutex<Vec<Weak<Postmortem>>> = Mutex::new(Vec::new());
+
+/// incremented by every dump request from outside the process
+static DUMP_REQUESTS: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(unix)]
+mod sys {
+    use std::{
+        ffi::{c_int, c_void},
+        mem, ptr,
+        sync::{atomic::Ordering, Once, OnceLock},
+    };
+
+    /// handler of SIGUSR1 before the dump handler was installed
+    static PREVIOUS: OnceLock<libc::sigaction> = OnceLock::new();
+
+    extern "C" fn on_signal(signal: c_int, info: *mut libc::siginfo_t, context: *mut c_void) {
+        // atomics are async-signal-safe
+        super::DUMP_REQUESTS.fetch_add(1, Ordering::Relaxed);
+        let Some(previous) = PREVIOUS.get() else {
+            return;
+        };
+        let handler = previous.sa_sigaction;
+        if handler == libc::SIG_DFL || handler == libc::SIG_IGN {
+            return;
+        }
+        // SAFETY: the previous handler was installed for SIGUSR1 with
+        // the signature indicated by its flags
+        unsafe {
+            if previous.sa_flags & libc::SA_SIGINFO != 0 {
+                let handler: extern "C" fn(c_int, *mut libc::siginfo_t, *mut c_void) =
+                    mem::transmute(handler);
+                handler(signal, info, context);
+            } else {
+                let handler: extern "C" fn(c_int) = mem::transmute(handler);
+                handler(signal);
+            }
+        }
+    }
+
+    /// counts SIGUSR1 as dump request, chaining to the handler
+    /// installed before
+    pub(super) fn install() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            // SAFETY: the actions are initialized before use and the
+            // handler only touches atomics before chaining
+            unsafe {
+                // read the previous handler first, so it is known
+                // before the first signal reaches the dump handler
+                let mut previous: libc::sigaction = mem::zeroed();
+                if libc::sigaction(libc::SIGUSR1, ptr::null(), &mut previous) != 0 {
+                    return;
+                }
+                let _ = PREVIOUS.set(previous);
+                let mut action: libc::sigaction = mem::zeroed();
+                action.sa_sigaction = on_signal as *const () as libc::sighandler_t;
+                action.sa_flags = libc::SA_SIGINFO | libc::SA_RESTART;
+                libc::sigemptyset(&mut action.sa_mask);
+                libc::sigaction(libc::SIGUSR1, &action, ptr::null_mut());
+            }
+        });
+    }
+}
+
+#[cfg(windows)]
+mod sys {
+    use std::{
+        ffi::c_void,
+        process, ptr,
+        sync::{atomic::Ordering, Once},
+        thread,
+    };
+
+    const INFINITE: u32 = u32::MAX;
+    const WAIT_OBJECT_0: u32 = 0;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn CreateEventW(
+            attributes: *mut c_void,
+            manual_reset: i32,
+            initial_state: i32,
+            name: *const u16,
+        ) -> *mut c_void;
+        fn WaitForSingleObject(handle: *mut c_void, millis: u32) -> u32;
+    }
+
+    /// counts signals of the named event `Local\fps-timer-dump-<pid>`
+    /// as dump request
+    pub(super) fn install() {
+        static INSTALL: Once = Once::new();
+        INSTALL.call_once(|| {
+            let name: Vec<u16> = format!("Local\\fps-timer-dump-{}", process::id())
+                .encode_utf16()
+                .chain([0])
+                .collect();
+            // SAFETY: `name` is a nul-terminated UTF-16 string
+            let event = unsafe { CreateEventW(ptr::null_mut(), 0, 0, name.as_ptr()) };
+            if event.is_null() {
+                return;
+            }
+            // the handle is owned by the thread for the lifetime of the process
+            let event = event as usize;
+            let _ = thread::Builder::new()
+                .name("fps-timer-dump".into())
+                .spawn(move || loop {
+                    // SAFETY: the event handle is never closed
+                    let woke = unsafe { WaitForSingleObject(event as *mut c_void, INFINITE) };
+                    if woke != WAIT_OBJECT_0 {
+                        return;
+                    }
+                    super::DUMP_REQUESTS.fetch_add(1, Ordering::Relaxed);
+                });
+        });
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+mod sys {
+    pub(super) fn install() {}
+}
+
+/// Session summary and recent frame history of a [`Timer`],
+/// as returned by [`Timer::stats_dump`].
+///
+/// The [`Display`] implementation writes a plain text report: a
+/// `session` line with the summary followed by a `frame` line per
+/// recorded frame, oldest first, with times in nanoseconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StatsDump {
+    /// summary of all frames since the timer was created
+    summary: SessionSummary,
+    /// most recent frames, oldest first
+    frames: Vec<FrameInfo>,
+}
+
+impl StatsDump {
+    /// summary of all frames since the timer was created
+    pub fn summary(&self) -> &SessionSummary {
+        &self.summary
+    }
+
+    /// most recent frames, oldest first, see [`Timer::frame_history`]
+    pub fn frames(&self) -> &[FrameInfo] {
+        &self.frames
+    }
+}
+
+impl Display for StatsDump {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let summary = &self.summary;
+        let stats = summary.frame_time_stats();
+        let wait_time = summary.wait_time();
+        writeln!(
+            f,
+            "session frames={} elapsed={} min={} median={} p99={} max={} jitter={} hitches={} sleeping={} spinning={}",
+            stats.frames(),
+            summary.elapsed().as_nanos(),
+            stats.min().as_nanos(),
+            stats.median().as_nanos(),
+            stats.p99().as_nanos(),
+            stats.max().as_nanos(),
+            stats.jitter().as_nanos(),
+            stats.hitches(),
+            wait_time.sleeping().as_nanos(),
+            wait_time.spinning().as_nanos(),
+        )?;
+        for frame in &self.frames {
+            writeln!(
+                f,
+                "frame epoch={} index={} delta={} discontinuity={}",
+                frame.epoch(),
+                frame.index(),
+                frame.delta_time().as_nanos(),
+                frame.is_discontinuity(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+type DumpSink = Box<dyn FnMut(&StatsDump) + Send + Sync>;
+
+/// pacing state published to the panic hook
+struct Postmortem {
+    /// file the dump is written to
+    path: PathBuf,
+    /// reference instant of the published frame timestamps
+    base: Instant,
+    /// most recent frames, at their frame count modulo the capacity
+    frames: Box<[SeqLock<FRAME_WORDS>]>,
+    /// number of frames published so far
+    published: AtomicU64,
+    /// summary as of the most recent frame
+    summary: SeqLock<SUMMARY_WORDS>,
+}
+
+impl Postmortem {
+    fn new(path: PathBuf, capacity: usize) -> Self {
+        Self {
+            path,
+            base: Instant::now(),
+            frames: (0..capacity).map(|_| SeqLock::new()).collect(),
+            published: AtomicU64::new(0),
+            summary: SeqLock::new(),
+        }
+    }
+
+    /// publishes `frame`, only ever called by the frame thread
+    fn publish_frame(&self, frame: FrameInfo) {
+        if self.frames.is_empty() {
+            return;
+        }
+        let published = self.published.load(Ordering::Relaxed);
+        let slot = &self.frames[(published % self.frames.len() as u64) as usize];
+        slot.write([
+            frame.index,
+            frame.epoch,
+            nanos(frame.delta),
+            nanos_since(self.base, frame.timestamp),
+            frame.discontinuity.into(),
+            nanos(frame.behind),
+            nanos(frame.period),
+        ]);
+        self.published.store(published + 1, Ordering::Release);
+    }
+
+    /// the published frames, oldest first
+    fn frames(&self) -> Vec<FrameInfo> {
+        let published = self.published.load(Ordering::Acquire);
+        let capacity = self.frames.len() as u64;
+        (published.saturating_sub(capacity)..published)
+            .filter_map(|n| self.frames[(n % capacity) as usize].read())
+            .map(|words| {
+                let [index, epoch, delta, timestamp, discontinuity, behind, period] = words;
+                FrameInfo {
+                    index,
+                    epoch,
+                    delta: Duration::from_nanos(delta),
+                    timestamp: self.base + Duration::from_nanos(timestamp),
+                    discontinuity: discontinuity != 0,
+                    behind: Duration::from_nanos(behind),
+                    period: Duration::from_nanos(period),
+                }
+            })
+            .collect()
+    }
+
+    /// writes the dump for a panic described by `panic`
+    fn write(&self, panic: &str) {
+        let Some(summary) = self.summary.read() else {
+            return;
+        };
+        let dump = StatsDump {
+            summary: SessionSummary::from_words(summary),
+            frames: self.frames(),
+        };
+        let _ = fs::write(&self.path, format!("panic {panic}\n{dump}"));
+    }
+}
+
+/// registers `postmortem` with the panic hook, installing the hook
+/// on first use
+fn register(postmortem: &Arc<Postmortem>) {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            // never block in the hook, e.g. when panicking while
+            // registering a postmortem
+            let postmortems: Vec<_> = match POSTMORTEMS.try_lock() {
+                Ok(postmortems) => postmortems.iter().filter_map(Weak::upgrade).collect(),
+                Err(TryLockError::Poisoned(e)) => {
+                    e.into_inner().iter().filter_map(Weak::upgrade).collect()
+                }
+                Err(TryLockError::WouldBlock) => Vec::new(),
+            };
+            for postmortem in postmortems {
+                postmortem.write(&info.to_string());
+            }
+            previous(info);
+        }));
+    });
+    let mut postmortems = POSTMORTEMS.lock().unwrap_or_else(|e| e.into_inner());
+    postmortems.retain(|postmortem| postmortem.strong_count() > 0);
+    postmortems.push(Arc::downgrade(postmortem));
+}
+
+/// recent frames and the dump trigger of a [`Timer`]
+pub(crate) struct FrameHistory {
+    /// most recent frames, oldest first
+    frames: VecDeque<FrameInfo>,
+    /// maximum number of frames kept
+    capacity: usize,
+    /// receives a dump on every request, if enabled
+    sink: Option<DumpSink>,
+    /// dump requests handled so far
+    seen: u64,
+    /// state published to the panic hook, if enabled
+    postmortem: Option<Arc<Postmortem>>,
+}
+
+impl Default for FrameHistory {
+    fn default() -> Self {
+        Self {
+            frames: VecDeque::with_capacity(DEFAULT_HISTORY),
+            capacity: DEFAULT_HISTORY,
+            sink: None,
+            seen: 0,
+            postmortem: None,
+        }
+    }
+}
+
+/// appends `frame` to `frames`, keeping at most `capacity` frames
+fn push(frames: &mut VecDeque<FrameInfo>, capacity: usize, frame: FrameInfo) {
+    if capacity == 0 {
+        return;
+    }
+    if frames.len() == capacity {
+        frames.pop_front();
+    }
+    frames.push_back(frame);
+}
+
+impl Timer {
+    /// Sets the number of recent frames kept for [`Self::stats_dump`],
+    /// `120` by default.
+    ///
+    /// # Arguments
+    /// * `len` - number of frames to keep, `0` disables the history
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn frame_history(mut self, len: usize) -> Self {
+        let history = &mut self.history;
+        history.capacity = len;
+        while history.frames.len() > len {
+            history.frames.pop_front();
+        }
+        if let Some(postmortem) = &history.postmortem {
+            let path = postmortem.path.clone();
+            self.start_postmortem(path);
+        }
+        self
+    }
+
+    /// Returns the session summary together with the most recent frames.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.).frame_history(3);
+    /// for _ in 0..5 {
+    ///     timer.frame();
+    /// }
+    /// let dump = timer.stats_dump();
+    /// assert_eq!(dump.summary().frame_time_stats().frames(), 5);
+    /// let indices: Vec<_> = dump.frames().iter().map(|f| f.index()).collect();
+    /// assert_eq!(indices, [2, 3, 4]);
+    /// assert!(dump.to_string().starts_with("session frames=5"));
+    /// ```
+    pub fn stats_dump(&self) -> StatsDump {
+        StatsDump {
+            summary: self.session_summary(),
+            frames: self.history.frames.iter().copied().collect(),
+        }
+    }
+
+    /// Delivers a [`StatsDump`] to `sink` whenever one is requested
+    /// from outside the process, to debug hangs and hitches in
+    /// production without attaching a debugger.
+    ///
+    /// On Unix, a dump is requested by sending `SIGUSR1` to the process
+    /// (`kill -USR1 <pid>`). On Windows, by signaling the named event
+    /// `Local\fps-timer-dump-<pid>`. The dump is taken at the end of
+    /// the next frame, on the thread running the frames. A `SIGUSR1`
+    /// handler installed before is kept and called after counting the
+    /// request.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Arguments
+    /// * `sink` - receives the dumps, e.g. writing them to a file
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{process::Command, sync::mpsc};
+    /// use fps_timer::Timer;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut timer = Timer::default()
+    ///     .fps(1000.)
+    ///     .dump_on_signal(move |dump| tx.send(dump.to_string()).unwrap());
+    /// # #[cfg(target_os = "linux")]
+    /// # {
+    /// Command::new("kill")
+    ///     .args(["-USR1", &std::process::id().to_string()])
+    ///     .status()?;
+    /// while rx.try_recv().is_err() {
+    ///     timer.frame();
+    /// }
+    /// # }
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// Handlers installed before keep working:
+    /// ```rust
+    /// # #[cfg(unix)]
+    /// # {
+    /// use std::sync::{atomic::{AtomicBool, Ordering}, mpsc};
+    /// use fps_timer::Timer;
+    ///
+    /// static RELOAD: AtomicBool = AtomicBool::new(false);
+    /// extern "C" fn reload(_: libc::c_int) {
+    ///     RELOAD.store(true, Ordering::Relaxed);
+    /// }
+    /// unsafe { libc::signal(libc::SIGUSR1, reload as *const () as libc::sighandler_t) };
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut timer = Timer::default()
+    ///     .fps(1000.)
+    ///     .dump_on_signal(move |dump| tx.send(dump.to_string()).unwrap());
+    /// unsafe { libc::raise(libc::SIGUSR1) };
+    /// timer.frame();
+    /// assert!(rx.try_recv().is_ok());
+    /// assert!(RELOAD.load(Ordering::Relaxed));
+    /// # }
+    /// ```
+    pub fn dump_on_signal(mut self, sink: impl FnMut(&StatsDump) + Send + Sync + 'static) -> Self {
+        sys::install();
+        self.history.seen = DUMP_REQUESTS.load(Ordering::Relaxed);
+        self.history.sink = Some(Box::new(sink));
+        self
+    }
+
+    /// Writes the session summary and the most recent frames (see
+    /// [`Self::frame_history`]) to the file at `path` if the process
+    /// panics, so crash reports include what frame pacing looked like
+    /// right before the failure.
+    ///
+    /// A single panic hook is installed for the whole process, running
+    /// before the previously installed hook and writing the postmortems
+    /// of all timers that are still alive. Frames are published to the
+    /// hook without locking. The file is only written if at least one
+    /// frame was completed and is overwritten by later panics.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Arguments
+    /// * `path` - file the postmortem is written to
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let path = std::env::temp_dir().join("fps-timer-postmortem-doc.txt");
+    /// let mut timer = Timer::default().fps(0.).panic_postmortem(&path);
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// let result = std::panic::catch_unwind(|| panic!("render thread died"));
+    /// assert!(result.is_err());
+    /// let postmortem = std::fs::read_to_string(&path)?;
+    /// assert!(postmortem.contains("render thread died"));
+    /// assert!(postmortem.contains("session frames=10"));
+    /// assert_eq!(postmortem.lines().filter(|l| l.starts_with("frame ")).count(), 10);
+    /// # std::fs::remove_file(&path)?;
+    ///
+    /// // every live timer writes its own postmortem
+    /// let other_path = std::env::temp_dir().join("fps-timer-postmortem-doc-2.txt");
+    /// let mut other = Timer::default().fps(0.).panic_postmortem(&other_path);
+    /// other.frame();
+    /// drop(Timer::default().panic_postmortem(&other_path));
+    /// let result = std::panic::catch_unwind(|| panic!("audio thread died"));
+    /// assert!(result.is_err());
+    /// assert!(std::fs::read_to_string(&path)?.contains("audio thread died"));
+    /// assert!(std::fs::read_to_string(&other_path)?.contains("session frames=1"));
+    /// # std::fs::remove_file(&path)?;
+    /// # std::fs::remove_file(&other_path)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn panic_postmortem(mut self, path: impl Into<PathBuf>) -> Self {
+        self.start_postmortem(path.into());
+        self
+    }
+
+    /// publishes the frame history to the panic hook, writing
+    /// postmortems to `path`
+    fn start_postmortem(&mut self, path: PathBuf) {
+        let postmortem = Arc::new(Postmortem::new(path, self.history.capacity));
+        for &frame in &self.history.frames {
+            postmortem.publish_frame(frame);
+        }
+        if self.framecount > 0 {
+            postmortem.summary.write(self.stats.session().to_words());
+        }
+        register(&postmortem);
+        self.history.postmortem = Some(postmortem);
+    }
+
+    /// records the most recent frame in the frame history
+    pub(crate) fn record_history(&mut self) {
+        let frame = self.last_frame;
+        let history = &mut self.history;
+        push(&mut history.frames, history.capacity, frame);
+        if let Some(postmortem) = &history.postmortem {
+            postmortem.publish_frame(frame);
+            // summarizing the session is comparatively expensive
+            if !self.high_rate || self.framecount.is_multiple_of(HIGH_RATE_BATCH) {
+                postmortem.summary.write(self.stats.session().to_words());
+            }
+        }
+    }
+
+    /// delivers a dump if one was requested since the last frame
+    pub(crate) fn poll_dump(&mut self) {
+        if self.history.sink.is_none() {
+            return;
+        }
+        let requests = DUMP_REQUESTS.load(Ordering::Relaxed);
+        if requests == self.history.seen {
+            return;
+        }
+        self.history.seen = requests;
+        let dump = self.stats_dump();
+        if let Some(sink) = &mut self.history.sink {
+            sink(&dump);
+        }
+    }
+}
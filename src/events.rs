@@ -0,0 +1,205 @@
+use std::{
+    collections::VecDeque,
+    fmt::{self, Display, Formatter},
+    time::{Duration, Instant},
+};
+
+use crate::Timer;
+
+/// number of events kept by default
+const DEFAULT_EVENTS: usize = 64;
+/// gap between two frames treated as a suspend of the process
+/// or the system rather than a hitch
+const SUSPEND_GAP: Duration = Duration::from_secs(1);
+
+/// What happened in a [`TimerEvent`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimerEventKind {
+    /// the frame was `behind` its target by more than the slack
+    /// and the schedule was re-based
+    Rebase { behind: Duration },
+    /// the frame took `frame_time`, more than twice the target
+    /// frametime or the smoothed workload
+    Hitch { frame_time: Duration },
+    /// no frame completed for `gap`, e.g. because the process was
+    /// stopped or the system suspended
+    Suspend { gap: Duration },
+    /// the target frametime changed, e.g. by a profile switch
+    FrameTimeChanged { from: Duration, to: Duration },
+}
+
+/// A notable event in the history of a [`Timer`],
+/// see [`Timer::events`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimerEvent {
+    /// index of the frame the event occurred in
+    frame: u64,
+    /// instant the event occurred
+    timestamp: Instant,
+    /// what happened
+    kind: TimerEventKind,
+}
+
+impl TimerEvent {
+    /// index of the frame the event occurred in,
+    /// see [`crate::FrameInfo::index`]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// instant the event occurred
+    pub fn timestamp(&self) -> Instant {
+        self.timestamp
+    }
+
+    /// what happened
+    pub fn kind(&self) -> TimerEventKind {
+        self.kind
+    }
+}
+
+impl Display for TimerEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            TimerEventKind::Rebase { behind } => {
+                write!(
+                    f,
+                    "rebase frame={} behind={}",
+                    self.frame,
+                    behind.as_nanos()
+                )
+            }
+            TimerEventKind::Hitch { frame_time } => write!(
+                f,
+                "hitch frame={} frame_time={}",
+                self.frame,
+                frame_time.as_nanos()
+            ),
+            TimerEventKind::Suspend { gap } => {
+                write!(f, "suspend frame={} gap={}", self.frame, gap.as_nanos())
+            }
+            TimerEventKind::FrameTimeChanged { from, to } => write!(
+                f,
+                "frame_time_changed frame={} from={} to={}",
+                self.frame,
+                from.as_nanos(),
+                to.as_nanos()
+            ),
+        }
+    }
+}
+
+/// bounded history of notable events of a [`Timer`]
+#[derive(Clone, Debug)]
+pub(crate) struct EventLog {
+    /// most recent events, oldest first
+    events: VecDeque<TimerEvent>,
+    /// maximum number of events kept
+    capacity: usize,
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self {
+            events: VecDeque::with_capacity(DEFAULT_EVENTS),
+            capacity: DEFAULT_EVENTS,
+        }
+    }
+}
+
+impl EventLog {
+    fn push(&mut self, event: TimerEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(event);
+    }
+}
+
+impl Timer {
+    /// Sets the number of notable events kept for [`Self::events`],
+    /// `64` by default.
+    ///
+    /// # Arguments
+    /// * `len` - number of events to keep, `0` disables the event log
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn event_log(mut self, len: usize) -> Self {
+        let events = &mut self.events.events;
+        while events.len() > len {
+            events.pop_front();
+        }
+        self.events.capacity = len;
+        self
+    }
+
+    /// Notable events of the timer, oldest first: re-based schedules,
+    /// hitches, suspend gaps and changes of the target frametime.
+    ///
+    /// The timer always keeps the most recent events (see
+    /// [`Self::event_log`]), so they are available for post-hoc
+    /// analysis without attaching a sink in advance.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::{Timer, TimerEventKind};
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// let start = Instant::now();
+    /// timer.frame_at(start + Duration::from_millis(10));
+    /// // the process was stopped for two seconds
+    /// timer.frame_at(start + Duration::from_millis(2010));
+    /// timer.fps_governor().set_fps(50.);
+    /// timer.frame();
+    ///
+    /// let kinds: Vec<_> = timer.events().map(|event| event.kind()).collect();
+    /// assert!(matches!(kinds[0], TimerEventKind::Rebase { .. }));
+    /// assert!(matches!(kinds[1], TimerEventKind::Suspend { .. }));
+    /// assert!(matches!(kinds[2], TimerEventKind::FrameTimeChanged { .. }));
+    /// ```
+    pub fn events(&self) -> impl Iterator<Item = &TimerEvent> + '_ {
+        self.events.events.iter()
+    }
+
+    /// records the events of the frame that just completed
+    /// after `frame_time`
+    pub(crate) fn record_frame_events(&mut self, frame_time: Duration) {
+        let info = self.last_frame;
+        let event = |kind| TimerEvent {
+            frame: info.index,
+            timestamp: info.timestamp,
+            kind,
+        };
+        if info.discontinuity {
+            self.events.push(event(TimerEventKind::Rebase {
+                behind: info.behind,
+            }));
+        }
+        let hitch_threshold = self.delta_time.max(self.work_avg).saturating_mul(2);
+        let hitch_threshold = hitch_threshold + self.capture_slack();
+        if frame_time > hitch_threshold.max(SUSPEND_GAP) {
+            self.events
+                .push(event(TimerEventKind::Suspend { gap: frame_time }));
+        } else if frame_time > hitch_threshold {
+            self.events
+                .push(event(TimerEventKind::Hitch { frame_time }));
+        }
+    }
+
+    /// records a change of the target frametime of a running timer
+    pub(crate) fn record_frame_time_change(&mut self, from: Duration, to: Duration) {
+        if from == to || self.framecount == 0 {
+            return;
+        }
+        self.events.push(TimerEvent {
+            frame: self.last_frame.index,
+            timestamp: self.previous,
+            kind: TimerEventKind::FrameTimeChanged { from, to },
+        });
+    }
+}
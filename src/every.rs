@@ -0,0 +1,118 @@
+use std::time::{Duration, Instant};
+
+use crate::{duration_from_nanos, Timer, MAX_INTERVAL};
+
+/// Cadence of an [`Every`] helper
+#[derive(Clone, Debug)]
+enum Cadence {
+    /// due every `interval`, next at `next`
+    Time { interval: Duration, next: Instant },
+    /// due every `interval` calls to [`Every::tick`]
+    Frames { interval: u64, ticks: u64 },
+}
+
+/// Helper for periodic tasks inside the frame loop.
+///
+/// Created through [`Timer::every`] or [`Timer::every_frames`].
+/// [`Self::tick`] returns `true` whenever the task is due.
+/// The schedule is anchored at creation, so it does not drift
+/// regardless of when exactly `tick()` is called.
+/// If ticks are missed, the task is due only once and the
+/// schedule skips ahead instead of firing repeatedly.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::Timer;
+///
+/// let mut timer = Timer::default().fps(0.);
+/// let mut autosave = timer.every(Duration::from_secs(30));
+/// let mut stats = timer.every_frames(10);
+///
+/// let mut due = 0;
+/// for _ in 0..100 {
+///     timer.frame();
+///     if autosave.tick() {
+///         // save the game
+///     }
+///     if stats.tick() {
+///         due += 1;
+///     }
+/// }
+/// assert_eq!(due, 10);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Every {
+    cadence: Cadence,
+}
+
+impl Every {
+    /// Returns `true` if the task is due, reading the current time
+    /// for time based cadences.
+    pub fn tick(&mut self) -> bool {
+        self.tick_at(Instant::now())
+    }
+
+    /// Same as [`Self::tick`] but uses `now` as the current time.
+    pub fn tick_at(&mut self, now: Instant) -> bool {
+        match &mut self.cadence {
+            Cadence::Time { interval, next } => {
+                if now < *next {
+                    return false;
+                }
+                if interval.is_zero() {
+                    *next = now;
+                    return true;
+                }
+                // skip all intervals that were missed entirely
+                let missed = (now - *next).as_nanos() / interval.as_nanos();
+                *next += duration_from_nanos((missed + 1) * interval.as_nanos());
+                true
+            }
+            Cadence::Frames { interval, ticks } => {
+                *ticks += 1;
+                if *ticks >= *interval {
+                    *ticks = 0;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+}
+
+impl Timer {
+    /// Creates an [`Every`] helper that is due every `interval`,
+    /// starting from the previous frame.
+    ///
+    /// # Arguments
+    /// * `interval` - time between two due ticks
+    pub fn every(&self, interval: Duration) -> Every {
+        let interval = interval.min(MAX_INTERVAL);
+        Every {
+            cadence: Cadence::Time {
+                interval,
+                next: self.previous + interval,
+            },
+        }
+    }
+
+    /// Creates an [`Every`] helper that is due every `frames`
+    /// calls to [`Every::tick`], which is meant to be ticked once per frame.
+    ///
+    /// # Arguments
+    /// * `frames` - number of ticks between two due ticks
+    ///
+    /// # Panics
+    /// if `frames` is zero
+    pub fn every_frames(&self, frames: u64) -> Every {
+        assert!(frames > 0, "frame interval must be at least 1");
+        Every {
+            cadence: Cadence::Frames {
+                interval: frames,
+                ticks: 0,
+            },
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{Log, LogSink};
+
+type RotateHook = Box<dyn FnMut(&Path) + Send + Sync>;
+
+/// [`LogSink`] appending interval statistics to a file,
+/// rotating it by size and age.
+///
+/// Every [`Log`] is written as one line, prefixed with the wall-clock
+/// time in milliseconds since the unix epoch (see the [`std::fmt::Display`]
+/// implementation of [`Log`]). When the file exceeds the maximum size or
+/// age, it is renamed to `<path>.1`, older files are shifted to
+/// `<path>.2`, `<path>.3`, ... and the oldest file is removed.
+///
+/// Compression of rotated files is left to the [`Self::on_rotate`]
+/// hook, which receives the path of every freshly rotated file.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{FileSink, Timer};
+///
+/// # let dir = std::env::temp_dir().join(format!("fps-timer-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir).unwrap();
+/// let path = dir.join("pacing.log");
+/// let sink = FileSink::new(&path)?
+///     .max_size(16 * 1024 * 1024)
+///     .max_age(Duration::from_secs(24 * 60 * 60))
+///     .max_files(14);
+/// let mut timer = Timer::default()
+///     .fps(1000.)
+///     .log_interval(Duration::from_millis(5))
+///     .log_sink(sink);
+/// for _ in 0..20 {
+///     timer.frame();
+/// }
+/// assert!(std::fs::read_to_string(&path)?.contains("frames="));
+/// # std::fs::remove_dir_all(&dir)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct FileSink {
+    /// path of the current file
+    path: PathBuf,
+    /// the current file
+    file: File,
+    /// size of the current file in bytes
+    size: u64,
+    /// instant the current file was opened
+    opened: Instant,
+    /// maximum size of a file before it is rotated
+    max_size: Option<u64>,
+    /// maximum age of a file before it is rotated
+    max_age: Option<Duration>,
+    /// number of rotated files to keep
+    max_files: u32,
+    /// called with the path of every rotated file
+    on_rotate: Option<RotateHook>,
+}
+
+impl FileSink {
+    /// Opens `path` for appending, creating it if it doesn't exist.
+    ///
+    /// By default, files are never rotated and 5 rotated files are kept.
+    pub fn new(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_owned();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            size,
+            opened: Instant::now(),
+            max_size: None,
+            max_age: None,
+            max_files: 5,
+            on_rotate: None,
+        })
+    }
+
+    /// Rotates the file once it exceeds `bytes` bytes.
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// Rotates the file once it has been written to for `age`.
+    pub fn max_age(mut self, age: Duration) -> Self {
+        self.max_age = Some(age);
+        self
+    }
+
+    /// Keeps `files` rotated files, `0` meaning rotated files are removed.
+    pub fn max_files(mut self, files: u32) -> Self {
+        self.max_files = files;
+        self
+    }
+
+    /// Calls `hook` with the path of every rotated file,
+    /// e.g. to compress it.
+    pub fn on_rotate(mut self, hook: impl FnMut(&Path) + Send + Sync + 'static) -> Self {
+        self.on_rotate = Some(Box::new(hook));
+        self
+    }
+
+    /// path of the `n`th rotated file
+    fn rotated(&self, n: u32) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{n}"));
+        path.into()
+    }
+
+    /// whether the current file is due for rotation
+    fn due(&self) -> bool {
+        self.max_size.is_some_and(|max| self.size >= max)
+            || self.max_age.is_some_and(|max| self.opened.elapsed() >= max)
+    }
+
+    /// shifts the rotated files and starts a new file
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        if self.max_files == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            let _ = fs::remove_file(self.rotated(self.max_files));
+            for n in (1..self.max_files).rev() {
+                let _ = fs::rename(self.rotated(n), self.rotated(n + 1));
+            }
+            fs::rename(&self.path, self.rotated(1))?;
+        }
+        self.file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.size = 0;
+        self.opened = Instant::now();
+        if self.max_files > 0 {
+            let rotated = self.rotated(1);
+            if let Some(hook) = &mut self.on_rotate {
+                hook(&rotated);
+            }
+        }
+        Ok(())
+    }
+
+    /// writes `log` as a single line, rotating beforehand if due
+    fn write(&mut self, log: &Log) -> io::Result<()> {
+        if self.due() {
+            self.rotate()?;
+        }
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        let line = format!("{timestamp} {log}\n");
+        self.file.write_all(line.as_bytes())?;
+        self.size += line.len() as u64;
+        Ok(())
+    }
+}
+
+impl LogSink for FileSink {
+    fn log(&mut self, log: &Log) {
+        // a full disk must not take down the frame loop
+        let _ = self.write(log);
+    }
+
+    fn flush(&mut self, _timeout: Duration) {
+        if self.due() {
+            let _ = self.rotate();
+        }
+        let _ = self.file.flush();
+        let _ = self.file.sync_data();
+    }
+}
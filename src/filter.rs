@@ -0,0 +1,234 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::Timer;
+
+/// Filter applied by a [`Timer`] to the deltas returned by
+/// [`Timer::frame`], e.g. to smooth out jitter of the measured
+/// frametimes before they reach the simulation.
+///
+/// Built-in filters are [`NoFilter`], [`EmaFilter`], [`MedianFilter`]
+/// and [`KalmanFilter`]. Closures taking the raw delta and returning
+/// the filtered delta implement this trait as well.
+///
+/// Install a filter with [`Timer::delta_filter`].
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{DeltaFilter, Timer};
+///
+/// /// limits deltas to `max`, e.g. after a breakpoint
+/// struct Clamp {
+///     max: Duration,
+/// }
+///
+/// impl DeltaFilter for Clamp {
+///     fn filter(&mut self, delta: Duration) -> Duration {
+///         delta.min(self.max)
+///     }
+/// }
+///
+/// let max = Duration::from_millis(5);
+/// let mut timer = Timer::default().fps(100.).delta_filter(Clamp { max });
+/// assert!(timer.frame() <= max);
+/// ```
+pub trait DeltaFilter: Send + Sync {
+    /// Returns the filtered delta for the raw delta `delta`.
+    fn filter(&mut self, delta: Duration) -> Duration;
+}
+
+impl<F: FnMut(Duration) -> Duration + Send + Sync> DeltaFilter for F {
+    fn filter(&mut self, delta: Duration) -> Duration {
+        self(delta)
+    }
+}
+
+/// Filter returning deltas unchanged, see [`DeltaFilter`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NoFilter;
+
+impl DeltaFilter for NoFilter {
+    fn filter(&mut self, delta: Duration) -> Duration {
+        delta
+    }
+}
+
+/// Exponential moving average of the deltas, see [`DeltaFilter`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EmaFilter {
+    /// weight of the most recent delta
+    factor: f64,
+    /// current average, `None` before the first delta
+    average: Option<f64>,
+}
+
+impl EmaFilter {
+    /// Creates a filter with the smoothing factor `factor` in `(0, 1]`,
+    /// the weight of the most recent delta, `1` meaning no smoothing.
+    pub fn new(factor: f64) -> Self {
+        Self {
+            factor: factor.clamp(f64::EPSILON, 1.),
+            average: None,
+        }
+    }
+}
+
+impl DeltaFilter for EmaFilter {
+    fn filter(&mut self, delta: Duration) -> Duration {
+        let current = delta.as_secs_f64();
+        let average = match self.average {
+            // start from the first delta instead of zero
+            None => current,
+            Some(average) => average + (current - average) * self.factor,
+        };
+        self.average = Some(average);
+        Duration::from_secs_f64(average)
+    }
+}
+
+/// Moving median of the most recent deltas, see [`DeltaFilter`].
+///
+/// Unlike averages, the median ignores single outliers entirely,
+/// at the cost of lagging behind by half the window.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MedianFilter {
+    /// most recent deltas, oldest first
+    window: VecDeque<Duration>,
+    /// number of deltas in the median
+    len: usize,
+}
+
+impl MedianFilter {
+    /// Creates a filter returning the median of the `len` most recent
+    /// deltas, e.g. `5`. A length of `0` is treated as `1`.
+    pub fn new(len: usize) -> Self {
+        let len = len.max(1);
+        Self {
+            window: VecDeque::with_capacity(len),
+            len,
+        }
+    }
+}
+
+impl DeltaFilter for MedianFilter {
+    fn filter(&mut self, delta: Duration) -> Duration {
+        if self.window.len() == self.len {
+            self.window.pop_front();
+        }
+        self.window.push_back(delta);
+        let mut sorted: Vec<_> = self.window.iter().copied().collect();
+        sorted.sort_unstable();
+        sorted[sorted.len() / 2]
+    }
+}
+
+/// One-dimensional Kalman filter estimating the true frametime from
+/// noisy measurements, see [`DeltaFilter`].
+///
+/// The filter adapts its gain to the ratio of the expected change of
+/// the true frametime (`process_noise`) and the measurement jitter
+/// (`measurement_noise`): the larger the jitter relative to the change,
+/// the more it smooths.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KalmanFilter {
+    /// variance of the change of the true frametime per frame in s²
+    process_variance: f64,
+    /// variance of the measured frametimes in s²
+    measurement_variance: f64,
+    /// estimated frametime in s, `None` before the first delta
+    estimate: Option<f64>,
+    /// variance of the estimate in s²
+    variance: f64,
+}
+
+impl KalmanFilter {
+    /// Creates a filter for a true frametime changing by about
+    /// `process_noise` per frame (e.g. `100µs`), measured with a
+    /// jitter of about `measurement_noise` (e.g. `1ms`).
+    pub fn new(process_noise: Duration, measurement_noise: Duration) -> Self {
+        let process_variance = process_noise.as_secs_f64().powi(2);
+        let measurement_variance = measurement_noise.as_secs_f64().powi(2);
+        Self {
+            process_variance,
+            measurement_variance,
+            estimate: None,
+            variance: measurement_variance,
+        }
+    }
+}
+
+impl DeltaFilter for KalmanFilter {
+    fn filter(&mut self, delta: Duration) -> Duration {
+        let measurement = delta.as_secs_f64();
+        let Some(estimate) = self.estimate else {
+            self.estimate = Some(measurement);
+            return delta;
+        };
+        // predict: the frametime stays the same, with growing uncertainty
+        let variance = self.variance + self.process_variance;
+        // update with the measurement
+        let gain = match variance + self.measurement_variance {
+            0. => 1.,
+            total => variance / total,
+        };
+        let estimate = estimate + gain * (measurement - estimate);
+        self.variance = (1. - gain) * variance;
+        self.estimate = Some(estimate);
+        Duration::from_secs_f64(estimate.max(0.))
+    }
+}
+
+impl Timer {
+    /// Filters the deltas returned by [`Self::frame`] with `filter`,
+    /// see [`DeltaFilter`].
+    ///
+    /// Filtering happens inside the timer after late-frame
+    /// compensation, so [`FrameInfo::delta`](crate::FrameInfo::delta)
+    /// reports the filtered delta, while statistics and hooks keep using
+    /// measured frametimes. The time the filtered deltas lag behind the
+    /// unfiltered ones is tracked in [`Self::filter_lag`].
+    ///
+    /// # Arguments
+    /// * `filter` - filter applied to every delta
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::{MedianFilter, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(100.).delta_filter(MedianFilter::new(3));
+    /// timer.frame();
+    /// timer.frame();
+    /// // a single slow frame does not reach the simulation
+    /// thread::sleep(Duration::from_millis(30));
+    /// let delta = timer.frame();
+    /// assert!(delta < Duration::from_millis(20));
+    /// assert!(timer.filter_lag() > 0.01);
+    /// ```
+    pub fn delta_filter(mut self, filter: impl DeltaFilter + 'static) -> Self {
+        self.delta_filter = Some(Box::new(filter));
+        self.filter_lag = 0;
+        self
+    }
+
+    /// Sum of the unfiltered minus the filtered deltas in seconds,
+    /// i.e. how far the time accumulated from the deltas returned by
+    /// [`Self::frame`] lags behind because of [`Self::delta_filter`].
+    /// Negative if filtered deltas ran ahead, zero without a filter.
+    pub fn filter_lag(&self) -> f64 {
+        self.filter_lag as f64 * 1e-9
+    }
+
+    /// `delta` filtered by the delta filter, if any
+    pub(crate) fn filter_delta(&mut self, delta: Duration) -> Duration {
+        let Some(filter) = &mut self.delta_filter else {
+            return delta;
+        };
+        let filtered = filter.filter(delta);
+        self.filter_lag += delta.as_nanos() as i128 - filtered.as_nanos() as i128;
+        filtered
+    }
+}
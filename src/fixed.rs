@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// `duration` in seconds as unsigned Q32.32 fixed point,
+/// saturating at `u64::MAX` (about 136 years)
+fn q32_32(duration: Duration) -> u64 {
+    let nanos = duration.as_nanos();
+    // round to the nearest 2^-32 s
+    let fixed = ((nanos << 32) + 500_000_000) / 1_000_000_000;
+    u64::try_from(fixed).unwrap_or(u64::MAX)
+}
+
+/// `duration` in whole units of `unit` nanoseconds, saturating at `u64::MAX`
+fn whole(duration: Duration, unit: u128) -> u64 {
+    u64::try_from(duration.as_nanos() / unit).unwrap_or(u64::MAX)
+}
+
+impl Timer {
+    /// Frametime of the most recent frame in whole microseconds.
+    ///
+    /// The integer and fixed point accessors allow scripting VMs and
+    /// deterministic simulations that forbid floats to consume the
+    /// timer without conversions at the boundary.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.);
+    /// let start = Instant::now();
+    /// timer.frame_at(start);
+    /// timer.frame_at(start + Duration::from_micros(16_667));
+    /// assert_eq!(timer.delta_micros(), 16_667);
+    /// assert_eq!(timer.delta_nanos(), 16_667_000);
+    /// // 0.016667s in units of 2^-32s
+    /// assert_eq!(timer.delta_q32_32(), 71_584_220);
+    /// ```
+    pub fn delta_micros(&self) -> u64 {
+        whole(self.last_frame.delta, 1_000)
+    }
+
+    /// frametime of the most recent frame in nanoseconds
+    pub fn delta_nanos(&self) -> u64 {
+        whole(self.last_frame.delta, 1)
+    }
+
+    /// frametime of the most recent frame in seconds as
+    /// unsigned Q32.32 fixed point, rounded to the nearest `2^-32`s
+    pub fn delta_q32_32(&self) -> u64 {
+        q32_32(self.last_frame.delta)
+    }
+
+    /// Time from the creation of the timer (see
+    /// [`Self::timebase_epoch`]) to the end of the most recent frame
+    /// in whole microseconds.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.);
+    /// let epoch = timer.timebase_epoch();
+    /// timer.frame_at(epoch + Duration::from_millis(1500));
+    /// assert_eq!(timer.elapsed_micros(), 1_500_000);
+    /// assert_eq!(timer.elapsed_q32_32(), 3 << 31);
+    /// ```
+    pub fn elapsed_micros(&self) -> u64 {
+        whole(self.elapsed_since_epoch(), 1_000)
+    }
+
+    /// time from the creation of the timer to the end of the most
+    /// recent frame in nanoseconds
+    pub fn elapsed_nanos(&self) -> u64 {
+        whole(self.elapsed_since_epoch(), 1)
+    }
+
+    /// time from the creation of the timer to the end of the most
+    /// recent frame in seconds as unsigned Q32.32 fixed point
+    pub fn elapsed_q32_32(&self) -> u64 {
+        q32_32(self.elapsed_since_epoch())
+    }
+
+    fn elapsed_since_epoch(&self) -> Duration {
+        self.last_frame
+            .timestamp
+            .saturating_duration_since(self.timebase_epoch())
+    }
+}
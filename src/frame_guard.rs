@@ -0,0 +1,106 @@
+use std::{thread, time::Duration};
+
+use crate::Timer;
+
+/// Guard of a frame in progress, as returned by [`Timer::start_frame`].
+///
+/// Dropping the guard ends the frame like [`Timer::frame`], including
+/// the wait for the frame target, so the frame is ended on every path
+/// out of the scope holding the guard, e.g. early returns and `?`.
+/// While the thread is panicking, the frame is ended without waiting.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::Timer;
+///
+/// fn update(timer: &mut Timer, paused: bool) -> Option<()> {
+///     let mut frame = timer.start_frame();
+///     if paused {
+///         // the frame still ends and waits for its target
+///         return None;
+///     }
+///     let _dt = frame.dt();
+///     frame.scope("physics", || {
+///         // advance the simulation
+///     });
+///     Some(())
+/// }
+///
+/// let mut timer = Timer::default().fps(200.);
+/// update(&mut timer, false);
+/// update(&mut timer, true);
+/// assert_eq!(timer.frame_info().index(), 1);
+/// ```
+pub struct Frame<'a> {
+    /// the timer paced by the frame
+    timer: &'a mut Timer,
+}
+
+impl Frame<'_> {
+    /// Delta of the previous frame, i.e. the time to advance the
+    /// simulation by in this frame, as returned by [`Timer::frame`].
+    /// Zero for the first frame of a timer.
+    pub fn dt(&self) -> Duration {
+        self.timer.last_frame.delta
+    }
+
+    /// time left until the target of the frame,
+    /// zero once the target has passed
+    pub fn time_left(&self) -> Duration {
+        let now = self.timer.clock_now();
+        self.timer.next_target().saturating_duration_since(now)
+    }
+
+    /// Runs `f` as the named scope `name` of the frame,
+    /// see [`Timer::scope`].
+    pub fn scope<R>(&mut self, name: &'static str, f: impl FnOnce() -> R) -> R {
+        self.timer.scope(name, f)
+    }
+
+    /// Ends the frame without waiting for its target when the guard is
+    /// dropped, e.g. when the application is about to exit. The frame
+    /// schedule is kept, so the next frame waits for its target as usual.
+    pub fn cancel_wait(&mut self) {
+        self.timer.skip_next_wait = true;
+    }
+
+    /// the timer paced by the frame, e.g. to set checkpoints
+    pub fn timer(&mut self) -> &mut Timer {
+        self.timer
+    }
+}
+
+impl Drop for Frame<'_> {
+    fn drop(&mut self) {
+        if thread::panicking() {
+            self.timer.skip_next_wait = true;
+        }
+        self.timer.frame();
+    }
+}
+
+impl Timer {
+    /// Starts a frame, returning a guard that ends it when dropped,
+    /// see [`Frame`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// for _ in 0..3 {
+    ///     let frame = timer.start_frame();
+    ///     assert!(frame.time_left() <= Duration::from_millis(10));
+    /// }
+    ///
+    /// let mut frame = timer.start_frame();
+    /// frame.cancel_wait();
+    /// drop(frame);
+    /// assert!(timer.frame_info().delta_time() < Duration::from_millis(5));
+    /// ```
+    pub fn start_frame(&mut self) -> Frame<'_> {
+        Frame { timer: self }
+    }
+}
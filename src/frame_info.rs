@@ -0,0 +1,176 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// Information about the most recent frame of a [`Timer`],
+/// as returned by [`Timer::frame_info`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameInfo {
+    /// index of the frame within the counter epoch
+    pub(crate) index: u64,
+    /// counter epoch the index belongs to
+    pub(crate) epoch: u64,
+    /// frametime returned by [`Timer::frame`]
+    pub(crate) delta: Duration,
+    /// instant the frame ended
+    pub(crate) timestamp: Instant,
+    /// whether the frame target was re-based during the frame
+    pub(crate) discontinuity: bool,
+    /// how late the frame was for its target
+    pub(crate) behind: Duration,
+    /// target frametime during the frame
+    pub(crate) period: Duration,
+}
+
+/// Pacing outcome of a frame, as returned by [`Timer::frame_outcome`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameOutcome {
+    /// the frame target was reached by waiting (or the timer is uncapped)
+    OnTime,
+    /// the frame started after its target, within the slack the timer
+    /// catches up with
+    Late {
+        /// how late the frame was
+        by: Duration,
+    },
+    /// the frame fell behind by more than the slack and the schedule was
+    /// re-based, see [`FrameInfo::is_discontinuity`]
+    Rebased {
+        /// number of whole frametimes the frame was late by
+        dropped_equivalent_frames: u64,
+    },
+}
+
+impl FrameInfo {
+    /// Index of the frame, starting at `0` for the first frame
+    /// after the timer was created or [`Timer::reset_counters`] was called.
+    ///
+    /// The index increases by exactly one per call to [`Timer::frame`]
+    /// and wraps around to `0` after `u64::MAX`, which would take
+    /// millions of years even at very high framerates.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Counter epoch of the frame, incremented by every call to
+    /// [`Timer::reset_counters`]. `(epoch, index)` uniquely identifies
+    /// a frame of a timer.
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// frametime of the frame, as returned by [`Timer::frame`]
+    pub fn delta_time(&self) -> Duration {
+        self.delta
+    }
+
+    /// instant the frame ended
+    pub fn timestamp(&self) -> Instant {
+        self.timestamp
+    }
+
+    /// Returns `true` if the timer fell behind by more than its slack
+    /// (e.g. after a stall or a system suspend) and re-based its frame
+    /// target during this frame.
+    ///
+    /// The frametime of such a frame spans a gap in the schedule, so
+    /// physics and interpolation code should skip extrapolating across
+    /// it instead of guessing from the large delta.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// let start = Instant::now();
+    /// timer.frame_at(start + Duration::from_millis(10));
+    /// assert!(!timer.frame_info().is_discontinuity());
+    /// // a 1s stall exceeds the slack of two frames
+    /// timer.frame_at(start + Duration::from_secs(1));
+    /// assert!(timer.frame_info().is_discontinuity());
+    /// ```
+    pub fn is_discontinuity(&self) -> bool {
+        self.discontinuity
+    }
+
+    /// pacing outcome of the frame
+    pub fn outcome(&self) -> FrameOutcome {
+        if self.discontinuity {
+            let dropped = match self.period.as_nanos() {
+                0 => 0,
+                period => self.behind.as_nanos() / period,
+            };
+            FrameOutcome::Rebased {
+                dropped_equivalent_frames: u64::try_from(dropped).unwrap_or(u64::MAX),
+            }
+        } else if self.behind > Duration::ZERO {
+            FrameOutcome::Late { by: self.behind }
+        } else {
+            FrameOutcome::OnTime
+        }
+    }
+}
+
+impl Timer {
+    /// Returns information about the most recent frame.
+    ///
+    /// Before the first frame, the index is `0` and the
+    /// frametime is zero.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default().fps(0.);
+    /// timer.frame();
+    /// timer.frame();
+    /// assert_eq!(timer.frame_info().index(), 1);
+    ///
+    /// timer.reset_counters();
+    /// timer.frame();
+    /// let info = timer.frame_info();
+    /// assert_eq!((info.epoch(), info.index()), (1, 0));
+    /// ```
+    pub fn frame_info(&self) -> FrameInfo {
+        self.last_frame
+    }
+
+    /// Same as [`Self::frame`], returning the pacing outcome of the
+    /// frame instead of the frametime, which remains available as
+    /// [`FrameInfo::delta_time`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::{FrameOutcome, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// assert_eq!(timer.frame_outcome(), FrameOutcome::OnTime);
+    ///
+    /// let target = timer.next_target();
+    /// timer.frame_at(target + Duration::from_millis(3));
+    /// let outcome = timer.frame_info().outcome();
+    /// assert_eq!(outcome, FrameOutcome::Late { by: Duration::from_millis(3) });
+    ///
+    /// let target = timer.next_target();
+    /// timer.frame_at(target + Duration::from_millis(55));
+    /// let outcome = timer.frame_info().outcome();
+    /// assert_eq!(outcome, FrameOutcome::Rebased { dropped_equivalent_frames: 5 });
+    /// ```
+    pub fn frame_outcome(&mut self) -> FrameOutcome {
+        self.frame();
+        self.last_frame.outcome()
+    }
+
+    /// Restarts frame indices at `0` and starts a new counter epoch.
+    ///
+    /// Neither the frame schedule nor the logging interval are affected,
+    /// so this can be called at any time, e.g. when a new level is loaded,
+    /// without causing a hitch.
+    pub fn reset_counters(&mut self) {
+        // keep the number of frames in the current logging interval
+        self.prev_framecount = self.prev_framecount.wrapping_sub(self.framecount);
+        self.framecount = 0;
+        self.counter_epoch += 1;
+    }
+}
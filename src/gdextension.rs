@@ -0,0 +1,102 @@
+use godot::{classes::Engine, prelude::*};
+
+use crate::Timer;
+
+/// Godot node pacing the main loop of the engine with a [`Timer`],
+/// registered as the GDExtension class `FpsTimerNode`.
+///
+/// Add it to a scene (e.g. an autoload) to replace the frame limiter
+/// of the engine: it sets `Engine.max_fps` to `0` when it enters the
+/// tree and waits for the frame target at the end of every
+/// `_process`, so it should have the highest `process_priority` of
+/// the scene. The statistics of every logging interval are emitted
+/// with the `stats_updated` signal as a dictionary of
+/// [`crate::Log::fields`].
+///
+/// The class is registered by the `ExtensionLibrary` of the Rust
+/// module of the project that depends on this crate.
+///
+/// Requires the `godot` feature.
+///
+/// # Example
+/// ```gdscript
+/// extends Node
+///
+/// func _ready():
+///     var pacer := FpsTimerNode.new()
+///     pacer.target_fps = 144.0
+///     pacer.process_priority = 1000
+///     pacer.stats_updated.connect(func(stats): print(stats["fps"], " ", stats["jitter_ms"]))
+///     add_child(pacer)
+/// ```
+#[derive(GodotClass)]
+#[class(base = Node)]
+pub struct FpsTimerNode {
+    /// the timer pacing the frames
+    timer: Timer,
+    /// framerate target, `0` meaning uncapped
+    #[export]
+    #[var(set = set_target_fps)]
+    target_fps: f64,
+    /// frametime of the previous frame in seconds
+    frame_time: f64,
+    /// statistics of the most recent logging interval
+    stats: VarDictionary,
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl INode for FpsTimerNode {
+    fn init(base: Base<Node>) -> Self {
+        Self {
+            timer: Timer::default().fps(60.),
+            target_fps: 60.,
+            frame_time: 0.,
+            stats: VarDictionary::new(),
+            base,
+        }
+    }
+
+    fn ready(&mut self) {
+        Engine::singleton().set_max_fps(0);
+    }
+
+    fn process(&mut self, _delta: f64) {
+        self.frame_time = self.timer.frame().as_secs_f64();
+        if let Some(log) = self.timer.log() {
+            self.stats = log
+                .fields()
+                .into_iter()
+                .map(|(name, value)| (name.to_variant(), value.to_variant()))
+                .collect();
+            let stats = self.stats.clone();
+            self.signals().stats_updated().emit(&stats);
+        }
+    }
+}
+
+#[godot_api]
+impl FpsTimerNode {
+    /// emitted with the statistics of every logging interval
+    #[signal]
+    fn stats_updated(stats: VarDictionary);
+
+    /// sets the framerate target, `0` meaning uncapped
+    #[func]
+    fn set_target_fps(&mut self, fps: f64) {
+        self.target_fps = fps;
+        self.timer.set_fps(fps);
+    }
+
+    /// frametime of the previous frame in seconds
+    #[func]
+    fn frame_time(&self) -> f64 {
+        self.frame_time
+    }
+
+    /// statistics of the most recent logging interval, see [`crate::Log::fields`]
+    #[func]
+    fn stats(&self) -> VarDictionary {
+        self.stats.clone()
+    }
+}
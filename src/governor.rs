@@ -0,0 +1,145 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use crate::{Rate, Timer};
+
+/// framerate target pushed by [`FpsGovernor`]s, shared with the [`Timer`]
+#[derive(Debug, Default)]
+struct Shared {
+    /// bits of the most recently pushed framerate target
+    fps: AtomicU64,
+    /// whether `fps` was pushed since the last frame
+    pending: AtomicBool,
+}
+
+/// Handle changing the framerate target of a running [`Timer`] from
+/// anywhere, as returned by [`Timer::fps_governor`].
+///
+/// The handle is `Clone + Send + Sync` and pushing a target is a pair
+/// of atomic stores, so external controllers (e.g. a load balancer,
+/// a battery daemon or a network thread) can adjust the framerate
+/// without synchronizing with the frame loop. The timer applies the
+/// most recent target at the end of its next frame.
+#[derive(Clone, Debug)]
+pub struct FpsGovernor {
+    shared: Arc<Shared>,
+}
+
+impl FpsGovernor {
+    /// Sets the framerate target applied at the end of the next frame,
+    /// replacing targets that were not applied yet.
+    ///
+    /// # Arguments
+    /// * `fps` - new framerate target, see [`Timer::fps`]
+    pub fn set_fps(&self, fps: impl Into<Rate>) {
+        let fps = fps.into().as_hz();
+        self.shared.fps.store(fps.to_bits(), Ordering::Relaxed);
+        self.shared.pending.store(true, Ordering::Release);
+    }
+}
+
+/// A change of the framerate target pushed by an [`FpsGovernor`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FpsChange {
+    /// index of the frame after which the target changed
+    frame: u64,
+    /// the framerate target before the change
+    previous_fps: f64,
+    /// the new framerate target
+    fps: f64,
+}
+
+impl FpsChange {
+    /// index of the frame after which the target changed,
+    /// see [`crate::FrameInfo::index`]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// the framerate target before the change
+    pub fn previous_fps(&self) -> f64 {
+        self.previous_fps
+    }
+
+    /// the new framerate target
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+}
+
+type FpsChangeHook = Box<dyn FnMut(FpsChange) + Send + Sync>;
+
+/// framerate targets pushed from outside the frame loop of a [`Timer`]
+#[derive(Default)]
+pub(crate) struct Governor {
+    shared: Arc<Shared>,
+    /// called whenever a pushed target is applied
+    hooks: Vec<FpsChangeHook>,
+}
+
+impl Timer {
+    /// Returns a handle to change the framerate target of this timer
+    /// from other threads, see [`FpsGovernor`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{sync::{Arc, Mutex}, thread};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(1000.);
+    /// let changes = Arc::new(Mutex::new(vec![]));
+    /// let log = changes.clone();
+    /// timer.on_fps_change(move |change| log.lock().unwrap().push(change.fps()));
+    ///
+    /// // e.g. a battery daemon
+    /// let governor = timer.fps_governor();
+    /// thread::spawn(move || governor.set_fps(500.)).join().unwrap();
+    ///
+    /// timer.frame();
+    /// assert_eq!(*changes.lock().unwrap(), [500.]);
+    /// ```
+    pub fn fps_governor(&mut self) -> FpsGovernor {
+        let governor = self.governor.get_or_insert_with(Governor::default);
+        FpsGovernor {
+            shared: governor.shared.clone(),
+        }
+    }
+
+    /// Registers a hook that is called with an [`FpsChange`] whenever
+    /// a framerate target pushed by an [`FpsGovernor`] is applied.
+    pub fn on_fps_change(&mut self, hook: impl FnMut(FpsChange) + Send + Sync + 'static) {
+        self.governor
+            .get_or_insert_with(Governor::default)
+            .hooks
+            .push(Box::new(hook));
+    }
+
+    /// applies the framerate target pushed since the last frame, if any
+    pub(crate) fn apply_governor(&mut self) {
+        let Some(governor) = &mut self.governor else {
+            return;
+        };
+        if !governor.shared.pending.swap(false, Ordering::Acquire) {
+            return;
+        }
+        let fps = f64::from_bits(governor.shared.fps.load(Ordering::Relaxed));
+        let previous_fps = match self.delta_time {
+            Duration::ZERO => 0.,
+            delta => 1. / delta.as_secs_f64(),
+        };
+        let change = FpsChange {
+            frame: self.last_frame.index,
+            previous_fps,
+            fps,
+        };
+        for hook in &mut governor.hooks {
+            hook(change);
+        }
+        self.set_fps(fps);
+    }
+}
@@ -0,0 +1,74 @@
+use std::time::{Duration, Instant};
+
+use crate::{frame_time_from_fps, Timer};
+
+/// A group of phase-locked timers sharing one epoch.
+///
+/// All timers of a group run at integer divisors of the group's base
+/// rate and are aligned to a common epoch, so their deadlines coincide
+/// exactly and the rate relationship holds indefinitely.
+/// E.g. with a base rate of 120 Hz, a physics timer with divisor 1
+/// and a render timer with divisor 2 are phase-locked such that every
+/// second physics tick coincides with a render frame.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::TimerGroup;
+///
+/// let group = TimerGroup::new(120.);
+/// let mut physics = group.timer(1); // 120 Hz
+/// let mut render = group.timer(2); // 60 Hz
+/// # physics.frame();
+/// # render.frame();
+/// ```
+#[derive(Clone, Debug)]
+pub struct TimerGroup {
+    /// common epoch of all timers
+    epoch: Instant,
+    /// frametime at the base rate of the group
+    base_delta: Duration,
+}
+
+impl TimerGroup {
+    /// Creates a new group with the given base framerate.
+    ///
+    /// # Arguments
+    /// * `fps` - base framerate of the group
+    ///
+    /// # Panics
+    /// if `fps` is not positive
+    pub fn new(fps: f64) -> Self {
+        assert!(fps > 0., "base framerate must be positive");
+        Self::with_frame_time(frame_time_from_fps(fps))
+    }
+
+    /// Creates a new group with the given base frametime.
+    ///
+    /// # Arguments
+    /// * `delta` - frametime at the base rate of the group
+    pub fn with_frame_time(delta: Duration) -> Self {
+        Self {
+            epoch: Instant::now(),
+            base_delta: delta,
+        }
+    }
+
+    /// The common epoch of all timers in this group
+    pub fn epoch(&self) -> Instant {
+        self.epoch
+    }
+
+    /// Creates a timer running at `1 / divisor` of the base rate.
+    ///
+    /// # Arguments
+    /// * `divisor` - number of base periods per frame of the new timer
+    ///
+    /// # Panics
+    /// if `divisor` is zero
+    pub fn timer(&self, divisor: u32) -> Timer {
+        assert!(divisor > 0, "divisor must be at least 1");
+        let mut timer = Timer::default();
+        timer.anchor = Some(self.epoch);
+        timer.frame_time(self.base_delta.saturating_mul(divisor))
+    }
+}
@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+use crate::{Rate, Timer};
+
+impl Timer {
+    /// Sets a hard frame rate ceiling, which is never exceeded, not
+    /// even while catching up after a stall.
+    ///
+    /// The rate set with [`Self::fps`] is a soft target: frames that
+    /// fall behind by up to the slack of the timer are made up for
+    /// with shorter frames, which can burst at an arbitrary rate after
+    /// a stall. With a hard cap, every frame takes at least
+    /// `1 / fps` seconds, so catch-up bursts are spread out and
+    /// downstream consumers (e.g. encoders or network peers) see at
+    /// most `fps` frames per second. Frames that cannot be made up for
+    /// in time re-base the schedule as usual.
+    ///
+    /// The hard cap has no effect on uncapped timers or with
+    /// [`crate::Pacing::External`], and should be above the soft
+    /// target.
+    ///
+    /// # Arguments
+    /// * `fps` - maximum frame rate, non-positive and NaN values
+    ///   remove the hard cap
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// // 60fps soft target, catching up at no more than 240fps
+    /// let mut timer = Timer::default().fps(60.).hard_cap(240.);
+    /// timer.frame();
+    /// thread::sleep(Duration::from_millis(30));
+    /// timer.frame();
+    /// for _ in 0..3 {
+    ///     assert!(timer.frame() >= Duration::from_micros(4166));
+    /// }
+    /// ```
+    pub fn hard_cap(mut self, fps: impl Into<Rate>) -> Self {
+        self.hard_cap = fps.into().frame_time().as_duration();
+        self
+    }
+
+    /// `target` delayed to respect the hard cap
+    pub(crate) fn hard_capped(&self, target: Instant) -> Instant {
+        match self.hard_cap {
+            Duration::ZERO => target,
+            min => target.max(self.previous + min),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// number of frames between two updates of derived statistics
+/// in high-rate mode, e.g. the summary of a panic postmortem
+#[cfg(feature = "stats")]
+pub(crate) const HIGH_RATE_BATCH: u64 = 64;
+
+impl Timer {
+    /// Enables or disables high-rate mode, for frame rates of 1kHz and
+    /// more, e.g. input polling or haptics loops.
+    ///
+    /// At these rates, the remaining time of a frame is often shorter
+    /// than the oversleep of `thread::sleep`, so in high-rate mode
+    /// * the timer never sleeps and busy waits for the whole frame,
+    ///   fully occupying a CPU core, and
+    /// * statistics derived from the session, e.g. the summary of a
+    ///   [`Self::panic_postmortem`], are only updated every 64 frames
+    ///   to keep the per-frame overhead low.
+    ///
+    /// See `cargo run --release --example bench` for the achievable
+    /// rates and accuracy on a system.
+    ///
+    /// # Arguments
+    /// * `enabled` - whether to enable high-rate mode
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(4000.).high_rate(true);
+    /// timer.frame();
+    /// let start = Instant::now();
+    /// for _ in 0..400 {
+    ///     timer.frame();
+    /// }
+    /// assert!(start.elapsed() >= Duration::from_millis(99));
+    /// ```
+    pub fn high_rate(mut self, enabled: bool) -> Self {
+        self.high_rate = enabled;
+        self.high_precision |= enabled;
+        self
+    }
+
+    /// maximum busy wait of a high precision wait
+    pub(crate) fn effective_spin_margin(&self) -> Duration {
+        match self.high_rate {
+            // spinning for the whole frame
+            true => Duration::MAX,
+            false => self.spin_margin,
+        }
+    }
+}
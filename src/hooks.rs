@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+type FrameStartHook = Box<dyn FnMut(Instant) + Send + Sync>;
+type FrameEndHook = Box<dyn FnMut(Duration) + Send + Sync>;
+type RebaseHook = Box<dyn FnMut(Duration) + Send + Sync>;
+
+/// subscribers attached to the frame lifecycle of a [`crate::Timer`]
+#[derive(Default)]
+pub(crate) struct Hooks {
+    /// called at the beginning of every frame
+    frame_start: Vec<FrameStartHook>,
+    /// called at the end of every frame
+    frame_end: Vec<FrameEndHook>,
+    /// called whenever the frame target is re-based
+    target_rebased: Vec<RebaseHook>,
+}
+
+impl Hooks {
+    pub(crate) fn add_frame_start(&mut self, hook: FrameStartHook) {
+        self.frame_start.push(hook);
+    }
+
+    pub(crate) fn add_frame_end(&mut self, hook: FrameEndHook) {
+        self.frame_end.push(hook);
+    }
+
+    pub(crate) fn add_target_rebased(&mut self, hook: RebaseHook) {
+        self.target_rebased.push(hook);
+    }
+
+    pub(crate) fn has_frame_start(&self) -> bool {
+        !self.frame_start.is_empty()
+    }
+
+    pub(crate) fn frame_start(&mut self, now: Instant) {
+        for hook in &mut self.frame_start {
+            hook(now);
+        }
+    }
+
+    pub(crate) fn frame_end(&mut self, frame_time: Duration) {
+        for hook in &mut self.frame_end {
+            hook(frame_time);
+        }
+    }
+
+    pub(crate) fn target_rebased(&mut self, behind: Duration) {
+        for hook in &mut self.target_rebased {
+            hook(behind);
+        }
+    }
+}
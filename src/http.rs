@@ -0,0 +1,350 @@
+use std::{
+    fmt::Write as _,
+    io::{self, BufRead, BufReader, Write},
+    net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+    time::Duration,
+};
+
+use crate::{Log, LogSink};
+
+/// time between two checks for new connections or a stop request
+const ACCEPT_POLL: Duration = Duration::from_millis(10);
+/// maximum number of connections served at once, further
+/// connections are closed right away
+const MAX_CONNECTIONS: usize = 16;
+/// time a client may take to send its request
+const READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// live pacing statistics shared with the server thread
+#[derive(Clone, Debug, Default)]
+struct Metrics {
+    /// number of completed logging intervals
+    intervals: u64,
+    /// frames over all intervals
+    frames: u64,
+    /// hitches over all intervals
+    hitches: u64,
+    /// average framerate of the latest interval
+    fps: f64,
+    /// frametimes of the latest interval, in seconds
+    avg: f64,
+    min: f64,
+    median: f64,
+    p99: f64,
+    max: f64,
+    jitter: f64,
+    /// stability score of the latest interval
+    stability: f64,
+    /// profile of the latest interval
+    profile: Option<String>,
+}
+
+impl Metrics {
+    fn update(&mut self, log: &Log) {
+        let stats = log.frame_time_stats();
+        self.intervals += 1;
+        self.frames += stats.frames();
+        self.hitches += stats.hitches();
+        self.fps = log.fps_average();
+        self.avg = log.delta_time_avg().as_secs_f64();
+        self.min = stats.min().as_secs_f64();
+        self.median = stats.median().as_secs_f64();
+        self.p99 = stats.p99().as_secs_f64();
+        self.max = stats.max().as_secs_f64();
+        self.jitter = stats.jitter().as_secs_f64();
+        self.stability = log.stability_score();
+        self.profile = log.profile().map(str::to_owned);
+    }
+
+    /// gauges of the latest interval as `(name, help, value)`
+    fn gauges(&self) -> [(&'static str, &'static str, f64); 8] {
+        [
+            ("fps", "average framerate", self.fps),
+            ("frame_time_avg_seconds", "average frametime", self.avg),
+            ("frame_time_min_seconds", "shortest frametime", self.min),
+            ("frame_time_median_seconds", "median frametime", self.median),
+            (
+                "frame_time_p99_seconds",
+                "99th percentile frametime",
+                self.p99,
+            ),
+            ("frame_time_max_seconds", "longest frametime", self.max),
+            ("jitter_seconds", "frametime jitter", self.jitter),
+            ("stability_score", "stability score (0-100)", self.stability),
+        ]
+    }
+
+    /// Prometheus text exposition format
+    fn prometheus(&self) -> String {
+        let mut out = String::new();
+        let counters = [
+            ("frames_total", "frames in completed intervals", self.frames),
+            (
+                "hitches_total",
+                "hitches in completed intervals",
+                self.hitches,
+            ),
+            (
+                "intervals_total",
+                "completed logging intervals",
+                self.intervals,
+            ),
+        ];
+        for (name, help, value) in counters {
+            let _ = writeln!(out, "# HELP fps_timer_{name} {help}");
+            let _ = writeln!(out, "# TYPE fps_timer_{name} counter");
+            let _ = writeln!(out, "fps_timer_{name} {value}");
+        }
+        for (name, help, value) in self.gauges() {
+            let _ = writeln!(out, "# HELP fps_timer_{name} {help} of the latest interval");
+            let _ = writeln!(out, "# TYPE fps_timer_{name} gauge");
+            let _ = writeln!(out, "fps_timer_{name} {}", finite(value));
+        }
+        out
+    }
+
+    /// JSON object holding all metrics
+    fn json(&self) -> String {
+        let mut out = format!(
+            "{{\"intervals\":{},\"frames\":{},\"hitches\":{}",
+            self.intervals, self.frames, self.hitches
+        );
+        for (name, _, value) in self.gauges() {
+            let _ = write!(out, ",\"{name}\":{}", finite(value));
+        }
+        match &self.profile {
+            Some(profile) => {
+                let _ = write!(out, ",\"profile\":\"{}\"}}", json_escape(profile));
+            }
+            None => out.push_str(",\"profile\":null}"),
+        }
+        out
+    }
+}
+
+/// `value` escaped for a JSON string
+fn json_escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0}'..='\u{1f}' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// non-finite values (e.g. the fps before the first interval) as `0`
+fn finite(value: f64) -> f64 {
+    if value.is_finite() {
+        value
+    } else {
+        0.
+    }
+}
+
+/// Tiny HTTP endpoint exposing live pacing statistics,
+/// served from a background thread.
+///
+/// Each connection is served on its own short-lived thread, so a slow
+/// client does not block other scrapes. The server stops and releases
+/// the port when the last clone of it is dropped, e.g. together with
+/// the [`crate::Timer`] it is attached to.
+///
+/// The server is a [`LogSink`]: attach it to a [`crate::Timer`] with
+/// [`crate::Timer::log_sink`], and every completed logging interval
+/// updates the served statistics. Two routes are available:
+///
+/// * `/metrics` - Prometheus text exposition format
+/// * `/metrics.json` - the same statistics as JSON
+///
+/// Requires the `http` feature.
+///
+/// # Example
+/// ```rust
+/// use std::{io::{Read, Write}, net::TcpStream, time::Duration};
+/// use fps_timer::{StatsServer, Timer};
+///
+/// let server = StatsServer::bind("127.0.0.1:0")?;
+/// let addr = server.local_addr();
+/// let mut timer = Timer::default()
+///     .fps(1000.)
+///     .log_interval(Duration::from_millis(5))
+///     .log_sink(server);
+/// for _ in 0..20 {
+///     timer.frame();
+/// }
+///
+/// // an idle client does not block other requests
+/// let _idle = TcpStream::connect(addr)?;
+/// let mut stream = TcpStream::connect(addr)?;
+/// stream.write_all(b"GET /metrics.json HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+/// let mut response = String::new();
+/// stream.read_to_string(&mut response)?;
+/// assert!(response.starts_with("HTTP/1.1 200 OK"));
+/// assert!(response.contains("\"frames\":"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct StatsServer {
+    /// server thread, stopped when the last clone is dropped
+    server: Arc<Server>,
+}
+
+/// state shared between the server thread and the connection threads
+#[derive(Debug, Default)]
+struct Shared {
+    /// latest statistics
+    metrics: Mutex<Metrics>,
+    /// set when the server is dropped
+    stop: AtomicBool,
+    /// number of connections being served
+    connections: AtomicUsize,
+}
+
+/// the accepting thread of a [`StatsServer`]
+#[derive(Debug)]
+struct Server {
+    shared: Arc<Shared>,
+    /// address the server is listening on
+    addr: SocketAddr,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl StatsServer {
+    /// Starts serving statistics on `addr` from a background thread,
+    /// which stops when the server is dropped.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::net::TcpListener;
+    /// use fps_timer::StatsServer;
+    ///
+    /// let server = StatsServer::bind("127.0.0.1:0")?;
+    /// let addr = server.local_addr();
+    /// drop(server);
+    /// // the port is released
+    /// TcpListener::bind(addr)?;
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn bind(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let listener = TcpListener::bind(addr)?;
+        let addr = listener.local_addr()?;
+        // poll for connections, so the thread notices a stop request
+        listener.set_nonblocking(true)?;
+        let shared = Arc::new(Shared::default());
+        let accepting = shared.clone();
+        let thread = thread::Builder::new()
+            .name("fps-timer-http".into())
+            .spawn(move || accept(listener, accepting))?;
+        Ok(Self {
+            server: Arc::new(Server {
+                shared,
+                addr,
+                thread: Some(thread),
+            }),
+        })
+    }
+
+    /// address the server is listening on
+    pub fn local_addr(&self) -> SocketAddr {
+        self.server.addr
+    }
+}
+
+impl LogSink for StatsServer {
+    fn log(&mut self, log: &Log) {
+        let metrics = &self.server.shared.metrics;
+        let mut metrics = metrics.lock().unwrap_or_else(|e| e.into_inner());
+        metrics.update(log);
+    }
+}
+
+/// accepts connections on `listener` until the server is stopped,
+/// serving each of them on its own thread
+fn accept(listener: TcpListener, shared: Arc<Shared>) {
+    while !shared.stop.load(Ordering::Relaxed) {
+        let stream = match listener.accept() {
+            Ok((stream, _)) => stream,
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                thread::sleep(ACCEPT_POLL);
+                continue;
+            }
+            Err(_) => continue,
+        };
+        if shared.connections.fetch_add(1, Ordering::Relaxed) >= MAX_CONNECTIONS {
+            shared.connections.fetch_sub(1, Ordering::Relaxed);
+            continue;
+        }
+        let serving = shared.clone();
+        let spawned = thread::Builder::new()
+            .name("fps-timer-http-conn".into())
+            .spawn(move || {
+                // a misbehaving client must not stop the server
+                let _ = serve(stream, &serving.metrics);
+                serving.connections.fetch_sub(1, Ordering::Relaxed);
+            });
+        if spawned.is_err() {
+            shared.connections.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+/// answers a single request on `stream`
+fn serve(stream: TcpStream, metrics: &Mutex<Metrics>) -> io::Result<()> {
+    // accepted streams may inherit the non-blocking mode of the listener
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream);
+    let mut request = String::new();
+    reader.read_line(&mut request)?;
+    // skip the headers
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        header.clear();
+    }
+
+    let mut parts = request.split_whitespace();
+    let (method, path) = (parts.next(), parts.next());
+    let metrics = metrics.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let (status, content_type, body) = match (method, path) {
+        (Some("GET"), Some("/metrics")) => {
+            ("200 OK", "text/plain; version=0.0.4", metrics.prometheus())
+        }
+        (Some("GET"), Some("/metrics.json")) => ("200 OK", "application/json", metrics.json()),
+        (Some("GET"), _) => ("404 Not Found", "text/plain", "not found\n".into()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".into(),
+        ),
+    };
+    let mut stream = reader.into_inner();
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()
+}
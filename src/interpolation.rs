@@ -0,0 +1,158 @@
+use std::time::{Duration, Instant};
+
+use crate::{frame_time_from_fps, Timer};
+
+/// Interpolation state for a single rendered frame,
+/// as returned by [`InterpolationClock::sample`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Interpolation {
+    /// render time in (fractional) server ticks
+    render_tick: f64,
+    /// whether the render time lies beyond the newest received tick
+    extrapolating: bool,
+}
+
+impl Interpolation {
+    /// render time in (fractional) server ticks
+    pub fn render_tick(&self) -> f64 {
+        self.render_tick
+    }
+
+    /// tick to interpolate from
+    pub fn from_tick(&self) -> u64 {
+        self.render_tick.max(0.).floor() as u64
+    }
+
+    /// tick to interpolate to (`from_tick() + 1`)
+    pub fn to_tick(&self) -> u64 {
+        self.from_tick() + 1
+    }
+
+    /// interpolation factor between [`Self::from_tick`] and [`Self::to_tick`] in `[0, 1)`
+    pub fn alpha(&self) -> f64 {
+        self.render_tick.max(0.).fract()
+    }
+
+    /// `true` if no snapshot for [`Self::to_tick`] has been received yet,
+    /// i.e. the state has to be extrapolated
+    pub fn is_extrapolating(&self) -> bool {
+        self.extrapolating
+    }
+}
+
+/// Client side clock for fixed-tick networking.
+///
+/// Estimates the offset between the local clock and the server's tick
+/// clock from the receive times of snapshots, smoothing out network
+/// jitter, and derives a render time that lags behind the server by a
+/// configurable interpolation delay. For every rendered frame,
+/// [`Self::sample`] tells which two ticks to interpolate between.
+///
+/// # Example
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use fps_timer::InterpolationClock;
+///
+/// let mut clock = InterpolationClock::new(20.).interpolation_delay(2.);
+/// let start = Instant::now();
+/// // snapshots of ticks 0..10 arrive every 50ms
+/// for tick in 0..10 {
+///     clock.on_snapshot(tick, start + Duration::from_millis(50 * tick));
+/// }
+/// // 25ms after tick 9 arrived, render halfway between ticks 7 and 8
+/// let frame = clock.sample(start + Duration::from_millis(475));
+/// assert_eq!(frame.from_tick(), 7);
+/// assert!((frame.alpha() - 0.5).abs() < 1e-6);
+/// assert!(!frame.is_extrapolating());
+/// ```
+#[derive(Clone, Debug)]
+pub struct InterpolationClock {
+    /// duration of a server tick
+    tick: Duration,
+    /// interpolation delay in ticks
+    delay: f64,
+    /// smoothing factor for the clock offset
+    smoothing: f64,
+    /// local reference instant
+    epoch: Instant,
+    /// smoothed offset of server ticks relative to the local clock, in ticks
+    offset: Option<f64>,
+    /// newest tick received so far
+    newest: Option<u64>,
+}
+
+impl InterpolationClock {
+    /// Creates a clock for a server running at `tick_rate` ticks per second
+    ///
+    /// # Panics
+    /// if `tick_rate` is not positive
+    pub fn new(tick_rate: f64) -> Self {
+        assert!(tick_rate > 0., "tick rate must be positive");
+        Self {
+            tick: frame_time_from_fps(tick_rate),
+            delay: 2.,
+            smoothing: 0.1,
+            epoch: Instant::now(),
+            offset: None,
+            newest: None,
+        }
+    }
+
+    /// Sets how many ticks rendering lags behind the newest server state.
+    ///
+    /// Larger delays hide more network jitter at the cost of latency.
+    /// Defaults to `2`.
+    pub fn interpolation_delay(mut self, ticks: f64) -> Self {
+        self.delay = ticks;
+        self
+    }
+
+    /// Sets the smoothing factor in `(0, 1]` applied to clock offset
+    /// updates, `1` meaning no smoothing. Defaults to `0.1`.
+    pub fn smoothing(mut self, factor: f64) -> Self {
+        self.smoothing = factor.clamp(f64::EPSILON, 1.);
+        self
+    }
+
+    /// local time in ticks since the epoch
+    fn local_ticks(&self, time: Instant) -> f64 {
+        let since_epoch = time.saturating_duration_since(self.epoch);
+        since_epoch.as_secs_f64() / self.tick.as_secs_f64()
+    }
+
+    /// Feeds the receive time of the snapshot of server tick `tick`.
+    pub fn on_snapshot(&mut self, tick: u64, received: Instant) {
+        let sample = tick as f64 - self.local_ticks(received);
+        self.offset = Some(match self.offset {
+            // snap to the new offset if it is far off (e.g. after a stall)
+            Some(offset) if (sample - offset).abs() < self.delay.max(1.) * 2. => {
+                offset + (sample - offset) * self.smoothing
+            }
+            _ => sample,
+        });
+        self.newest = self.newest.max(Some(tick));
+    }
+
+    /// Returns the interpolation state for a frame rendered at `now`.
+    ///
+    /// Before the first snapshot is received, the render time is `0`.
+    pub fn sample(&self, now: Instant) -> Interpolation {
+        let Some(offset) = self.offset else {
+            return Interpolation {
+                render_tick: 0.,
+                extrapolating: true,
+            };
+        };
+        let render_tick = self.local_ticks(now) + offset - self.delay;
+        let newest = self.newest.unwrap_or_default() as f64;
+        Interpolation {
+            render_tick,
+            extrapolating: render_tick.floor() + 1. > newest,
+        }
+    }
+
+    /// Returns the interpolation state for the previous frame of `timer`.
+    pub fn sample_frame(&self, timer: &Timer) -> Interpolation {
+        self.sample(timer.previous)
+    }
+}
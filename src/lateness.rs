@@ -0,0 +1,235 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::Timer;
+
+/// share of the frametime a frame has to end after its target
+/// to count as late, ignoring the usual wakeup jitter
+const LATE_TOLERANCE: f64 = 0.05;
+
+/// Cause a [`Timer`] attributes the lateness of a frame to,
+/// see [`Timer::last_lateness`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LatenessCause {
+    /// the application called [`Timer::frame`] after the frame target,
+    /// i.e. its work took longer than the frame budget
+    WorkOverrun,
+    /// the thread woke up late from sleeping
+    Oversleep,
+    /// the thread was preempted while busy waiting
+    /// for a high precision target
+    SpinPreemption,
+    /// the frame was so late that the schedule was re-based,
+    /// dropping the frames it fell behind by
+    Rebase,
+}
+
+impl Display for LatenessCause {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::WorkOverrun => "work_overrun",
+            Self::Oversleep => "oversleep",
+            Self::SpinPreemption => "spin_preemption",
+            Self::Rebase => "rebase",
+        };
+        f.write_str(name)
+    }
+}
+
+/// How late a frame ended and why, see [`Timer::last_lateness`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lateness {
+    /// time the frame ended after its target
+    by: Duration,
+    /// largest contribution to the lateness
+    cause: LatenessCause,
+}
+
+impl Lateness {
+    /// time the frame ended after its target
+    pub fn by(&self) -> Duration {
+        self.by
+    }
+
+    /// largest contribution to the lateness
+    pub fn cause(&self) -> LatenessCause {
+        self.cause
+    }
+}
+
+/// Lateness of the late frames of a logging interval, split by cause,
+/// see [`crate::Log::lateness`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LatenessBreakdown {
+    /// number of late frames
+    late_frames: u64,
+    /// lateness caused by work overruns
+    work_overrun: Duration,
+    /// lateness caused by late wakeups from sleeping
+    oversleep: Duration,
+    /// lateness caused by preemption while spinning
+    spin_preemption: Duration,
+    /// lateness of frames that re-based the schedule
+    rebase: Duration,
+    /// number of frames that re-based the schedule
+    rebases: u64,
+}
+
+impl LatenessBreakdown {
+    /// number of late frames
+    pub fn late_frames(&self) -> u64 {
+        self.late_frames
+    }
+
+    /// lateness caused by the application calling [`Timer::frame`]
+    /// after the frame target
+    pub fn work_overrun(&self) -> Duration {
+        self.work_overrun
+    }
+
+    /// lateness caused by late wakeups from sleeping
+    pub fn oversleep(&self) -> Duration {
+        self.oversleep
+    }
+
+    /// lateness caused by preemption while busy waiting
+    pub fn spin_preemption(&self) -> Duration {
+        self.spin_preemption
+    }
+
+    /// lateness of frames that re-based the schedule
+    pub fn rebase(&self) -> Duration {
+        self.rebase
+    }
+
+    /// number of frames that re-based the schedule
+    pub fn rebases(&self) -> u64 {
+        self.rebases
+    }
+
+    /// The cause of most of the lateness, [`None`] without late frames.
+    ///
+    /// A root-cause hint for a cap that does not hold: work overruns
+    /// point at the application, oversleep at the sleep granularity of
+    /// the system (see [`Timer::high_precision`]) and spin preemption at
+    /// contention for the CPU.
+    pub fn dominant_cause(&self) -> Option<LatenessCause> {
+        if self.late_frames == 0 {
+            return None;
+        }
+        [
+            (self.work_overrun, LatenessCause::WorkOverrun),
+            (self.oversleep, LatenessCause::Oversleep),
+            (self.spin_preemption, LatenessCause::SpinPreemption),
+            (self.rebase, LatenessCause::Rebase),
+        ]
+        .into_iter()
+        .max_by_key(|(lateness, _)| *lateness)
+        .map(|(_, cause)| cause)
+    }
+
+    /// adds the lateness of `other`
+    #[cfg_attr(not(feature = "sinks"), allow(dead_code))]
+    pub(crate) fn add(&mut self, other: &LatenessBreakdown) {
+        self.late_frames += other.late_frames;
+        self.work_overrun += other.work_overrun;
+        self.oversleep += other.oversleep;
+        self.spin_preemption += other.spin_preemption;
+        self.rebase += other.rebase;
+        self.rebases += other.rebases;
+    }
+}
+
+/// lateness of the most recent frame and the logging interval
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct LatenessTracking {
+    /// lateness of the most recent frame, if it was late
+    last: Option<Lateness>,
+    /// lateness of the late frames of the logging interval
+    interval: LatenessBreakdown,
+}
+
+impl LatenessTracking {
+    /// returns and resets the lateness of the interval
+    pub(crate) fn take_interval(&mut self) -> LatenessBreakdown {
+        std::mem::take(&mut self.interval)
+    }
+}
+
+impl Timer {
+    /// How late the most recent frame ended after its target and the
+    /// cause the timer attributes it to, [`None`] if it was on time.
+    ///
+    /// Frames ending less than 5% of the frametime after their target
+    /// count as on time. Uncapped timers never report late frames.
+    /// The late frames of each logging interval are aggregated in
+    /// [`crate::Log::lateness`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{LatenessCause, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// let target = timer.next_target();
+    /// timer.frame_at(target + Duration::from_millis(5));
+    /// let lateness = timer.last_lateness().unwrap();
+    /// assert_eq!(lateness.cause(), LatenessCause::WorkOverrun);
+    /// assert_eq!(lateness.by(), Duration::from_millis(5));
+    ///
+    /// let target = timer.next_target();
+    /// timer.frame_at(target + Duration::from_millis(100));
+    /// assert_eq!(timer.last_lateness().unwrap().cause(), LatenessCause::Rebase);
+    /// ```
+    pub fn last_lateness(&self) -> Option<Lateness> {
+        self.lateness.last
+    }
+
+    /// attributes the lateness of the frame that just ended, `behind`
+    /// its target when [`Self::frame`] was called and woken up
+    /// `wakeup` after its wait target, if it waited
+    pub(crate) fn attribute_lateness(
+        &mut self,
+        behind: Duration,
+        rebased: bool,
+        wakeup: Duration,
+        spun: bool,
+    ) {
+        let tracking = &mut self.lateness;
+        let by = behind + wakeup;
+        if self.delta_time.is_zero() || by <= self.delta_time.mul_f64(LATE_TOLERANCE) {
+            tracking.last = None;
+            return;
+        }
+        let interval = &mut tracking.interval;
+        interval.late_frames += 1;
+        let late_cause = match rebased {
+            true => {
+                interval.rebase += behind;
+                interval.rebases += 1;
+                LatenessCause::Rebase
+            }
+            false => {
+                interval.work_overrun += behind;
+                LatenessCause::WorkOverrun
+            }
+        };
+        let wakeup_cause = match spun {
+            true => {
+                interval.spin_preemption += wakeup;
+                LatenessCause::SpinPreemption
+            }
+            false => {
+                interval.oversleep += wakeup;
+                LatenessCause::Oversleep
+            }
+        };
+        let cause = match behind >= wakeup {
+            true => late_cause,
+            false => wakeup_cause,
+        };
+        tracking.last = Some(Lateness { by, cause });
+    }
+}
@@ -1,9 +1,362 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
 use std::{
-    hint, thread,
-    time::{Duration, Instant},
+    collections::VecDeque,
+    fmt::{self, Display, Formatter},
+    hint,
+    sync::Arc,
+    thread,
+    time::{Duration, Instant, SystemTime},
 };
 
+#[cfg(feature = "stats")]
+mod adaptive_log;
+#[cfg(feature = "sinks")]
+mod aggregate;
+#[cfg(feature = "std")]
+mod asynchronous;
+#[cfg(feature = "std")]
+mod auto_precision;
+#[cfg(feature = "std")]
+mod average_rate;
+#[cfg(feature = "std")]
+mod bench;
+#[cfg(feature = "capi")]
+mod capi;
+#[cfg(feature = "std")]
+mod capture;
+#[cfg(feature = "stats")]
+mod checkpoint;
+#[cfg(feature = "std")]
+mod child;
+#[cfg(feature = "std")]
+mod clock;
+#[cfg(feature = "std")]
+mod compare;
+#[cfg(feature = "std")]
+mod compensation;
+#[cfg(feature = "std")]
+mod config;
+#[cfg(feature = "std")]
+mod context;
+#[cfg(feature = "std")]
+mod deadline_queue;
+#[cfg(feature = "std")]
+mod delta_floor;
+#[cfg(feature = "stats")]
+mod diagnostics;
+#[cfg(feature = "display")]
+mod display;
+#[cfg(feature = "stats")]
+mod dump;
+#[cfg(feature = "stats")]
+mod events;
+#[cfg(feature = "std")]
+mod every;
+#[cfg(feature = "sinks")]
+mod file_sink;
+#[cfg(feature = "std")]
+mod filter;
+#[cfg(feature = "std")]
+mod fixed;
+#[cfg(feature = "std")]
+mod frame_guard;
+#[cfg(feature = "std")]
+mod frame_info;
+#[cfg(feature = "godot")]
+mod gdextension;
+#[cfg(feature = "std")]
+mod governor;
+#[cfg(feature = "std")]
+mod group;
+#[cfg(feature = "std")]
+mod hard_cap;
+#[cfg(feature = "std")]
+mod high_rate;
+#[cfg(feature = "hooks")]
+mod hooks;
+#[cfg(feature = "http")]
+mod http;
+#[cfg(feature = "std")]
+mod interpolation;
+#[cfg(feature = "stats")]
+mod lateness;
+#[cfg(feature = "std")]
+mod log_alignment;
+#[cfg(feature = "std")]
+mod low_rate;
+#[cfg(feature = "std")]
+mod media;
+#[cfg(feature = "std")]
+mod midi;
+#[cfg(feature = "sinks")]
+mod net_sink;
+#[cfg(feature = "osc")]
+mod osc;
+#[cfg(feature = "std")]
+mod pacing;
+pub mod pacing_core;
+#[cfg(feature = "std")]
+mod pacing_state;
+#[cfg(feature = "std")]
+mod parallel;
+#[cfg(feature = "std")]
+mod precision;
+#[cfg(feature = "std")]
+mod preset;
+#[cfg(feature = "std")]
+mod profile;
+#[cfg(feature = "std")]
+mod quantize;
+#[cfg(feature = "std")]
+mod rate;
+#[cfg(feature = "std")]
+mod redraw;
+#[cfg(feature = "stats")]
+mod refresh;
+#[cfg(feature = "std")]
+mod render_skip;
+#[cfg(feature = "std")]
+mod replay;
+#[cfg(feature = "std")]
+mod reset;
+#[cfg(feature = "std")]
+mod run;
+#[cfg(feature = "std")]
+mod scheduler;
+#[cfg(feature = "std")]
+#[cfg_attr(not(feature = "scopes"), allow(dead_code))]
+mod scope;
+#[cfg(feature = "std")]
+mod seed;
+#[cfg(feature = "std")]
+mod seqlock;
+#[cfg(feature = "std")]
+mod session_time;
+#[cfg(feature = "std")]
+mod shutdown;
+#[cfg(feature = "std")]
+mod simulate;
+#[cfg(feature = "sinks")]
+mod sink;
+#[cfg(feature = "stats")]
+mod slo;
+#[cfg(feature = "std")]
+mod smoothing;
+#[cfg(feature = "stats")]
+mod snapshot;
+#[cfg(feature = "std")]
+mod soak;
+#[cfg(feature = "std")]
+pub mod spin_sleep_util;
+#[cfg(feature = "std")]
+#[cfg_attr(not(feature = "stats"), allow(dead_code))]
+mod stats;
+#[cfg(feature = "std")]
+mod sub_deadline;
+#[cfg(feature = "std")]
+mod swap_pacing;
+#[cfg(feature = "std")]
+mod swapchain;
+#[cfg(feature = "stats")]
+mod tags;
+#[cfg(feature = "stats")]
+mod telemetry;
+#[cfg(feature = "thermal")]
+mod thermal;
+#[cfg(feature = "std")]
+mod tick_loop;
+mod ticks;
+#[cfg(feature = "std")]
+mod timebase;
+#[cfg(feature = "std")]
+mod timer_slack;
+#[cfg(feature = "std")]
+mod transition;
+#[cfg(feature = "std")]
+mod tui;
+#[cfg(feature = "std")]
+mod virtualized;
+#[cfg(feature = "std")]
+mod visibility;
+#[cfg(feature = "std")]
+mod wait;
+#[cfg(feature = "std")]
+mod wakeup;
+#[cfg(feature = "std")]
+mod wall_epoch;
+#[cfg(feature = "std")]
+mod watchdog;
+#[cfg(feature = "wasm")]
+mod web;
+
+#[cfg(feature = "sinks")]
+pub use aggregate::{StatsAggregator, ThreadSink};
+#[cfg(feature = "futures-timer")]
+pub use asynchronous::FuturesTimerSleeper;
+#[cfg(feature = "tokio")]
+pub use asynchronous::TokioSleeper;
+#[cfg(feature = "std")]
+pub use asynchronous::{AsyncSleeper, ThreadSleep, ThreadSleeper};
+#[cfg(feature = "smol")]
+pub use asynchronous::{SmolSleep, SmolSleeper};
+#[cfg(feature = "std")]
+use average_rate::AverageRate;
+#[cfg(feature = "std")]
+pub use bench::{benchmark_sleeper, compare_sleepers, BenchmarkReport, BenchmarkResult, Sleeper};
+#[cfg(feature = "capi")]
+pub use capi::{
+    fps_timer_abi_version, fps_timer_config_default, fps_timer_frame, fps_timer_free,
+    fps_timer_log, fps_timer_new, fps_timer_set_fps, FpsTimer, FpsTimerConfig, FpsTimerLog,
+    FpsTimerStatus, FPS_TIMER_ABI_VERSION,
+};
+#[cfg(feature = "stats")]
+pub use checkpoint::{Checkpoint, LongFrame};
+#[cfg(feature = "std")]
+pub use child::ChildTimer;
+#[cfg(feature = "std")]
+pub use clock::Clock;
+#[cfg(feature = "std")]
+pub use compare::{PercentileDelta, RunComparison};
+#[cfg(feature = "std")]
+pub use config::TimerConfig;
+#[cfg(feature = "std")]
+pub use context::TimingContext;
+#[cfg(feature = "std")]
+pub use deadline_queue::{Deadline, DeadlineQueue, Deadlines};
+#[cfg(feature = "stats")]
+pub use diagnostics::DiagnosticsReport;
+#[cfg(feature = "display")]
+pub use display::{Monitor, MonitorEvent, MonitorSource};
+#[cfg(feature = "stats")]
+pub use dump::StatsDump;
+#[cfg(feature = "stats")]
+pub use events::{TimerEvent, TimerEventKind};
+#[cfg(feature = "std")]
+pub use every::Every;
+#[cfg(feature = "sinks")]
+pub use file_sink::FileSink;
+#[cfg(feature = "std")]
+pub use filter::{DeltaFilter, EmaFilter, KalmanFilter, MedianFilter, NoFilter};
+#[cfg(feature = "std")]
+pub use frame_guard::Frame;
+#[cfg(feature = "std")]
+pub use frame_info::{FrameInfo, FrameOutcome};
+#[cfg(feature = "godot")]
+pub use gdextension::FpsTimerNode;
+#[cfg(feature = "std")]
+pub use governor::{FpsChange, FpsGovernor};
+#[cfg(feature = "std")]
+pub use group::TimerGroup;
+#[cfg(feature = "hooks")]
+use hooks::Hooks;
+#[cfg(feature = "http")]
+pub use http::StatsServer;
+#[cfg(feature = "std")]
+pub use interpolation::{Interpolation, InterpolationClock};
+#[cfg(feature = "stats")]
+pub use lateness::{Lateness, LatenessBreakdown, LatenessCause};
+#[cfg(feature = "std")]
+pub use log_alignment::LogAlignment;
+#[cfg(feature = "std")]
+pub use low_rate::FrameInterrupt;
+#[cfg(feature = "std")]
+pub use media::{BufferTiming, PtsPacer};
+#[cfg(feature = "std")]
+pub use midi::{MidiClock, MIDI_CONTINUE, MIDI_START, MIDI_STOP, MIDI_TIMING_CLOCK};
+#[cfg(feature = "sinks")]
+pub use net_sink::NetSink;
+#[cfg(feature = "osc")]
+pub use osc::OscSink;
+#[cfg(feature = "std")]
+pub use pacing::Pacing;
+pub use pacing_core::FrameTimeStats;
+#[cfg(feature = "std")]
+use pacing_core::{duration_from_nanos, frame_time_from_fps, Schedule, MAX_INTERVAL};
+#[cfg(feature = "std")]
+pub use pacing_state::{PacingSample, PacingState};
+#[cfg(feature = "std")]
+pub use parallel::{FrameDeadline, ParallelSection};
+#[cfg(feature = "std")]
+pub use precision::Precision;
+#[cfg(feature = "std")]
+pub use preset::Preset;
+#[cfg(feature = "std")]
+pub use profile::{Profile, UnknownProfile};
+#[cfg(feature = "std")]
+pub use rate::{FrameTime, ParseRateError, Rate};
+#[cfg(feature = "std")]
+pub use redraw::RedrawScheduler;
+#[cfg(feature = "stats")]
+use refresh::RefreshEstimator;
+#[cfg(feature = "std")]
+pub use replay::{ParseRecordingError, RecordedEvent, Recorder, Recording, ReplayOutput};
+#[cfg(feature = "std")]
+pub use run::{run_at_fps, FrameContext};
+#[cfg(feature = "std")]
+pub use scheduler::Scheduler;
+#[cfg(feature = "std")]
+pub use scope::ScopeStats;
+#[cfg(feature = "std")]
+use scope::Scopes;
+#[cfg(feature = "std")]
+pub use seed::frame_seed;
+#[cfg(feature = "std")]
+pub use simulate::{ClockFault, InvariantViolation, Simulation, SimulationReport};
+#[cfg(feature = "sinks")]
+pub use sink::LogSink;
+#[cfg(feature = "stats")]
+pub use slo::{Slo, SloStatus};
+#[cfg(feature = "stats")]
+pub use snapshot::StatsSnapshot;
+#[cfg(feature = "std")]
+pub use soak::{SoakFailure, SoakReport};
+#[cfg(feature = "std")]
+use stats::FrameStats;
+#[cfg(feature = "std")]
+pub use stats::{SessionSummary, WaitTime};
+#[cfg(feature = "std")]
+pub use sub_deadline::SubDeadline;
+#[cfg(feature = "std")]
+use swap_pacing::SwapPacing;
+#[cfg(feature = "std")]
+pub use swapchain::QueueMode;
+#[cfg(feature = "std")]
+use swapchain::Swapchain;
+#[cfg(feature = "stats")]
+pub use telemetry::{SessionAggregate, SessionHistory, SessionRecord, SessionStore};
+#[cfg(feature = "thermal")]
+pub use thermal::{SysfsThermal, ThermalEvent, ThermalSource, ThermalState};
+#[cfg(feature = "std")]
+pub use tick_loop::{Tick, TickLoop};
+#[cfg(feature = "embassy")]
+pub use ticks::EmbassyTickClock;
+#[cfg(feature = "embedded-hal")]
+pub use ticks::HalTickClock;
+pub use ticks::{TickClock, TickTimer};
+#[cfg(feature = "std")]
+use timer_slack::TimerSlackGuard;
+#[cfg(feature = "std")]
+pub use transition::{Easing, Transition};
+#[cfg(feature = "std")]
+pub use tui::Wakeup;
+#[cfg(feature = "std")]
+pub use virtualized::is_virtualized;
+#[cfg(feature = "std")]
+pub use visibility::{BackgroundPolicy, Visibility};
+#[cfg(feature = "std")]
+pub use wait::WaitStrategy;
+#[cfg(feature = "std")]
+pub use wakeup::WakeupHistogram;
+#[cfg(feature = "wasm")]
+pub use web::WebTimer;
+
+#[cfg(feature = "std")]
 /// Timer instance
+///
+/// The timer is `Send + Sync`, so callbacks and strategies
+/// registered with it have to be `Send + Sync` as well.
 pub struct Timer {
     /// instant of the previous call to frame()
     previous: Instant,
@@ -25,17 +378,218 @@ pub struct Timer {
     max_delay_frames: u32,
     /// improved_accuracy
     high_precision: bool,
+    /// frame lifecycle subscribers
+    #[cfg(feature = "hooks")]
+    hooks: Hooks,
+    /// smoothed time spent between two calls to frame()
+    work_avg: Duration,
+    /// if set, frame targets are aligned to `anchor + k * delta_time`
+    anchor: Option<Instant>,
+    /// offset of the anchor set with [`Self::phase`]
+    phase: Duration,
+    /// wall-clock instant the frame targets are aligned to
+    wall_anchor: Option<SystemTime>,
+    /// frametime statistics
+    stats: FrameStats,
+    /// requested OS timer slack, until applied
+    timer_slack: Option<Duration>,
+    /// restores the OS timer slack when dropped
+    timer_slack_guard: Option<TimerSlackGuard>,
+    /// swap interval pacing policy, if enabled
+    swap_pacing: Option<SwapPacing>,
+    /// swapchain the timer paces for, if configured
+    swapchain: Option<Swapchain>,
+    /// named quality-of-service profiles
+    profiles: Vec<(String, Profile)>,
+    /// name of the active profile
+    active_profile: Option<String>,
+    /// logs not yet returned by log(), e.g. of intervals
+    /// cut short by profile switches, oldest first
+    pending_logs: VecDeque<Log>,
+    /// exact long-term rate schedule, if enabled
+    average_rate: Option<AverageRate>,
+    /// incremented whenever the frame counters are reset
+    counter_epoch: u64,
+    /// information about the most recent frame
+    last_frame: FrameInfo,
+    /// clock frames are measured against
+    clock: Clock,
+    /// custom wait strategy replacing the built-in one
+    wait_strategy: Option<Box<dyn WaitStrategy>>,
+    /// destinations for interval statistics
+    #[cfg(feature = "sinks")]
+    sinks: Vec<Box<dyn LogSink>>,
+    /// reference instant of the nanosecond timebase
+    epoch: Instant,
+    /// schedule of frame targets in nanoseconds since the epoch
+    schedule: Schedule,
+    /// estimator of the display refresh period
+    #[cfg(feature = "stats")]
+    refresh: RefreshEstimator,
+    /// time spent in named scopes during the current interval
+    scopes: Scopes,
+    /// thermal throttling policy, if configured
+    #[cfg(feature = "thermal")]
+    thermal: Option<thermal::Thermal>,
+    /// tracking of the monitor the window is shown on, if configured
+    #[cfg(feature = "display")]
+    monitor: Option<display::MonitorTracking>,
+    /// recent frames and the dump trigger
+    #[cfg(feature = "stats")]
+    history: dump::FrameHistory,
+    /// pacing while the window is hidden, if configured
+    background: Option<visibility::Background>,
+    /// maximum busy wait of a high precision wait
+    spin_margin: Duration,
+    /// stall detection thread, if configured
+    watchdog: Option<watchdog::Watchdog>,
+    /// spreading of late frames over the next frames, if configured
+    compensation: Option<compensation::LateCompensation>,
+    /// filter applied to returned deltas
+    delta_filter: Option<Box<dyn DeltaFilter>>,
+    /// unfiltered minus filtered deltas in nanoseconds
+    filter_lag: i128,
+    /// minimum returned delta, if enabled
+    delta_floor: Option<Duration>,
+    /// wait precision of the next frame, overriding the configured one
+    next_precision: Option<Precision>,
+    /// whether the next frame ends without waiting for its target
+    skip_next_wait: bool,
+    /// automatic high precision state, if enabled
+    auto_precision: Option<auto_precision::AutoPrecision>,
+    /// maximum duration of a shutdown
+    shutdown_timeout: Duration,
+    /// frametime objectives evaluated every frame
+    #[cfg(feature = "stats")]
+    slos: slo::Slos,
+    /// checkpoints of the current frame and recent long frames
+    #[cfg(feature = "stats")]
+    checkpoints: checkpoint::Checkpoints,
+    /// lateness of the most recent frame and the logging interval
+    #[cfg(feature = "stats")]
+    lateness: lateness::LatenessTracking,
+    /// lock-free pacing state for other threads, if requested
+    pacing_state: Option<Arc<pacing_state::Shared>>,
+    /// lock-free upcoming deadlines for other threads, if requested
+    deadline_queue: Option<Arc<deadline_queue::Shared>>,
+    /// capture of the current or next frame
+    capture: capture::Capture,
+    /// seed the frame seeds are derived from
+    session_seed: u64,
+    /// whether the timer waits for frame targets
+    pacing: Pacing,
+    /// exponential moving average of the frametime
+    smoothed_delta: Duration,
+    /// weight of the most recent frametime in `smoothed_delta`
+    delta_smoothing: f64,
+    /// errors of all waits for frame targets
+    #[cfg(feature = "stats")]
+    wakeups: WakeupHistogram,
+    /// statistics per user-defined tag
+    #[cfg(feature = "stats")]
+    tags: tags::Tags,
+    /// boundaries of the logging intervals
+    log_alignment: LogAlignment,
+    /// adaptation of the logging interval to the pacing, if enabled
+    #[cfg(feature = "stats")]
+    adaptive_log: Option<adaptive_log::AdaptiveLog>,
+    /// interrupt of waits in low-rate mode, if enabled
+    low_rate: Option<Arc<low_rate::Interrupt>>,
+    /// whether the timer busy waits for whole frames
+    high_rate: bool,
+    /// minimum frametime, even while catching up
+    hard_cap: Duration,
+    /// framerate targets pushed from other threads, if requested
+    governor: Option<governor::Governor>,
+    /// speed of session time relative to real time
+    time_scale: f64,
+    /// real time covered by all frames
+    real_time: Duration,
+    /// scaled time covered by all frames, excluding pauses
+    session_time: Duration,
+    /// render skipping for overloaded render paths, if enabled
+    render_skip: Option<render_skip::RenderSkip>,
+    /// wall-clock time of `epoch`
+    wall_epoch: SystemTime,
+    /// refresh period frametimes are quantized to, zero if disabled
+    quantum: Duration,
+    /// recent notable events
+    #[cfg(feature = "stats")]
+    events: events::EventLog,
 }
 
+// a timer can be shared between threads, e.g. to read its statistics,
+// so all callbacks it stores are required to be `Send + Sync`
+#[cfg(feature = "std")]
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Timer>();
+};
+
+#[cfg(feature = "std")]
+/// waits until `target`, accounting the time spent
+/// sleeping and spinning in `spent`
+///
+/// returns the last measured timestamp
+fn wait_until(target: Instant, high_precision: bool, spent: &mut WaitTime) -> Instant {
+    let spin_margin = high_precision.then_some(DEFAULT_SPIN_MARGIN);
+    wait_until_on(target, spin_margin, spent, Instant::now, None)
+}
+
+#[cfg(feature = "std")]
+/// same as [`wait_until`], measuring time with `clock` and spinning
+/// for `spin_margin` in high precision mode
+///
+/// `sample` is a fresh reading of `clock`, saving the first measurement
+fn wait_until_on(
+    target: Instant,
+    spin_margin: Option<Duration>,
+    spent: &mut WaitTime,
+    clock: fn() -> Instant,
+    sample: Option<Instant>,
+) -> Instant {
+    let now = sample.unwrap_or_else(clock);
+    match spin_margin {
+        Some(margin) => sleep_until_high_precision(target, now, margin, spent, clock),
+        None => sleep_until(target, now, spent, clock),
+    }
+}
+
+#[cfg(feature = "std")]
+/// frame schedule of [`Timer::default`], 60 fps
+const DEFAULT_SCHEDULE: Schedule = Schedule::from_rate(60, 1);
+
+#[cfg(feature = "std")]
+/// logging interval of [`Timer::default`]
+const DEFAULT_LOG_INTERVAL: Duration = Duration::from_millis(100);
+
+#[cfg(feature = "std")]
+/// maximum number of logs kept until they are returned by
+/// [`Timer::log`], dropping the oldest ones
+const MAX_PENDING_LOGS: usize = 16;
+
+#[cfg(feature = "std")]
+/// maximum busy wait of a high precision wait, unless calibrated
+/// with a [`TimingContext`]
+#[cfg(unix)]
+const DEFAULT_SPIN_MARGIN: Duration = Duration::from_micros(250);
+#[cfg(feature = "std")]
+#[cfg(not(unix))]
+const DEFAULT_SPIN_MARGIN: Duration = Duration::from_millis(1);
+
+#[cfg(feature = "std")]
 /// since thread::sleep usually is not accurate down to the millisecond, we
-/// only suspend the thread for max(delay - 1ms, 0)
+/// only suspend the thread for max(delay - margin, 0)
 /// and spin in a loop for the rest of the time
 ///
 /// returns the last measured timestamp
-fn sleep_until_high_precision(target: Instant) -> Instant {
-    // calculate approximate duration until target time
-    let now = Instant::now();
-
+fn sleep_until_high_precision(
+    target: Instant,
+    now: Instant,
+    margin: Duration,
+    spent: &mut WaitTime,
+    clock: fn() -> Instant,
+) -> Instant {
     // early out to avoid additional measurement
     if now >= target {
         return now;
@@ -44,23 +598,21 @@ fn sleep_until_high_precision(target: Instant) -> Instant {
     // calculate the required wait duration
     let approx_duration = target.duration_since(now);
 
-    // sleep for a maximum of 1ms less than the approximate required delay
-    // (0.250ms on unix)
-    #[cfg(unix)]
-    const MAX_BUSY_WAIT: Duration = Duration::from_micros(250);
-    #[cfg(not(unix))]
-    const MAX_BUSY_WAIT: Duration = Duration::from_millis(1);
-    if approx_duration > MAX_BUSY_WAIT {
-        thread::sleep(approx_duration - MAX_BUSY_WAIT);
+    // sleep for a maximum of `margin` less than the approximate required delay
+    if approx_duration > margin {
+        thread::sleep(approx_duration - margin);
     }
 
-    busy_wait_until(target)
+    busy_wait_until(target, now, spent, clock)
 }
 
-fn sleep_until(target: Instant) -> Instant {
-    // calculate approximate duration until target time
-    let now = Instant::now();
-
+#[cfg(feature = "std")]
+fn sleep_until(
+    target: Instant,
+    now: Instant,
+    spent: &mut WaitTime,
+    clock: fn() -> Instant,
+) -> Instant {
     // early out to avoid additional measurement
     if now >= target {
         return now;
@@ -68,27 +620,89 @@ fn sleep_until(target: Instant) -> Instant {
 
     let suspend_duration = target - now;
     thread::sleep(suspend_duration);
-    busy_wait_until(target)
+    busy_wait_until(target, now, spent, clock)
 }
 
-fn busy_wait_until(target: Instant) -> Instant {
+#[cfg(feature = "std")]
+/// returns an [`Instant`] on the grid `top of the second + k * delta`,
+/// where seconds are counted from the wall-clock `anchor`
+fn wall_clock_grid(anchor: SystemTime, delta: Duration) -> Instant {
+    const SECOND: u128 = 1_000_000_000;
+    let (now, wall_now) = (Instant::now(), SystemTime::now());
+    if delta.is_zero() {
+        return now;
+    }
+    // time since the most recent top of the second
+    let into_second = match wall_now.duration_since(anchor) {
+        Ok(elapsed) => elapsed.as_nanos() % SECOND,
+        Err(ahead) => (SECOND - ahead.duration().as_nanos() % SECOND) % SECOND,
+    };
+    // time since the most recent grid point
+    let into_frame = Duration::from_nanos((into_second % delta.as_nanos()) as u64);
+    now.checked_sub(into_frame)
+        .unwrap_or_else(|| now + (delta - into_frame))
+}
+
+#[cfg(feature = "std")]
+/// returns the first instant `anchor + k * delta` (k >= 0) at or after `at`
+fn next_aligned(anchor: Instant, delta: Duration, at: Instant) -> Instant {
+    if at <= anchor || delta.is_zero() {
+        return anchor.max(at);
+    }
+    let elapsed = (at - anchor).as_nanos();
+    let periods = elapsed.div_ceil(delta.as_nanos());
+    anchor + duration_from_nanos(periods * delta.as_nanos())
+}
+
+#[cfg(feature = "std")]
+/// spins until `target`, accounting the time since `since`
+/// up to the first measurement as sleeping time
+fn busy_wait_until(
+    target: Instant,
+    since: Instant,
+    spent: &mut WaitTime,
+    clock: fn() -> Instant,
+) -> Instant {
+    let woke = clock();
+    spent.sleeping += woke.saturating_duration_since(since);
+
     // spin until target time is reached and return it
-    loop {
-        let time = Instant::now();
-        if time >= target {
-            break time;
-        }
+    let mut time = woke;
+    while time < target {
         hint::spin_loop();
+        time = clock();
     }
+    spent.spinning += time - woke;
+    time
 }
 
+#[cfg(feature = "std")]
 /// A struct holding information about the previous logging interval
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Log {
     /// average delta time between frames since the last call to [`Timer::log`]
     delta_avg: Duration,
+    /// moving average of the frametime at the end of the interval
+    smoothed_delta: Duration,
+    /// frametime statistics since the last call to [`Timer::log`]
+    stats: FrameTimeStats,
+    /// time spent waiting since the last call to [`Timer::log`]
+    wait_time: WaitTime,
+    /// profile that was active during the interval
+    profile: Option<String>,
+    /// time spent in named scopes during the interval
+    scopes: Vec<ScopeStats>,
+    /// thread the log belongs to in a process-level report
+    thread: Option<String>,
+    /// longest frame exceeding its budget during the interval
+    #[cfg(feature = "stats")]
+    long_frame: Option<LongFrame>,
+    /// lateness of the late frames of the interval by cause
+    #[cfg(feature = "stats")]
+    lateness: LatenessBreakdown,
 }
 
+#[cfg(feature = "std")]
 impl Log {
     /// frame time averaged over the interval since the last call to [`Timer::log`]
     pub fn delta_time_avg(&self) -> Duration {
@@ -101,17 +715,177 @@ impl Log {
         self.delta_avg.as_secs_f64() * 1000.
     }
 
+    /// moving average of the frametime at the end of the interval,
+    /// see [`Timer::smoothed_delta`]
+    pub fn smoothed_delta(&self) -> Duration {
+        self.smoothed_delta
+    }
+
     /// fps averaged over the interval since the last call to [`Timer::log`]
     pub fn fps_average(&self) -> f64 {
         1. / self.delta_avg.as_secs_f64()
     }
+
+    /// frametime statistics over the interval since the last call to [`Timer::log`]
+    pub fn frame_time_stats(&self) -> &FrameTimeStats {
+        &self.stats
+    }
+
+    /// stability score over the interval since the last call to [`Timer::log`],
+    /// see [`FrameTimeStats::stability_score`]
+    pub fn stability_score(&self) -> f64 {
+        self.stats.stability_score()
+    }
+
+    /// time spent sleeping and spinning since the last call to [`Timer::log`]
+    pub fn wait_time(&self) -> &WaitTime {
+        &self.wait_time
+    }
+
+    /// name of the profile that was active during the interval,
+    /// see [`Timer::set_profile`]
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// time spent in each named scope during the interval,
+    /// see [`Timer::scope`]
+    pub fn scopes(&self) -> &[ScopeStats] {
+        &self.scopes
+    }
+
+    /// name of the thread the log belongs to in a process-level report,
+    /// see [`StatsAggregator`]
+    pub fn thread(&self) -> Option<&str> {
+        self.thread.as_deref()
+    }
+
+    /// longest frame of the interval whose work exceeded its budget,
+    /// with the checkpoints it reached, see [`Timer::checkpoint`]
+    ///
+    /// Requires the `stats` feature.
+    #[cfg(feature = "stats")]
+    pub fn long_frame(&self) -> Option<&LongFrame> {
+        self.long_frame.as_ref()
+    }
+
+    /// lateness of the late frames of the interval split by cause,
+    /// see [`Timer::last_lateness`]
+    ///
+    /// Requires the `stats` feature.
+    #[cfg(feature = "stats")]
+    pub fn lateness(&self) -> &LatenessBreakdown {
+        &self.lateness
+    }
+
+    /// Numeric statistics of the interval as `(name, value)` pairs,
+    /// with times in milliseconds.
+    ///
+    /// This is meant for engine bindings that expose the statistics
+    /// to scripting languages as a dictionary, e.g. the `stats` of the
+    /// Godot `FpsTimerNode` (`godot` feature).
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.);
+    /// let start = timer.timebase_epoch();
+    /// timer.frame_at(start + Duration::from_millis(250));
+    /// let log = timer.log().unwrap();
+    /// let fields = log.fields();
+    /// assert_eq!(fields[0], ("fps", 4.));
+    /// assert!(fields.contains(&("frame_time_avg_ms", 250.)));
+    /// ```
+    pub fn fields(&self) -> Vec<(&'static str, f64)> {
+        let ms = |duration: Duration| duration.as_secs_f64() * 1000.;
+        let stats = &self.stats;
+        vec![
+            ("fps", self.fps_average()),
+            ("frame_time_avg_ms", self.delta_time_avg_ms()),
+            ("frame_time_smoothed_ms", ms(self.smoothed_delta)),
+            ("frame_time_min_ms", ms(stats.min())),
+            ("frame_time_median_ms", ms(stats.median())),
+            ("frame_time_p99_ms", ms(stats.p99())),
+            ("frame_time_max_ms", ms(stats.max())),
+            ("jitter_ms", ms(stats.jitter())),
+            ("frames", stats.frames() as f64),
+            ("hitches", stats.hitches() as f64),
+            ("stability_score", self.stability_score()),
+            ("sleeping_ms", ms(self.wait_time.sleeping)),
+            ("spinning_ms", ms(self.wait_time.spinning)),
+        ]
+    }
+}
+
+#[cfg(feature = "std")]
+/// Formats the log as a single line of `key=value` pairs,
+/// with durations in nanoseconds, e.g. for line based log files.
+/// Named scopes are appended as `scope.<name>=<avg>/<max>/<share>`, the
+/// longest frame exceeding its budget as `long_frame=<work>/<label>/<offset>`
+/// with the last checkpoint it reached and late frames as
+/// `late=<frames>/<dominant cause>`.
+impl Display for Log {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let stats = &self.stats;
+        write!(
+            f,
+            "frames={} avg={} smoothed={} min={} median={} p99={} max={} jitter={} hitches={} sleeping={} spinning={}",
+            stats.frames(),
+            self.delta_avg.as_nanos(),
+            self.smoothed_delta.as_nanos(),
+            stats.min().as_nanos(),
+            stats.median().as_nanos(),
+            stats.p99().as_nanos(),
+            stats.max().as_nanos(),
+            stats.jitter().as_nanos(),
+            stats.hitches(),
+            self.wait_time.sleeping.as_nanos(),
+            self.wait_time.spinning.as_nanos(),
+        )?;
+        if let Some(profile) = &self.profile {
+            write!(f, " profile={profile}")?;
+        }
+        if let Some(thread) = &self.thread {
+            write!(f, " thread={thread}")?;
+        }
+        for scope in &self.scopes {
+            write!(
+                f,
+                " scope.{}={}/{}/{:.3}",
+                scope.name(),
+                scope.avg().as_nanos(),
+                scope.max().as_nanos(),
+                scope.share()
+            )?;
+        }
+        #[cfg(feature = "stats")]
+        if let Some(long_frame) = &self.long_frame {
+            write!(f, " long_frame={}", long_frame.work().as_nanos())?;
+            if let Some(checkpoint) = long_frame.last_checkpoint() {
+                write!(
+                    f,
+                    "/{}/{}",
+                    checkpoint.label(),
+                    checkpoint.offset().as_nanos()
+                )?;
+            }
+        }
+        #[cfg(feature = "stats")]
+        if let Some(cause) = self.lateness.dominant_cause() {
+            write!(f, " late={}/{cause}", self.lateness.late_frames())?;
+        }
+        Ok(())
+    }
 }
 
+#[cfg(feature = "std")]
 impl Default for Timer {
     fn default() -> Self {
         let now = Instant::now();
-        let delta_time = Duration::from_secs_f64(1.0 / 60.);
-        let log_interval = Duration::from_millis(100);
+        let delta_time = DEFAULT_SCHEDULE.frame_time();
+        let log_interval = DEFAULT_LOG_INTERVAL;
         Self {
             framecount: 0,
             log_interval,
@@ -123,10 +897,94 @@ impl Default for Timer {
             delta_time,
             max_delay_frames: 2,
             high_precision: true,
+            #[cfg(feature = "hooks")]
+            hooks: Hooks::default(),
+            work_avg: Duration::ZERO,
+            anchor: None,
+            phase: Duration::ZERO,
+            wall_anchor: None,
+            stats: FrameStats::default(),
+            timer_slack: None,
+            timer_slack_guard: None,
+            swap_pacing: None,
+            swapchain: None,
+            profiles: vec![],
+            active_profile: None,
+            pending_logs: VecDeque::new(),
+            average_rate: None,
+            counter_epoch: 0,
+            last_frame: FrameInfo {
+                index: 0,
+                epoch: 0,
+                delta: Duration::ZERO,
+                timestamp: now,
+                discontinuity: false,
+                behind: Duration::ZERO,
+                period: delta_time,
+            },
+            clock: Clock::Monotonic,
+            wait_strategy: None,
+            #[cfg(feature = "sinks")]
+            sinks: vec![],
+            epoch: now,
+            schedule: DEFAULT_SCHEDULE,
+            #[cfg(feature = "stats")]
+            refresh: RefreshEstimator::default(),
+            scopes: Scopes::default(),
+            #[cfg(feature = "thermal")]
+            thermal: None,
+            #[cfg(feature = "display")]
+            monitor: None,
+            #[cfg(feature = "stats")]
+            history: dump::FrameHistory::default(),
+            background: None,
+            spin_margin: DEFAULT_SPIN_MARGIN,
+            watchdog: None,
+            compensation: None,
+            delta_filter: None,
+            filter_lag: 0,
+            delta_floor: None,
+            next_precision: None,
+            skip_next_wait: false,
+            auto_precision: None,
+            shutdown_timeout: shutdown::DEFAULT_SHUTDOWN_TIMEOUT,
+            #[cfg(feature = "stats")]
+            slos: slo::Slos::default(),
+            #[cfg(feature = "stats")]
+            checkpoints: checkpoint::Checkpoints::default(),
+            #[cfg(feature = "stats")]
+            lateness: lateness::LatenessTracking::default(),
+            pacing_state: None,
+            deadline_queue: None,
+            capture: capture::Capture::default(),
+            session_seed: seed::random_seed(),
+            pacing: Pacing::Internal,
+            smoothed_delta: Duration::ZERO,
+            delta_smoothing: smoothing::DEFAULT_DELTA_SMOOTHING,
+            #[cfg(feature = "stats")]
+            wakeups: WakeupHistogram::default(),
+            #[cfg(feature = "stats")]
+            tags: tags::Tags::default(),
+            log_alignment: LogAlignment::SinceLastLog,
+            #[cfg(feature = "stats")]
+            adaptive_log: None,
+            low_rate: None,
+            high_rate: false,
+            hard_cap: Duration::ZERO,
+            governor: None,
+            time_scale: 1.,
+            real_time: Duration::ZERO,
+            session_time: Duration::ZERO,
+            render_skip: None,
+            wall_epoch: wall_epoch::wall_clock_at(now),
+            quantum: Duration::ZERO,
+            #[cfg(feature = "stats")]
+            events: events::EventLog::default(),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl Timer {
     /// Sets the logging interval of this timer to `log_interval`.
     ///
@@ -145,15 +1003,23 @@ impl Timer {
     ///     .fps(240.);
     /// ```
     pub fn log_interval(mut self, log_interval: Duration) -> Self {
+        let log_interval = log_interval.min(MAX_INTERVAL);
         self.log_interval = log_interval;
-        self.log_target = self.previous + log_interval;
+        self.log_target = self.next_log_target(self.previous);
         self
     }
 
+    /// The logging interval currently in use, which differs from the
+    /// one set with [`Self::log_interval`] while it is adapted to the
+    /// pacing, see `Timer::adaptive_log_interval`.
+    pub fn current_log_interval(&self) -> Duration {
+        self.log_interval
+    }
+
     /// Sets the target frametime to the specified amount.
     ///
     /// # Arguments
-    /// * `delta` - target frametime
+    /// * `delta` - target frametime, a [`Duration`] or a [`FrameTime`]
     ///
     /// # Returns
     /// [`Self`] the (modified) timer
@@ -161,36 +1027,111 @@ impl Timer {
     /// # Example
     /// ```rust
     /// use std::time::Duration;
-    /// use fps_timer::Timer;
+    /// use fps_timer::{FrameTime, Timer};
     /// let mut timer = Timer::default()
     ///     .frame_time(Duration::from_secs_f64(1. / 60.));
+    ///
+    /// // e.g. from a configuration file
+    /// let frame_time: FrameTime = "16.7ms".parse().unwrap();
+    /// let mut timer = Timer::default().frame_time(frame_time);
     /// ```
-    pub fn frame_time(mut self, delta: Duration) -> Self {
-        self.delta_time = delta;
-        self.target = self.previous + delta;
+    pub fn frame_time(mut self, delta: impl Into<FrameTime>) -> Self {
+        self.set_frame_time(delta.into().as_duration());
         self
     }
 
     /// Sets the framerate target to the specified amount.
     ///
+    /// A framerate of `0` (as well as negative and NaN framerates)
+    /// disables the framerate cap.
+    ///
     /// # Arguments
-    /// * `fps` - target framerate
+    /// * `fps` - target framerate in Hz, an [`f64`] or a [`Rate`]
     ///
     /// # Returns
     /// [`Self`] the (modified) timer
     ///
     /// # Example
     /// ```rust
-    /// use fps_timer::Timer;
+    /// use fps_timer::{Rate, Timer};
     /// let mut timer = Timer::default()
     ///     .fps(60.);
+    ///
+    /// // e.g. from a command line flag
+    /// let rate: Rate = "60000/1001".parse().unwrap();
+    /// let mut timer = Timer::default().fps(rate);
+    ///
+    /// // invalid or extreme framerates never panic
+    /// let uncapped = Timer::default().fps(f64::NAN);
+    /// let slow = Timer::default().fps(1e-30);
     /// ```
-    pub fn fps(self, fps: f64) -> Self {
-        let duration = match fps {
-            0. => Duration::ZERO,
-            fps => Duration::from_secs_f64(1. / fps),
-        };
-        self.frame_time(duration)
+    pub fn fps(mut self, fps: impl Into<Rate>) -> Self {
+        self.set_fps(fps.into().as_hz());
+        self
+    }
+
+    /// Offsets the deadline schedule of this timer by `phase`.
+    ///
+    /// Frame targets are placed at `start + phase + k * frametime`,
+    /// so two timers running at the same rate can be deliberately
+    /// staggered (e.g. a render and a capture thread) or frames can
+    /// be aligned to land in the middle of a vblank interval.
+    /// When the timer falls behind and re-bases its target, the
+    /// phase is preserved by skipping to the next aligned target.
+    ///
+    /// # Arguments
+    /// * `phase` - offset of the deadline schedule
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    /// let render = Timer::default().fps(60.);
+    /// // half a frame later than the render timer
+    /// let capture = Timer::default()
+    ///     .fps(60.)
+    ///     .phase(Duration::from_secs_f64(0.5 / 60.));
+    /// ```
+    pub fn phase(mut self, phase: Duration) -> Self {
+        self.phase = phase.min(MAX_INTERVAL);
+        self.anchor = Some(self.previous + self.phase);
+        self.target = self.first_target(self.previous);
+        self
+    }
+
+    /// Aligns frame targets to absolute wall-clock boundaries.
+    ///
+    /// Frames are scheduled at whole multiples of the frametime from the
+    /// top of every second as seen from `anchor`, e.g. with 30 fps and
+    /// [`std::time::UNIX_EPOCH`] as anchor, every frame starts at a
+    /// multiple of 1/30 s within the current wall-clock second.
+    /// Machines with synchronized wall clocks thus produce frames at
+    /// the same time, as required for video walls or broadcast pipelines.
+    ///
+    /// The alignment is established once, when the frame rate is set,
+    /// and assumes the frame rate divides one second evenly.
+    ///
+    /// # Arguments
+    /// * `anchor` - wall-clock instant on the frame grid
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::UNIX_EPOCH;
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default()
+    ///     .fps(30.)
+    ///     .wall_clock_aligned(UNIX_EPOCH);
+    /// ```
+    pub fn wall_clock_aligned(mut self, anchor: SystemTime) -> Self {
+        self.wall_anchor = Some(anchor);
+        let delta = self.delta_time;
+        self.frame_time(delta)
     }
 
     /// Enable or disable improved accuracy for this timer.
@@ -199,7 +1140,8 @@ impl Timer {
     /// at the cost of higher power consumption because
     /// part of the duration is awaited in a busy spinloop.
     ///
-    /// Defaults to `true`
+    /// Defaults to `true`, see [`Self::auto_high_precision`]
+    /// to switch it depending on the accuracy of sleeping
     ///
     /// # Arguments
     /// * `enable` - whether or not to enable higher precision
@@ -218,10 +1160,122 @@ impl Timer {
         self
     }
 
+    #[cfg(feature = "hooks")]
+    /// Registers a hook that is called at the beginning of every
+    /// call to [`Self::frame`], before waiting for the frame target.
+    ///
+    /// Any number of hooks can be registered. They are called in
+    /// the order they were registered with the [`Instant`]
+    /// at which the frame started.
+    ///
+    /// # Arguments
+    /// * `hook` - callback invoked with the start time of the frame
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default().fps(0.);
+    /// timer.on_frame_start(|start| println!("frame started at {start:?}"));
+    /// timer.frame();
+    /// ```
+    pub fn on_frame_start(&mut self, hook: impl FnMut(Instant) + Send + Sync + 'static) {
+        self.hooks.add_frame_start(Box::new(hook));
+    }
+
+    #[cfg(feature = "hooks")]
+    /// Registers a hook that is called at the end of every
+    /// call to [`Self::frame`], after the frame target was reached.
+    ///
+    /// Any number of hooks can be registered. They are called in
+    /// the order they were registered with the frametime
+    /// that is returned by [`Self::frame`].
+    ///
+    /// # Arguments
+    /// * `hook` - callback invoked with the frametime
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::sync::{Arc, atomic::{AtomicU64, Ordering}};
+    /// use fps_timer::Timer;
+    /// let frames = Arc::new(AtomicU64::new(0));
+    /// let mut timer = Timer::default().fps(0.);
+    /// let counter = frames.clone();
+    /// timer.on_frame_end(move |_dt| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    /// timer.frame();
+    /// timer.frame();
+    /// assert_eq!(frames.load(Ordering::Relaxed), 2);
+    /// ```
+    pub fn on_frame_end(&mut self, hook: impl FnMut(Duration) + Send + Sync + 'static) {
+        self.hooks.add_frame_end(Box::new(hook));
+    }
+
+    #[cfg(feature = "hooks")]
+    /// Registers a hook that is called whenever the timer falls
+    /// behind by more than its slack and the frame target is
+    /// re-based to the current time (see [`Self::frame`]).
+    ///
+    /// Any number of hooks can be registered. They are called in
+    /// the order they were registered with the amount of time
+    /// the frame was behind its target.
+    ///
+    /// # Arguments
+    /// * `hook` - callback invoked with the lag that caused the re-base
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default().fps(60.);
+    /// timer.on_target_rebased(|behind| eprintln!("fell behind by {behind:?}"));
+    /// ```
+    pub fn on_target_rebased(&mut self, hook: impl FnMut(Duration) + Send + Sync + 'static) {
+        self.hooks.add_target_rebased(Box::new(hook));
+    }
+
+    /// Sets the OS timer slack of the thread running the timer.
+    ///
+    /// On Linux, sleeps may overshoot by up to the thread's timer slack
+    /// (50µs by default), which, combined with scheduling delays under
+    /// load, results in consistent oversleeping. When high precision is
+    /// enabled, the timer slack is set via `prctl(PR_SET_TIMERSLACK)`
+    /// on the first call to [`Self::frame`] and the previous value is
+    /// restored when the timer is dropped.
+    ///
+    /// Since the timer slack is a per-thread setting, the timer should
+    /// be dropped on the thread that runs its frames.
+    /// Has no effect on other platforms.
+    ///
+    /// # Arguments
+    /// * `slack` - timer slack to use while the timer is alive
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default()
+    ///     .high_precision(true)
+    ///     .timer_slack(Duration::from_micros(1));
+    /// timer.frame();
+    /// ```
+    pub fn timer_slack(mut self, slack: Duration) -> Self {
+        self.timer_slack = Some(slack);
+        self
+    }
+
     /// Waits until the specified frametime target is reached
     /// and returns the [`Duration`] since the last call
     /// to [`Self::frame()`] of this [`Timer`] (= frametime).
     ///
+    /// The clock is read once if the frame target has already passed.
+    /// Otherwise this reading also starts the wait and the last
+    /// measurement of the wait ends the frame. The instant the frame
+    /// ended is available as [`FrameInfo::timestamp`], so there is no
+    /// need to read the clock again after this call.
+    ///
     /// # Example
     /// ```no_run
     /// use std::time::Duration;
@@ -240,19 +1294,242 @@ impl Timer {
     /// }
     /// ```
     pub fn frame(&mut self) -> Duration {
-        // increment framecount
-        self.framecount += 1;
+        let now = self.clock_now();
+        self.frame_sampled(now, !self.is_synthetic(), None)
+    }
+
+    /// Same as [`Self::frame`] but uses `now` as the current time
+    /// instead of reading the clock.
+    ///
+    /// This is useful for engines that already sample the clock
+    /// once per frame and for simulations that drive the timer
+    /// with synthetic time. If `now` lies before the frame target,
+    /// the timer still waits until the target is reached, unless it
+    /// runs on [`Clock::Synthetic`], where the frame ends at its
+    /// target immediately.
+    ///
+    /// # Arguments
+    /// * `now` - the current time as sampled by the caller
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::{Clock, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// let start = Instant::now();
+    /// timer.frame_at(start + Duration::from_millis(10));
+    /// // the next frame ends 30ms later, no waiting required
+    /// let dt = timer.frame_at(start + Duration::from_millis(40));
+    /// assert_eq!(dt, Duration::from_millis(30));
+    ///
+    /// // timestamps that do not advance yield a zero frametime
+    /// let mut uncapped = Timer::default().fps(0.);
+    /// uncapped.frame_at(start + Duration::from_millis(40));
+    /// assert_eq!(uncapped.frame_at(start), Duration::ZERO);
+    ///
+    /// // simulated time advances without sleeping
+    /// let mut simulated = Timer::default().fps(60.).clock(Clock::Synthetic);
+    /// let epoch = simulated.timebase_epoch();
+    /// let real = Instant::now();
+    /// for _ in 0..1000 {
+    ///     simulated.frame_at(epoch);
+    /// }
+    /// assert!(real.elapsed() < Duration::from_secs(1));
+    /// let elapsed = simulated.frame_info().timestamp() - epoch;
+    /// assert_eq!(elapsed.as_millis(), 16_666);
+    /// ```
+    ///
+    /// # Non-monotonic time
+    /// Repeated readings and readings that jump backwards, e.g. of
+    /// virtualized clocks, are taken as the time of the previous frame
+    /// and yield a zero frametime. After a jump
+    /// forwards, the schedule restarts at the new time instead of
+    /// catching up on the missed frames.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{Clock, Timer};
+    ///
+    /// let hour = Duration::from_secs(3600);
+    /// let mut uncapped = Timer::default().fps(0.);
+    /// let start = uncapped.timebase_epoch() + hour;
+    /// uncapped.frame_at(start);
+    /// // repeated readings
+    /// assert_eq!(uncapped.frame_at(start), Duration::ZERO);
+    /// assert_eq!(uncapped.frame_at(start), Duration::ZERO);
+    /// // backwards, then forwards again
+    /// assert_eq!(uncapped.frame_at(start - hour), Duration::ZERO);
+    /// assert_eq!(uncapped.frame_at(start + hour), hour);
+    /// assert_eq!(uncapped.frame_at(start), Duration::ZERO);
+    /// # uncapped.log();
+    ///
+    /// let frame_time = Duration::from_millis(10);
+    /// let mut capped = Timer::default().fps(100.).clock(Clock::Synthetic);
+    /// let epoch = capped.timebase_epoch();
+    /// capped.frame_at(epoch);
+    /// // repeated and backwards readings keep the frame pacing
+    /// assert_eq!(capped.frame_at(epoch), frame_time);
+    /// assert_eq!(capped.frame_at(epoch - hour), frame_time);
+    /// // a day-long jump forwards is a single long frame ...
+    /// let jump = epoch + 24 * hour;
+    /// assert!(capped.frame_at(jump) > 23 * hour);
+    /// assert!(capped.frame_info().is_discontinuity());
+    /// // ... after which frames are paced normally again
+    /// for _ in 0..3 {
+    ///     assert_eq!(capped.frame_at(jump), frame_time);
+    /// }
+    /// # capped.log();
+    /// ```
+    pub fn frame_at(&mut self, now: Instant) -> Duration {
+        self.frame_sampled(now, false, None)
+    }
+
+    /// frame logic waiting with the built-in wait or the custom
+    /// wait strategy, `sampled` if `now` was just read from the clock
+    ///
+    /// waits end at `replayed` instead, if given, e.g. the recorded
+    /// end of the wait when replaying a [`Recording`]
+    pub(crate) fn frame_sampled(
+        &mut self,
+        now: Instant,
+        sampled: bool,
+        replayed: Option<Instant>,
+    ) -> Duration {
+        let mut spent = WaitTime::default();
+        let clock = self.clock.reader();
+        let mut strategy = self.wait_strategy.take();
+        // the reading can start the wait, unless callbacks
+        // may take time before waiting
+        let sample = (sampled && !self.runs_frame_start_callbacks()).then_some(now);
+        let spin_margin = self.effective_spin_margin();
+        let interrupt = self.low_rate.clone();
+        let precision = self.next_precision;
+        let measures_oversleep = self.measures_oversleep();
+        let mut oversleep = None;
+        let synthetic = self.is_synthetic();
+        let frame_time = self.frame_with(now, |target, high_precision| {
+            if let Some(end) = replayed {
+                return end;
+            }
+            if synthetic {
+                return target;
+            }
+            if let Some(precision) = precision {
+                let spin_margin = precision.spin_margin(spin_margin);
+                return wait_until_on(target, spin_margin, &mut spent, clock, sample);
+            }
+            match &mut strategy {
+                Some(strategy) => {
+                    wait::wait_with(strategy.as_mut(), target, &mut spent, clock, sample)
+                }
+                None => match &interrupt {
+                    Some(interrupt) => interrupt.wait_until(target, &mut spent, clock, sample),
+                    None if measures_oversleep => {
+                        let spin_margin = high_precision.then_some(spin_margin);
+                        let start = sample.unwrap_or_else(clock);
+                        let woke =
+                            wait_until_on(target, spin_margin, &mut spent, clock, Some(start));
+                        oversleep = auto_precision::oversleep(
+                            start,
+                            target,
+                            woke,
+                            spin_margin,
+                            spent.spinning,
+                        );
+                        woke
+                    }
+                    None => {
+                        let spin_margin = high_precision.then_some(spin_margin);
+                        wait_until_on(target, spin_margin, &mut spent, clock, sample)
+                    }
+                },
+            }
+        });
+        self.wait_strategy = strategy;
+        if let Some(oversleep) = oversleep {
+            self.update_auto_precision(oversleep);
+        }
+        #[cfg(feature = "stats")]
+        {
+            self.stats.record_wait(spent);
+            self.tags.record_wait(spent);
+        }
+        #[cfg(feature = "sinks")]
+        self.flush_sinks();
+        #[cfg(feature = "stats")]
+        self.poll_dump();
+        frame_time
+    }
+
+    /// `true` if code outside of the timer runs at the start of a frame
+    fn runs_frame_start_callbacks(&self) -> bool {
+        #[cfg(feature = "hooks")]
+        if self.hooks.has_frame_start() {
+            return true;
+        }
+        #[cfg(feature = "thermal")]
+        if self.thermal.is_some() {
+            return true;
+        }
+        #[cfg(feature = "display")]
+        if self.monitor.is_some() {
+            return true;
+        }
+        false
+    }
+
+    /// frame logic with a custom `wait` function, which is called
+    /// with the target instant and the high precision setting
+    /// and returns the instant at which the wait finished
+    pub(crate) fn frame_with(
+        &mut self,
+        now: Instant,
+        wait: impl FnOnce(Instant, bool) -> Instant,
+    ) -> Duration {
+        // increment framecount, wrapping around after u64::MAX
+        self.framecount = self.framecount.wrapping_add(1);
+        let high_precision = match self.next_precision.take() {
+            Some(precision) => precision != Precision::Sleep,
+            None => self.high_precision,
+        };
+        let skip_wait = std::mem::take(&mut self.skip_next_wait);
+
+        // apply the requested timer slack on the thread running the frames
+        if self.high_precision {
+            if let Some(slack) = self.timer_slack.take() {
+                self.timer_slack_guard = TimerSlackGuard::set(slack);
+            }
+        }
 
-        // get current time
-        let mut current = Instant::now();
+        // current time as provided by the caller, never before the
+        // previous frame, so readings that jump backwards are not
+        // counted twice once the clock recovers
+        let mut current = now.max(self.previous);
+        #[cfg(feature = "hooks")]
+        self.hooks.frame_start(current);
+        #[cfg(feature = "thermal")]
+        self.update_thermal(current);
+        #[cfg(feature = "display")]
+        self.update_monitor(current);
 
+        // smooth the workload of the application for delta prediction
+        let work = current.saturating_duration_since(self.previous);
+        #[cfg(feature = "stats")]
+        self.record_checkpoints(work);
+        self.work_avg = self.work_avg * 7 / 8 + work / 8;
+        self.update_swap_pacing(work);
+        self.update_render_skip(work);
+        self.swapchain_submitted();
+
+        let mut discontinuity = false;
+        let mut behind = Duration::ZERO;
+        #[cfg(feature = "stats")]
+        let mut wakeup = Duration::ZERO;
+        let period = self.delta_time;
         if self.delta_time > Duration::ZERO {
             // calculate if frame was too late
-            let behind = if current > self.target {
-                current - self.target
-            } else {
-                Duration::ZERO
-            };
+            behind = current.saturating_duration_since(self.target);
 
             // If the frame is more than `slack` behind,
             // we update the target to the current time,
@@ -260,27 +1537,113 @@ impl Timer {
             //
             // Otherwise, the next frame is scheduled for
             // `prev_target + delta_time` to allow the timer to catch up.
-            if behind > self.slack() {
-                self.target = current;
+            // a capture frame may be late by its allowance
+            // without catching up afterwards
+            if self.relax_capture_deadline(current, behind) {
+                behind = Duration::ZERO;
+            } else if behind > self.slack() && self.average_rate.is_none() {
+                self.target = match self.anchor {
+                    Some(anchor) => next_aligned(anchor, self.delta_time, current),
+                    None => current,
+                };
+                #[cfg(feature = "hooks")]
+                self.hooks.target_rebased(behind);
+                discontinuity = true;
             }
 
-            // wait until target instant if needed
-            if current < self.target {
-                current = if self.high_precision {
-                    sleep_until_high_precision(self.target)
-                } else {
-                    sleep_until(self.target)
-                };
+            // wait until target instant if needed, unless frames
+            // are paced elsewhere or the wait was cancelled
+            let (wait_target, delayed) = self.wait_target(current);
+            // the schedule continues from a target delayed to the next refresh
+            if let Some(delayed) = delayed {
+                self.target = self.target.max(delayed);
+            }
+            if current < wait_target && self.pacing == Pacing::Internal && !skip_wait {
+                current = wait(wait_target, high_precision);
+                #[cfg(feature = "stats")]
+                {
+                    wakeup = current.saturating_duration_since(wait_target);
+                    self.wakeups.record(wakeup);
+                }
             }
 
             // update target time
-            self.target += self.delta_time;
+            self.target = match &mut self.average_rate {
+                Some(rate) => rate.advance(),
+                None => self.advance_schedule(),
+            };
         }
+        #[cfg(feature = "stats")]
+        self.attribute_lateness(behind, discontinuity, wakeup, high_precision);
 
         // calculate frame_time and update previous time
-        let frame_time = current.duration_since(self.previous);
+        let frame_time = current.saturating_duration_since(self.previous);
         self.previous = current;
-        frame_time
+        #[cfg(feature = "stats")]
+        {
+            let hitch_threshold = self.delta_time.max(self.work_avg).saturating_mul(2);
+            let hitch_threshold = hitch_threshold + self.capture_slack();
+            self.stats.record(frame_time, hitch_threshold);
+            self.tags.record(frame_time, hitch_threshold);
+            if self.is_capture_frame() {
+                self.tags
+                    .record_named("capture", frame_time, hitch_threshold);
+            }
+            self.refresh.record(frame_time);
+        }
+        self.update_smoothed_delta(frame_time);
+        let delta = self.compensate(frame_time);
+        let delta = self.filter_delta(delta);
+        let delta = self.floored(delta);
+        self.last_frame = FrameInfo {
+            index: self.framecount.wrapping_sub(1),
+            epoch: self.counter_epoch,
+            delta,
+            timestamp: current,
+            discontinuity,
+            behind,
+            period,
+        };
+        self.advance_session_time(frame_time);
+        #[cfg(feature = "stats")]
+        {
+            self.record_frame_events(frame_time);
+            self.track_slos(frame_time);
+            self.record_history();
+        }
+        #[cfg(feature = "hooks")]
+        self.hooks.frame_end(frame_time);
+        self.apply_governor();
+        self.feed_watchdog();
+        self.publish_pacing_state(frame_time);
+        self.publish_deadlines();
+        self.advance_capture();
+        delta
+    }
+
+    /// Estimates the frametime that the next call to [`Self::frame`]
+    /// will return.
+    ///
+    /// The estimate is based on the current frame target and the
+    /// smoothed time the application spent between previous frames,
+    /// so frames that consistently miss their target are predicted
+    /// to do so again.
+    /// Animation and camera systems can use it to avoid
+    /// one-frame-late corrections.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default().fps(100.);
+    /// timer.frame();
+    /// // light workload, the frame target will be met
+    /// let predicted = timer.predicted_delta();
+    /// assert!(predicted <= Duration::from_millis(10));
+    /// ```
+    pub fn predicted_delta(&self) -> Duration {
+        let until_target = self.target.saturating_duration_since(self.previous);
+        until_target.max(self.work_avg)
     }
 
     /// returns [`Some<Log>`], holding information
@@ -288,27 +1651,188 @@ impl Timer {
     /// the interval specified by [`Timer::log_interval`] has passed
     /// and [`None`] otherwise
     pub fn log(&mut self) -> Option<Log> {
+        self.log_at(self.previous)
+    }
+
+    /// Same as [`Self::log`] but uses `now` as the current time
+    /// instead of the time of the previous frame.
+    ///
+    /// # Arguments
+    /// * `now` - the current time as sampled by the caller
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default()
+    ///     .log_interval(Duration::from_millis(100))
+    ///     .fps(0.);
+    /// let start = Instant::now();
+    /// timer.frame_at(start);
+    /// assert!(timer.log_at(start).is_none());
+    /// let log = timer.log_at(start + Duration::from_secs(1)).unwrap();
+    /// assert!(log.delta_time_avg() >= Duration::from_secs(1));
+    /// ```
+    pub fn log_at(&mut self, now: Instant) -> Option<Log> {
+        // intervals cut short by profile switches
+        if let Some(log) = self.pending_logs.pop_front() {
+            return Some(log);
+        }
+
         // check if it's time to log fps
-        let current = self.previous;
-        if current < self.log_target {
+        if !self.log_due(now) {
             return None;
         }
+        let log = self.close_log_interval(now)?;
+        self.emit_log(log);
+        self.pending_logs.pop_front()
+    }
+
+    /// delivers `log` to all sinks and keeps it for [`Timer::log`]
+    fn emit_log(&mut self, log: Log) {
+        #[cfg(feature = "sinks")]
+        for sink in &mut self.sinks {
+            sink.log(&log);
+        }
+        if self.pending_logs.len() == MAX_PENDING_LOGS {
+            self.pending_logs.pop_front();
+        }
+        self.pending_logs.push_back(log);
+    }
 
+    /// ends the current logging interval at `current`
+    /// and returns its [`Log`], if it contains any frames
+    fn close_log_interval(&mut self, current: Instant) -> Option<Log> {
         // frames since last log (guaranteed to be at least 1)
-        let frames = (self.framecount - self.prev_framecount) as u32;
+        let frames = self.framecount.wrapping_sub(self.prev_framecount);
         if frames == 0 {
             return None;
         }
 
         // avg frametime = duration / (frames in this duration)
-        let delta_avg = current.duration_since(self.previous_log) / frames;
+        let elapsed = current.saturating_duration_since(self.previous_log);
+        let delta_avg = duration_from_nanos(elapsed.as_nanos() / frames as u128);
 
-        // set time of current and next log (current time + log interval)
-        self.log_target = current + self.log_interval;
+        let stats = self.stats.take_interval();
+        #[cfg(feature = "stats")]
+        self.adapt_log_interval(&stats);
+
+        // set time of current and next log
+        self.log_target = self.next_log_target(current);
         self.previous_log = current;
         self.prev_framecount = self.framecount;
 
-        Some(Log { delta_avg })
+        let wait_time = self.stats.take_interval_wait();
+        Some(Log {
+            delta_avg,
+            smoothed_delta: self.smoothed_delta,
+            stats,
+            wait_time,
+            profile: self.active_profile.clone(),
+            scopes: self.scopes.take_interval(frames, elapsed),
+            thread: None,
+            #[cfg(feature = "stats")]
+            long_frame: self.checkpoints.take_interval(),
+            #[cfg(feature = "stats")]
+            lateness: self.lateness.take_interval(),
+        })
+    }
+
+    /// Returns a summary of all frames since the timer was created.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default().fps(1000.);
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// let summary = timer.session_summary();
+    /// assert_eq!(summary.frame_time_stats().frames(), 10);
+    /// assert!(summary.stability_score() > 0.);
+    /// ```
+    #[cfg(feature = "stats")]
+    pub fn session_summary(&self) -> SessionSummary {
+        self.stats.session()
+    }
+
+    /// restarts the timer at `now`, resetting the frame schedule,
+    /// the logging interval and all counters
+    pub(crate) fn reset_at(&mut self, now: Instant) {
+        self.previous = now;
+        self.target = match &mut self.average_rate {
+            Some(rate) => rate.restart(now),
+            None => self.first_target(now),
+        };
+        self.previous_log = now;
+        self.log_target = self.next_log_target(now);
+        self.framecount = 0;
+        self.prev_framecount = 0;
+        self.work_avg = Duration::ZERO;
+        self.stats = FrameStats::default();
+        self.real_time = Duration::ZERO;
+        self.session_time = Duration::ZERO;
+    }
+
+    /// sets the target frametime and restarts the frame schedule
+    /// from the previous frame
+    fn set_frame_time(&mut self, delta: Duration) {
+        let delta = delta.min(MAX_INTERVAL);
+        self.average_rate = None;
+        let delta = self.request_swap_frame_time(delta);
+        #[cfg(feature = "stats")]
+        self.record_frame_time_change(self.delta_time, delta);
+        self.delta_time = delta;
+        if let Some(wall_anchor) = self.wall_anchor {
+            self.anchor = Some(wall_clock_grid(wall_anchor, delta));
+        }
+        self.target = self.first_target(self.previous);
+        self.schedule = Schedule::new(delta);
+        self.restart_schedule();
+    }
+
+    /// sets the exact frametime for `fps` and restarts the frame
+    /// schedule from the previous frame
+    fn set_fps(&mut self, fps: f64) {
+        self.set_schedule(Schedule::from_fps(fps));
+    }
+
+    /// sets the exact frametime of `schedule` and restarts the frame
+    /// schedule from the previous frame
+    pub(crate) fn set_schedule(&mut self, schedule: Schedule) {
+        let delta = schedule.frame_time();
+        self.set_frame_time(delta);
+        // keep the sub-nanosecond frametime, unless it was adjusted
+        if self.delta_time == delta {
+            self.schedule = schedule;
+            match self.anchor {
+                None => self.start_schedule(self.previous),
+                // continue from the aligned first target
+                Some(_) => self.restart_schedule(),
+            }
+        }
+    }
+
+    /// Instant a frame ending at `current` waits for: the target as
+    /// adjusted by the swapchain, delayed to the next refresh if it
+    /// was missed and limited by the hard cap. The second instant is
+    /// the target delayed to the next refresh, if it was.
+    pub(crate) fn wait_target(&self, current: Instant) -> (Instant, Option<Instant>) {
+        let target = self.swapchain_wait_target(current);
+        let quantized = self.quantized(current, target);
+        let delayed = (quantized != target).then_some(quantized);
+        (self.hard_capped(quantized), delayed)
+    }
+
+    /// target of the first frame when starting the schedule at `start`
+    fn first_target(&self, start: Instant) -> Instant {
+        match self.anchor {
+            Some(anchor) => next_aligned(anchor, self.delta_time, start + self.delta_time),
+            None => start + self.delta_time,
+        }
     }
 
     /// The slack of the timer, i.e. the amount of time in which a game
@@ -316,6 +1840,10 @@ impl Timer {
     /// If the game lags behind more than this slack, the target frame
     /// time is relaxed to not fall behind completely.
     fn slack(&self) -> Duration {
-        self.max_delay_frames * self.delta_time
+        let slack = self.delta_time.saturating_mul(self.max_delay_frames);
+        match self.low_rate {
+            Some(_) => slack.min(low_rate::LOW_RATE_SLACK),
+            None => slack,
+        }
     }
 }
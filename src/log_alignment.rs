@@ -0,0 +1,91 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::{duration_from_nanos, next_aligned, Timer};
+
+/// Boundaries of the logging intervals of a [`Timer`],
+/// see [`Timer::log_alignment`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LogAlignment {
+    /// an interval ends once [`Timer::log_interval`] has passed since
+    /// the end of the previous one
+    #[default]
+    SinceLastLog,
+    /// intervals end on multiples of the logging interval since
+    /// [`Timer::timebase_epoch`], so late logs don't shift later ones
+    SessionStart,
+    /// intervals end on multiples of the logging interval of the wall
+    /// clock, e.g. on every full second, to line up the logs of
+    /// different processes or machines
+    WallClock,
+    /// an interval ends after the given number of frames,
+    /// regardless of the logging interval
+    Frames(u64),
+}
+
+impl Timer {
+    /// Sets how the boundaries of logging intervals are chosen.
+    ///
+    /// Frame based intervals are convenient for benchmark comparisons,
+    /// as every log covers the same amount of work.
+    ///
+    /// # Arguments
+    /// * `alignment` - [`LogAlignment::SinceLastLog`] by default
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{LogAlignment, Timer};
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(0.)
+    ///     .log_alignment(LogAlignment::Frames(100));
+    /// let mut logs = 0;
+    /// for _ in 0..1000 {
+    ///     timer.frame();
+    ///     if let Some(log) = timer.log() {
+    ///         # #[cfg(feature = "stats")]
+    ///         assert_eq!(log.frame_time_stats().frames(), 100);
+    ///         logs += 1;
+    ///     }
+    /// }
+    /// assert_eq!(logs, 10);
+    /// ```
+    pub fn log_alignment(mut self, alignment: LogAlignment) -> Self {
+        self.log_alignment = alignment;
+        self.log_target = self.next_log_target(self.previous);
+        self
+    }
+
+    /// `true` if the logging interval ends at `now`
+    pub(crate) fn log_due(&self, now: Instant) -> bool {
+        match self.log_alignment {
+            LogAlignment::Frames(frames) => {
+                self.framecount.wrapping_sub(self.prev_framecount) >= frames.max(1)
+            }
+            _ => now >= self.log_target,
+        }
+    }
+
+    /// end of the logging interval starting at `start`
+    pub(crate) fn next_log_target(&self, start: Instant) -> Instant {
+        let interval = self.log_interval;
+        match self.log_alignment {
+            LogAlignment::SinceLastLog | LogAlignment::Frames(_) => start + interval,
+            LogAlignment::SessionStart => next_aligned(
+                self.timebase_epoch(),
+                interval,
+                start + Duration::from_nanos(1),
+            ),
+            LogAlignment::WallClock if !interval.is_zero() => {
+                let wall = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default();
+                let into = wall.as_nanos() % interval.as_nanos();
+                start + (interval - duration_from_nanos(into))
+            }
+            LogAlignment::WallClock => start,
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::{
+    sync::{Arc, Condvar, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::{Timer, WaitTime};
+
+/// maximum slack in low-rate mode: a frame more than this late
+/// re-bases the schedule instead of shortening the next frame
+pub(crate) const LOW_RATE_SLACK: Duration = Duration::from_millis(100);
+
+/// interrupt flag shared between a [`Timer`] and its [`FrameInterrupt`]s
+#[derive(Debug, Default)]
+pub(crate) struct Interrupt {
+    /// set by [`FrameInterrupt::interrupt`], cleared by the wait it ends
+    pending: Mutex<bool>,
+    wake: Condvar,
+}
+
+impl Interrupt {
+    /// sleeps until `target` or an interrupt, accounting the time
+    /// spent in `spent`
+    ///
+    /// returns the last measured timestamp
+    pub(crate) fn wait_until(
+        &self,
+        target: Instant,
+        spent: &mut WaitTime,
+        clock: fn() -> Instant,
+        sample: Option<Instant>,
+    ) -> Instant {
+        let start = sample.unwrap_or_else(clock);
+        let mut now = start;
+        let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+        while !*pending && now < target {
+            pending = self
+                .wake
+                .wait_timeout(pending, target - now)
+                .unwrap_or_else(|e| e.into_inner())
+                .0;
+            now = clock();
+        }
+        *pending = false;
+        spent.sleeping += now.saturating_duration_since(start);
+        now
+    }
+}
+
+/// Handle ending the current wait of a [`Timer`] in low-rate mode early,
+/// as returned by [`Timer::frame_interrupt`].
+///
+/// The handle is `Clone + Send + Sync`, so it can be handed to the
+/// threads producing new data, e.g. a file watcher of a dashboard.
+#[derive(Clone, Debug)]
+pub struct FrameInterrupt {
+    interrupt: Arc<Interrupt>,
+}
+
+impl FrameInterrupt {
+    /// Ends the current wait for the frame target, or the next one
+    /// if the timer is not waiting.
+    pub fn interrupt(&self) {
+        *self
+            .interrupt
+            .pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = true;
+        self.interrupt.wake.notify_all();
+    }
+}
+
+impl Timer {
+    /// Enables or disables low-rate mode, for frame rates of one fps
+    /// and lower, e.g. dashboards, e-ink displays or thumbnails.
+    ///
+    /// In low-rate mode
+    /// * high precision is disabled, as a busy wait of up to a
+    ///   millisecond is irrelevant for multi-second frames,
+    /// * a frame more than 100ms late re-bases the schedule, instead of
+    ///   shortening the next frames by up to two whole frametimes, and
+    /// * waits can be interrupted with a [`FrameInterrupt`] (see
+    ///   [`Self::frame_interrupt`]) to show new data immediately.
+    ///   An interrupted frame ends early, the frame schedule is
+    ///   unaffected.
+    ///
+    /// Custom wait strategies (see [`Self::wait_strategy`]) take
+    /// precedence over interruptible waits.
+    ///
+    /// # Arguments
+    /// * `enabled` - whether to enable low-rate mode
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// // one frame every five seconds
+    /// let mut timer = Timer::default().fps(0.2).low_rate(true);
+    /// let start = timer.timebase_epoch();
+    /// timer.frame_at(start + Duration::from_secs(5));
+    /// // 50ms late: the next frame keeps its target
+    /// timer.frame_at(start + Duration::from_millis(10_050));
+    /// assert!(!timer.frame_info().is_discontinuity());
+    /// // a second late: the schedule restarts from the late frame
+    /// timer.frame_at(start + Duration::from_secs(16));
+    /// assert!(timer.frame_info().is_discontinuity());
+    /// ```
+    pub fn low_rate(mut self, enabled: bool) -> Self {
+        self.low_rate = enabled.then(Arc::default);
+        if enabled {
+            self.high_precision = false;
+        }
+        self
+    }
+
+    /// Returns a handle to interrupt waits in low-rate mode,
+    /// [`None`] unless low-rate mode is enabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::{Duration, Instant}};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.1).low_rate(true);
+    /// let interrupt = timer.frame_interrupt().unwrap();
+    /// let start = Instant::now();
+    /// thread::spawn(move || {
+    ///     // new data arrived
+    ///     thread::sleep(Duration::from_millis(20));
+    ///     interrupt.interrupt();
+    /// });
+    /// timer.frame();
+    /// assert!(start.elapsed() < Duration::from_secs(5));
+    /// ```
+    pub fn frame_interrupt(&self) -> Option<FrameInterrupt> {
+        let interrupt = self.low_rate.clone()?;
+        Some(FrameInterrupt { interrupt })
+    }
+}
@@ -0,0 +1,212 @@
+use std::time::Duration;
+
+use crate::{duration_from_nanos, pacing_core::Schedule, Timer};
+
+/// Timing of a single buffer produced by a [`PtsPacer`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BufferTiming {
+    /// index of the buffer
+    index: u64,
+    /// presentation timestamp
+    pts: Duration,
+    /// duration of the buffer
+    duration: Duration,
+}
+
+impl BufferTiming {
+    /// index of the buffer since the start of the stream
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// presentation timestamp relative to the start of the stream
+    pub fn pts(&self) -> Duration {
+        self.pts
+    }
+
+    /// presentation timestamp in nanoseconds
+    /// (e.g. for `gstreamer::ClockTime::from_nseconds`)
+    pub fn pts_nanos(&self) -> u64 {
+        self.pts.as_nanos() as u64
+    }
+
+    /// duration of the buffer, such that `pts + duration`
+    /// equals the pts of the next buffer
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+/// Paces a media source (e.g. a GStreamer `appsrc` push loop)
+/// at an exact rational frame rate.
+///
+/// Presentation timestamps are computed from the buffer index and
+/// the rational rate instead of accumulating rounded frametimes or
+/// sampling the clock, so they are monotonically increasing, free of
+/// jitter and never drift from the nominal rate. Pushing is paced by a
+/// [`Timer`] in [`Timer::average_rate`] mode on the same rational
+/// schedule, which is never re-based: after a stall, the missed
+/// buffers are pushed back to back until the pushes are on time for
+/// their timestamps again.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::PtsPacer;
+///
+/// // 29.97 fps
+/// let mut pacer = PtsPacer::new(30_000, 1001);
+/// let first = pacer.next_buffer();
+/// let second = pacer.next_buffer();
+/// assert_eq!(first.pts_nanos(), 0);
+/// assert_eq!(second.pts_nanos(), 33_366_666);
+/// assert_eq!(first.pts() + first.duration(), second.pts());
+/// // push the buffer with its pts and duration
+/// ```
+pub struct PtsPacer {
+    /// timer pacing the source
+    timer: Timer,
+    /// presentation timestamps in nanoseconds since the start
+    schedule: Schedule,
+    /// frame rate numerator
+    numerator: u64,
+    /// frame rate denominator
+    denominator: u64,
+    /// index of the next buffer
+    index: u64,
+}
+
+impl PtsPacer {
+    /// Creates a pacer for `numerator / denominator` frames per second.
+    ///
+    /// # Panics
+    /// if `numerator` or `denominator` is zero
+    pub fn new(numerator: u64, denominator: u64) -> Self {
+        assert!(numerator > 0 && denominator > 0, "invalid frame rate");
+        Self {
+            timer: Timer::default().average_rate(numerator, denominator),
+            schedule: Schedule::from_rate(numerator, denominator),
+            numerator,
+            denominator,
+            index: 0,
+        }
+    }
+
+    /// exact pts of buffer `index`, rounded down to nanoseconds
+    fn pts(&self, index: u64) -> Duration {
+        let mut schedule = self.schedule;
+        schedule.restart(0);
+        duration_from_nanos(schedule.advance_by(index))
+    }
+
+    /// Returns the timing of the first buffer immediately and waits
+    /// for the frame target of every following buffer.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::{Duration, Instant}};
+    /// use fps_timer::PtsPacer;
+    ///
+    /// let start = Instant::now();
+    /// let mut pacer = PtsPacer::new(100, 1);
+    /// pacer.next_buffer();
+    /// // a stall of 10 buffers
+    /// thread::sleep(Duration::from_millis(100));
+    /// for _ in 0..10 {
+    ///     pacer.next_buffer();
+    /// }
+    /// // the missed buffers are pushed back to back, on time for their pts
+    /// assert_eq!(pacer.next_buffer().pts(), Duration::from_millis(110));
+    /// assert!(start.elapsed() < Duration::from_millis(180));
+    /// ```
+    pub fn next_buffer(&mut self) -> BufferTiming {
+        if self.index > 0 {
+            self.timer.frame();
+        }
+        let index = self.index;
+        self.index += 1;
+        let pts = self.pts(index);
+        BufferTiming {
+            index,
+            pts,
+            duration: self.pts(index + 1) - pts,
+        }
+    }
+
+    /// frame rate as numerator and denominator
+    pub fn rate(&self) -> (u64, u64) {
+        (self.numerator, self.denominator)
+    }
+
+    /// the timer pacing the source, e.g. for logging
+    pub fn timer(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
+
+#[cfg(feature = "gstreamer")]
+impl BufferTiming {
+    /// Sets the pts, duration and offsets of `buffer` to this timing.
+    ///
+    /// Requires the `gstreamer` feature.
+    pub fn apply(&self, buffer: &mut gstreamer::BufferRef) {
+        let duration = self.duration.as_nanos() as u64;
+        buffer.set_pts(gstreamer::ClockTime::from_nseconds(self.pts_nanos()));
+        buffer.set_duration(gstreamer::ClockTime::from_nseconds(duration));
+        buffer.set_offset(self.index);
+        buffer.set_offset_end(self.index + 1);
+    }
+}
+
+#[cfg(feature = "gstreamer")]
+impl PtsPacer {
+    /// Waits for the next buffer like [`Self::next_buffer`] and stamps
+    /// `buffer` with its pts, duration and offsets, e.g. before pushing
+    /// it into an `appsrc`.
+    ///
+    /// Requires the `gstreamer` feature.
+    ///
+    /// # Arguments
+    /// * `buffer` - buffer to stamp
+    ///
+    /// # Returns
+    /// [`BufferTiming`] the timing the buffer was stamped with
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::PtsPacer;
+    ///
+    /// gstreamer::init().unwrap();
+    /// let mut pacer = PtsPacer::new(30_000, 1001);
+    /// let caps = gstreamer::Caps::builder("video/x-raw")
+    ///     .field("framerate", pacer.framerate())
+    ///     .build();
+    /// assert_eq!(caps.structure(0).unwrap().get::<gstreamer::Fraction>("framerate").unwrap(),
+    ///     gstreamer::Fraction::new(30_000, 1001));
+    ///
+    /// for index in 0..2 {
+    ///     let mut buffer = gstreamer::Buffer::with_size(16).unwrap();
+    ///     let timing = pacer.stamp_buffer(buffer.get_mut().unwrap());
+    ///     assert_eq!(buffer.offset(), index);
+    ///     assert_eq!(buffer.pts().unwrap().nseconds(), timing.pts_nanos());
+    ///     // appsrc.push_buffer(buffer)
+    /// }
+    /// ```
+    pub fn stamp_buffer(&mut self, buffer: &mut gstreamer::BufferRef) -> BufferTiming {
+        let timing = self.next_buffer();
+        timing.apply(buffer);
+        timing
+    }
+
+    /// Frame rate of the pacer for the `framerate` field of caps.
+    ///
+    /// Requires the `gstreamer` feature.
+    ///
+    /// # Panics
+    /// if the numerator or denominator exceed [`i32::MAX`]
+    pub fn framerate(&self) -> gstreamer::Fraction {
+        let (numerator, denominator) = self.rate();
+        let numerator = i32::try_from(numerator).expect("numerator exceeds i32::MAX");
+        let denominator = i32::try_from(denominator).expect("denominator exceeds i32::MAX");
+        gstreamer::Fraction::new(numerator, denominator)
+    }
+}
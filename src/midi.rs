@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use crate::Timer;
+
+/// MIDI timing clock message, sent 24 times per quarter note
+pub const MIDI_TIMING_CLOCK: u8 = 0xF8;
+/// MIDI start message
+pub const MIDI_START: u8 = 0xFA;
+/// MIDI continue message
+pub const MIDI_CONTINUE: u8 = 0xFB;
+/// MIDI stop message
+pub const MIDI_STOP: u8 = 0xFC;
+
+/// MIDI clock pulses per quarter note
+const PPQN: u64 = 24;
+
+/// MIDI clock source driven by the high precision scheduler of a [`Timer`].
+///
+/// [`Self::tick`] waits for the next clock pulse (24 per quarter note)
+/// and returns the MIDI timing clock message, which can be sent to
+/// hardware sequencers through any MIDI output (e.g. `midir`). Pulses
+/// are scheduled at an exact long-term rate (see
+/// [`Timer::average_rate`]), so the clock never drifts from the tempo.
+///
+/// Running the clock on its own thread and sharing [`Self::beats`]
+/// with the visual frame loop drives both from one timing source.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::{MidiClock, MIDI_START, MIDI_TIMING_CLOCK};
+///
+/// let mut clock = MidiClock::new(480.);
+/// assert_eq!(clock.start(), MIDI_START);
+/// // send the message to the MIDI output
+/// for _ in 0..24 {
+///     assert_eq!(clock.tick(), MIDI_TIMING_CLOCK);
+/// }
+/// assert_eq!(clock.beats(), 1.);
+/// ```
+pub struct MidiClock {
+    /// timer pacing the clock pulses
+    timer: Timer,
+    /// tempo in beats per minute
+    bpm: f64,
+    /// pulses since the clock was started
+    pulses: u64,
+}
+
+impl MidiClock {
+    /// Creates a stopped clock running at `bpm` beats per minute.
+    ///
+    /// # Panics
+    /// if `bpm` is not positive
+    pub fn new(bpm: f64) -> Self {
+        let mut clock = Self {
+            timer: Timer::default(),
+            bpm,
+            pulses: 0,
+        };
+        clock.set_bpm(bpm);
+        clock
+    }
+
+    /// Changes the tempo, effective from the previous pulse.
+    ///
+    /// The tempo is rounded to 1/1000 beats per minute.
+    ///
+    /// # Panics
+    /// if `bpm` is not positive
+    pub fn set_bpm(&mut self, bpm: f64) {
+        assert!(bpm > 0., "tempo must be positive");
+        let millibpm = ((bpm * 1000.).round() as u64).max(1);
+        // pulses per second = bpm * 24 / 60
+        let timer = std::mem::take(&mut self.timer);
+        self.timer = timer
+            .high_precision(true)
+            .average_rate(millibpm * PPQN, 60_000);
+        self.bpm = bpm;
+    }
+
+    /// tempo in beats per minute
+    pub fn bpm(&self) -> f64 {
+        self.bpm
+    }
+
+    /// Starts the clock from the beginning and returns the start message,
+    /// which has to be sent before the first pulse.
+    pub fn start(&mut self) -> u8 {
+        self.pulses = 0;
+        self.timer.reset_at(Instant::now());
+        MIDI_START
+    }
+
+    /// Resumes the clock at the current position and returns
+    /// the continue message.
+    pub fn resume(&mut self) -> u8 {
+        self.timer.reset_at(Instant::now());
+        MIDI_CONTINUE
+    }
+
+    /// Returns the stop message. Pulses are not sent until
+    /// the clock is started or resumed again.
+    pub fn stop(&self) -> u8 {
+        MIDI_STOP
+    }
+
+    /// Waits for the next clock pulse and returns the timing clock message.
+    pub fn tick(&mut self) -> u8 {
+        self.timer.frame();
+        self.pulses += 1;
+        MIDI_TIMING_CLOCK
+    }
+
+    /// pulses since the clock was started
+    pub fn pulses(&self) -> u64 {
+        self.pulses
+    }
+
+    /// position in quarter notes since the clock was started
+    pub fn beats(&self) -> f64 {
+        self.pulses as f64 / PPQN as f64
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    io::{self, Write},
+    net::{Shutdown, TcpStream, ToSocketAddrs, UdpSocket},
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use crate::{Log, LogSink};
+
+/// message kind of a [`Log`] record, followed by its textual form
+const KIND_LOG: u8 = b'L';
+/// message kind of a frame record, followed by the frametime
+/// in nanoseconds as big-endian `u64`
+const KIND_FRAME: u8 = b'F';
+
+/// how long a write may block the frame loop before
+/// a TCP connection is considered dead
+const WRITE_TIMEOUT: Duration = Duration::from_millis(5);
+
+/// transport of a [`NetSink`]
+#[derive(Debug)]
+enum Connection {
+    Udp(UdpSocket),
+    /// [`None`] once the connection failed
+    Tcp(Option<TcpStream>),
+}
+
+impl Connection {
+    fn send(&mut self, kind: u8, payload: &[u8]) {
+        let len = u32::try_from(payload.len() + 1).unwrap_or(u32::MAX);
+        let mut message = Vec::with_capacity(payload.len() + 5);
+        message.extend_from_slice(&len.to_be_bytes());
+        message.push(kind);
+        message.extend_from_slice(payload);
+        match self {
+            // datagrams may get lost, which is fine for live monitoring
+            Connection::Udp(socket) => {
+                let _ = socket.send(&message);
+            }
+            Connection::Tcp(stream) => {
+                let failed = stream
+                    .as_mut()
+                    .is_some_and(|stream| stream.write_all(&message).is_err());
+                if failed {
+                    // a partial write would corrupt the stream
+                    *stream = None;
+                }
+            }
+        }
+    }
+}
+
+/// [`LogSink`] streaming interval statistics over UDP or TCP, so a
+/// companion tool on another machine can monitor frame pacing live.
+///
+/// Every message is length-prefixed: a big-endian `u32` holding the
+/// number of bytes that follow, a kind byte and the payload.
+///
+/// | kind  | payload                                               |
+/// |-------|-------------------------------------------------------|
+/// | `'L'` | a [`Log`] in its textual form (UTF-8, `key=value ...`) |
+/// | `'F'` | a frametime in nanoseconds as big-endian `u64`         |
+///
+/// Per-frame records are only sent if [`Self::frame_stream`] is
+/// registered as a frame hook. Sending never blocks the frame loop for
+/// long: a TCP connection that fails or stalls is dropped for good,
+/// see [`Self::is_connected`].
+///
+/// # Example
+/// ```rust
+/// use std::{io::Read, net::TcpListener, time::Duration};
+/// use fps_timer::{NetSink, Timer};
+///
+/// let monitor = TcpListener::bind("127.0.0.1:0")?;
+/// let sink = NetSink::tcp(monitor.local_addr()?)?;
+/// let mut timer = Timer::default().fps(0.);
+/// timer.on_frame_end(sink.frame_stream());
+/// let mut timer = timer.log_sink(sink);
+/// timer.frame();
+///
+/// let (mut stream, _) = monitor.accept()?;
+/// let mut header = [0; 5];
+/// stream.read_exact(&mut header)?;
+/// assert_eq!(u32::from_be_bytes(header[..4].try_into().unwrap()), 9);
+/// assert_eq!(header[4], b'F');
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct NetSink {
+    /// connection shared with the frame streams
+    connection: Arc<Mutex<Connection>>,
+}
+
+impl NetSink {
+    fn new(connection: Connection) -> Self {
+        Self {
+            connection: Arc::new(Mutex::new(connection)),
+        }
+    }
+
+    /// Creates a sink sending a datagram per message to `addr`.
+    pub fn udp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no address"))?;
+        let local = if addr.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(addr)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self::new(Connection::Udp(socket)))
+    }
+
+    /// Creates a sink streaming messages over a TCP connection to `addr`.
+    pub fn tcp(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        Ok(Self::new(Connection::Tcp(Some(stream))))
+    }
+
+    /// `false` once a TCP connection failed, always `true` for UDP
+    pub fn is_connected(&self) -> bool {
+        match &*self.lock() {
+            Connection::Udp(_) => true,
+            Connection::Tcp(stream) => stream.is_some(),
+        }
+    }
+
+    /// Returns a frame hook sending a record per frame over the
+    /// same connection, to be registered with
+    /// [`crate::Timer::on_frame_end`].
+    pub fn frame_stream(&self) -> impl FnMut(Duration) + Send + Sync + 'static {
+        let connection = self.connection.clone();
+        move |frame_time| {
+            let nanos = u64::try_from(frame_time.as_nanos()).unwrap_or(u64::MAX);
+            let mut connection = connection.lock().unwrap_or_else(|e| e.into_inner());
+            connection.send(KIND_FRAME, &nanos.to_be_bytes());
+        }
+    }
+
+    /// locks the connection, ignoring poisoning by a panicking hook
+    fn lock(&self) -> MutexGuard<'_, Connection> {
+        self.connection.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl LogSink for NetSink {
+    fn log(&mut self, log: &Log) {
+        self.lock().send(KIND_LOG, log.to_string().as_bytes());
+    }
+
+    fn flush(&mut self, timeout: Duration) {
+        if let Connection::Tcp(stream) = &mut *self.lock() {
+            if let Some(stream) = stream.take() {
+                // a zero timeout would block indefinitely
+                let timeout = timeout.max(Duration::from_nanos(1));
+                let _ = stream.set_write_timeout(Some(timeout));
+                let _ = (&stream).flush();
+                let _ = stream.shutdown(Shutdown::Write);
+            }
+        }
+    }
+}
@@ -0,0 +1,139 @@
+use std::{
+    io,
+    net::{ToSocketAddrs, UdpSocket},
+    sync::Arc,
+    time::Duration,
+};
+
+use crate::{Log, LogSink};
+
+/// argument of an OSC message
+#[derive(Clone, Copy, Debug)]
+enum Arg {
+    Int(i32),
+    Float(f32),
+}
+
+/// appends `s` as an OSC string (null terminated, padded to 4 bytes)
+fn push_str(buf: &mut Vec<u8>, s: &str) {
+    buf.extend_from_slice(s.as_bytes());
+    let padding = 4 - s.len() % 4;
+    buf.extend(std::iter::repeat_n(0, padding));
+}
+
+/// encodes an OSC message with address `addr` and arguments `args`
+fn encode(addr: &str, args: &[Arg]) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(addr.len() + 8 + args.len() * 5);
+    push_str(&mut buf, addr);
+    let tags: String = std::iter::once(',')
+        .chain(args.iter().map(|arg| match arg {
+            Arg::Int(_) => 'i',
+            Arg::Float(_) => 'f',
+        }))
+        .collect();
+    push_str(&mut buf, &tags);
+    for arg in args {
+        match arg {
+            Arg::Int(i) => buf.extend_from_slice(&i.to_be_bytes()),
+            Arg::Float(f) => buf.extend_from_slice(&f.to_be_bytes()),
+        }
+    }
+    buf
+}
+
+/// [`LogSink`] emitting frame and stats events as OSC messages over UDP,
+/// so creative-coding environments (TouchDesigner, Max, SuperCollider, ...)
+/// can synchronize to and monitor the frame clock.
+///
+/// Messages (with the default prefix `/fps_timer`):
+///
+/// * `/fps_timer/frame ,if` - frame index and frametime in seconds,
+///   sent by [`Self::frame_stream`] if registered as a frame hook
+/// * `/fps_timer/stats ,fffffi` - average fps, average, median and 99th
+///   percentile frametime in seconds, stability score and number of
+///   hitches, sent for every logging interval
+///
+/// Requires the `osc` feature.
+///
+/// # Example
+/// ```rust
+/// use std::{net::UdpSocket, time::Duration};
+/// use fps_timer::{OscSink, Timer};
+///
+/// let receiver = UdpSocket::bind("127.0.0.1:0")?;
+/// let osc = OscSink::new(receiver.local_addr()?)?.prefix("/render");
+/// let mut timer = Timer::default().fps(0.);
+/// timer.on_frame_end(osc.frame_stream());
+/// let mut timer = timer.log_sink(osc);
+/// timer.frame();
+///
+/// let mut packet = [0; 64];
+/// let len = receiver.recv(&mut packet)?;
+/// assert!(packet[..len].starts_with(b"/render/frame\0\0\0,if\0"));
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct OscSink {
+    /// socket connected to the OSC receiver
+    socket: Arc<UdpSocket>,
+    /// address prefix of all messages
+    prefix: String,
+}
+
+impl OscSink {
+    /// Creates a sink sending OSC messages to `addr`.
+    pub fn new(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        let addr = addr
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no address"))?;
+        let local = if addr.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(addr)?;
+        socket.set_nonblocking(true)?;
+        Ok(Self {
+            socket: Arc::new(socket),
+            prefix: "/fps_timer".into(),
+        })
+    }
+
+    /// Sets the address prefix of all messages, `/fps_timer` by default.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Returns a frame hook sending a `frame` message per frame,
+    /// to be registered with [`crate::Timer::on_frame_end`].
+    pub fn frame_stream(&self) -> impl FnMut(Duration) + Send + Sync + 'static {
+        let socket = self.socket.clone();
+        let addr = format!("{}/frame", self.prefix);
+        let mut index = 0i32;
+        move |frame_time| {
+            let args = [Arg::Int(index), Arg::Float(frame_time.as_secs_f32())];
+            let _ = socket.send(&encode(&addr, &args));
+            index = index.wrapping_add(1);
+        }
+    }
+}
+
+impl LogSink for OscSink {
+    fn log(&mut self, log: &Log) {
+        let stats = log.frame_time_stats();
+        let args = [
+            Arg::Float(log.fps_average() as f32),
+            Arg::Float(log.delta_time_avg().as_secs_f32()),
+            Arg::Float(stats.median().as_secs_f32()),
+            Arg::Float(stats.p99().as_secs_f32()),
+            Arg::Float(log.stability_score() as f32),
+            Arg::Int(i32::try_from(stats.hitches()).unwrap_or(i32::MAX)),
+        ];
+        let addr = format!("{}/stats", self.prefix);
+        // datagrams may get lost, which is fine for monitoring
+        let _ = self.socket.send(&encode(&addr, &args));
+    }
+}
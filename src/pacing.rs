@@ -0,0 +1,46 @@
+use crate::Timer;
+
+/// Who paces the frames of a [`Timer`], see [`Timer::pacing`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Pacing {
+    /// the timer waits for frame targets
+    #[default]
+    Internal,
+    /// frames are paced elsewhere, e.g. by vsync in the present call,
+    /// and the timer never waits
+    External,
+}
+
+impl Timer {
+    /// Selects who paces the frames.
+    ///
+    /// With [`Pacing::External`], [`Self::frame`] never sleeps or spins,
+    /// but still measures frametimes and keeps statistics, logging and
+    /// hitch detection active, i.e. the timer only reports frame
+    /// statistics. The frametime set with [`Self::fps`] remains the
+    /// expected frametime, e.g. the refresh period of the display,
+    /// that lateness and hitches are measured against.
+    ///
+    /// # Arguments
+    /// * `pacing` - [`Pacing::Internal`] by default
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::{Pacing, Timer};
+    ///
+    /// // the swapchain blocks on vsync
+    /// let mut timer = Timer::default().fps(60.).pacing(Pacing::External);
+    /// let start = Instant::now();
+    /// timer.frame();
+    /// timer.frame();
+    /// assert!(start.elapsed() < Duration::from_millis(16));
+    /// ```
+    pub fn pacing(mut self, pacing: Pacing) -> Self {
+        self.pacing = pacing;
+        self
+    }
+}
@@ -0,0 +1,588 @@
+//! Pacing math of the [`Timer`](crate::Timer) without clocks, sleeping
+//! or threads.
+//!
+//! Everything in this module works on plain [`Duration`]s and integer
+//! nanosecond offsets instead of [`std::time::Instant`]s, so it can be
+//! driven by any time source: a simulated clock in tests, the tick
+//! counter of a microcontroller or `performance.now()` in a browser.
+//! The [`Timer`](crate::Timer) is the std-backed frontend on top of it,
+//! which samples the clock and waits for the targets computed here.
+//!
+//! This module does not depend on `std` and is available when the crate
+//! is built without default features, e.g. for `no_std` targets.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use fps_timer::pacing_core::{Accumulator, FrameTimeStats, Schedule};
+//!
+//! // 3 fps, frame targets in nanoseconds since the start
+//! let mut schedule = Schedule::from_fps(3.);
+//! assert_eq!(schedule.start(0), 333_333_333);
+//! assert_eq!(schedule.advance(), 666_666_666);
+//! schedule.advance();
+//! // without accumulated rounding
+//! assert_eq!(schedule.advance(), 1_333_333_333);
+//!
+//! // fixed 10ms simulation steps for 25ms of rendering
+//! let mut accumulator = Accumulator::new(Duration::from_millis(10));
+//! accumulator.push(Duration::from_millis(25));
+//! assert_eq!(accumulator.drain(), 2);
+//! assert_eq!(accumulator.alpha(), 0.5);
+//!
+//! let ms = Duration::from_millis;
+//! let mut frame_times = [ms(10), ms(10), ms(30), ms(10)];
+//! let stats = FrameTimeStats::from_frame_times(&mut frame_times, ms(20));
+//! assert_eq!(stats.median(), ms(10));
+//! assert_eq!(stats.hitches(), 1);
+//! // mean of the 3 differences between consecutive frametimes
+//! assert_eq!(stats.jitter(), ms(40) / 3);
+//! ```
+
+use core::time::Duration;
+
+/// longest supported frametime or interval (~136 years), keeping all
+/// [`std::time::Instant`] arithmetic far away from overflowing
+pub const MAX_INTERVAL: Duration = Duration::from_secs(u32::MAX as u64);
+
+/// fractional bits of the fixed point frametime
+const FRACTION_BITS: u32 = 32;
+
+/// Frametime for a target framerate of `fps`,
+/// non-positive and NaN framerates meaning uncapped.
+pub fn frame_time_from_fps(fps: f64) -> Duration {
+    if fps.is_nan() || fps <= 0. {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(1. / fps).map_or(MAX_INTERVAL, |d| d.min(MAX_INTERVAL))
+}
+
+#[cfg(feature = "std")]
+/// converts nanoseconds to a [`Duration`], saturating at `u64::MAX` nanoseconds
+pub(crate) fn duration_from_nanos(nanos: u128) -> Duration {
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/// frametime in nanoseconds as 96.32 fixed point
+pub(crate) const fn fixed_period(delta: Duration) -> u128 {
+    delta.as_nanos() << FRACTION_BITS
+}
+
+/// exact frametime for `fps` in nanoseconds as 96.32 fixed point,
+/// zero for non-positive and NaN framerates
+fn fixed_period_from_fps(fps: f64) -> u128 {
+    if fps.is_nan() || fps <= 0. {
+        return 0;
+    }
+    let max = fixed_period(MAX_INTERVAL);
+    let period = 1e9 / fps * (1u64 << FRACTION_BITS) as f64;
+    if period >= max as f64 {
+        max
+    } else {
+        period as u128
+    }
+}
+
+/// Schedule of frame targets as nanosecond offsets from an epoch.
+///
+/// The frametime is kept to a fraction of a nanosecond and targets are
+/// computed from the start of the schedule instead of by adding up
+/// frametimes, so rounding errors do not accumulate even for very
+/// high framerates and very long sessions.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::pacing_core::Schedule;
+///
+/// let mut schedule = Schedule::new(Duration::from_millis(10));
+/// schedule.restart(5_000_000);
+/// assert_eq!(schedule.advance(), 15_000_000);
+/// assert_eq!(schedule.frames(), 1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    /// exact frametime in nanoseconds as 96.32 fixed point
+    period: u128,
+    /// start of the schedule in nanoseconds since the epoch
+    origin: u128,
+    /// frames scheduled since the start
+    frames: u64,
+}
+
+impl Schedule {
+    /// Creates a schedule of frames taking `frame_time`,
+    /// starting at the epoch.
+    pub const fn new(frame_time: Duration) -> Self {
+        let period = fixed_period(frame_time);
+        let max = fixed_period(MAX_INTERVAL);
+        Self {
+            period: if period > max { max } else { period },
+            origin: 0,
+            frames: 0,
+        }
+    }
+
+    /// Creates a schedule with the exact frametime for `fps`,
+    /// starting at the epoch.
+    pub fn from_fps(fps: f64) -> Self {
+        Self {
+            period: fixed_period_from_fps(fps),
+            origin: 0,
+            frames: 0,
+        }
+    }
+
+    /// Creates a schedule with the exact frametime for a framerate of
+    /// `numerator / denominator` frames per second, starting at the epoch.
+    /// A zero `numerator` means uncapped.
+    ///
+    /// Unlike [`Self::from_fps`], this can be used in `const` contexts
+    /// and is exact for rational framerates like 59.94 (`60_000 / 1001`).
+    ///
+    /// # Panics
+    /// if `denominator` is zero
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::pacing_core::Schedule;
+    ///
+    /// const NTSC: Schedule = Schedule::from_rate(60_000, 1001);
+    /// assert_eq!(NTSC.frame_time(), Duration::from_nanos(16_683_333));
+    /// ```
+    pub const fn from_rate(numerator: u64, denominator: u64) -> Self {
+        assert!(denominator > 0, "denominator must not be zero");
+        let max = fixed_period(MAX_INTERVAL);
+        let period = match numerator {
+            0 => 0,
+            // rounded up, so targets that are whole nanoseconds are hit exactly
+            n => ((denominator as u128 * 1_000_000_000) << FRACTION_BITS).div_ceil(n as u128),
+        };
+        Self {
+            period: if period > max { max } else { period },
+            origin: 0,
+            frames: 0,
+        }
+    }
+
+    /// Creates a schedule with the exact frametime `period`, given in
+    /// nanoseconds as 96.32 fixed point (see [`Self::fixed_period`]),
+    /// starting at the epoch.
+    pub fn from_fixed_period(period: u128) -> Self {
+        Self {
+            period: period.min(fixed_period(MAX_INTERVAL)),
+            origin: 0,
+            frames: 0,
+        }
+    }
+
+    /// frametime rounded down to whole nanoseconds,
+    /// zero for uncapped schedules
+    pub const fn frame_time(&self) -> Duration {
+        // periods are limited to MAX_INTERVAL, so the nanoseconds fit
+        Duration::from_nanos((self.period >> FRACTION_BITS) as u64)
+    }
+
+    /// Exact frametime in nanoseconds as 96.32 fixed point, i.e. in
+    /// units of 2^-32 nanoseconds, e.g. to store a schedule losslessly.
+    pub fn fixed_period(&self) -> u128 {
+        self.period
+    }
+
+    /// frames scheduled since the start of the schedule
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// target of the most recently scheduled frame
+    /// in nanoseconds since the epoch
+    pub fn target(&self) -> u128 {
+        let offset = (self.frames as u128).saturating_mul(self.period) >> FRACTION_BITS;
+        self.origin.saturating_add(offset)
+    }
+
+    /// Restarts the schedule at `origin` nanoseconds since the epoch,
+    /// e.g. after the frametime changed or frames were dropped,
+    /// without scheduling a frame.
+    pub fn restart(&mut self, origin: u128) {
+        self.origin = origin;
+        self.frames = 0;
+    }
+
+    /// Restarts the schedule at `origin` nanoseconds since the epoch
+    /// and returns the target of the first frame.
+    pub fn start(&mut self, origin: u128) -> u128 {
+        self.origin = origin;
+        self.frames = 1;
+        self.target()
+    }
+
+    /// Schedules the next frame and returns its target.
+    pub fn advance(&mut self) -> u128 {
+        self.frames += 1;
+        self.target()
+    }
+
+    /// Schedules the next `frames` frames at once, e.g. to skip frames
+    /// that are too late to be run, and returns the target of the last.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::pacing_core::Schedule;
+    ///
+    /// // 3 fps, 2.5s late
+    /// let mut schedule = Schedule::from_fps(3.);
+    /// let late = schedule.frames_in(2_500_000_000);
+    /// assert_eq!(late, 7);
+    /// assert_eq!(schedule.advance_by(late), 2_333_333_333);
+    /// ```
+    pub fn advance_by(&mut self, frames: u64) -> u128 {
+        self.frames = self.frames.saturating_add(frames);
+        self.target()
+    }
+
+    /// number of whole frametimes in `nanos` nanoseconds,
+    /// zero for uncapped schedules
+    pub fn frames_in(&self, nanos: u128) -> u64 {
+        match self.period {
+            0 => 0,
+            period => {
+                u64::try_from(nanos.saturating_mul(1 << FRACTION_BITS) / period).unwrap_or(u64::MAX)
+            }
+        }
+    }
+}
+
+/// Fixed timestep accumulator, splitting variable frametimes into
+/// fixed simulation steps.
+///
+/// Time that does not fill a whole step is carried over to the next
+/// frame, and the fraction of a step it covers is returned by
+/// [`Self::alpha`] to interpolate the rendered state between the
+/// two most recent steps.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{pacing_core::Accumulator, Timer};
+///
+/// let mut timer = Timer::default().fps(200.);
+/// let mut accumulator = Accumulator::new(Duration::from_millis(2));
+/// for _ in 0..3 {
+///     accumulator.push(timer.frame());
+///     while accumulator.step() {
+///         // advance the simulation by 2ms
+///     }
+///     assert!(accumulator.alpha() < 1.);
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Accumulator {
+    /// duration of a step
+    step: Duration,
+    /// time not yet consumed by steps
+    accumulated: Duration,
+    /// maximum accumulated time, dropping time beyond it
+    max: Duration,
+}
+
+impl Accumulator {
+    /// Creates an accumulator of steps taking `step`, which
+    /// carries over at most 8 steps to limit catching up after stalls.
+    pub fn new(step: Duration) -> Self {
+        Self {
+            step,
+            accumulated: Duration::ZERO,
+            max: step.saturating_mul(8),
+        }
+    }
+
+    /// Sets the number of steps that can be carried over, dropping
+    /// time beyond them, e.g. after a breakpoint.
+    ///
+    /// # Arguments
+    /// * `steps` - maximum steps carried over, `8` by default
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) accumulator
+    pub fn max_steps(mut self, steps: u32) -> Self {
+        self.max = self.step.saturating_mul(steps);
+        self.accumulated = self.accumulated.min(self.max);
+        self
+    }
+
+    /// duration of a step
+    pub fn step_duration(&self) -> Duration {
+        self.step
+    }
+
+    /// Adds the frametime `delta` to the accumulated time and returns
+    /// the time that was dropped because it exceeded the maximum.
+    pub fn push(&mut self, delta: Duration) -> Duration {
+        let accumulated = self.accumulated.saturating_add(delta);
+        self.accumulated = accumulated.min(self.max);
+        accumulated - self.accumulated
+    }
+
+    /// Consumes a single step, returning `false` if less than a step
+    /// is accumulated.
+    pub fn step(&mut self) -> bool {
+        match self.accumulated.checked_sub(self.step) {
+            Some(rest) if !self.step.is_zero() => {
+                self.accumulated = rest;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Consumes all whole steps and returns their number.
+    pub fn drain(&mut self) -> u32 {
+        let mut steps = 0;
+        while self.step() {
+            steps += 1;
+        }
+        steps
+    }
+
+    /// time accumulated, but not yet consumed by steps
+    pub fn remainder(&self) -> Duration {
+        self.accumulated
+    }
+
+    /// fraction of a step covered by the remainder, in `[0, 1)`
+    /// once all whole steps are consumed
+    pub fn alpha(&self) -> f64 {
+        match self.step.is_zero() {
+            true => 0.,
+            false => self.accumulated.as_secs_f64() / self.step.as_secs_f64(),
+        }
+    }
+}
+
+/// number of words of [`FrameTimeStats::to_words`]
+#[cfg(feature = "std")]
+pub(crate) const STATS_WORDS: usize = 8;
+
+/// Frametime statistics over a logging interval or a whole session
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimeStats {
+    /// number of frames
+    frames: u64,
+    /// shortest frametime
+    min: Duration,
+    /// longest frametime
+    max: Duration,
+    /// median frametime
+    median: Duration,
+    /// 99th percentile frametime
+    p99: Duration,
+    /// mean absolute difference between consecutive frametimes
+    jitter: Duration,
+    /// number of hitches
+    hitches: u64,
+    /// stability score
+    stability: f64,
+}
+
+impl FrameTimeStats {
+    pub(crate) fn new(
+        frames: u64,
+        sorted: Sorted,
+        jitter: Duration,
+        mean: Duration,
+        hitches: u64,
+    ) -> Self {
+        let Sorted {
+            min,
+            max,
+            median,
+            p99,
+        } = sorted;
+        let relative = |d: Duration, base: Duration| match base.is_zero() {
+            true => 0.,
+            false => d.as_secs_f64() / base.as_secs_f64(),
+        };
+        let jitter_ratio = relative(jitter, mean);
+        let spread = relative(p99.saturating_sub(median), median);
+        let hitch_ratio = match frames {
+            0 => 0.,
+            frames => hitches as f64 / frames as f64,
+        };
+        Self {
+            frames,
+            min,
+            max,
+            median,
+            p99,
+            jitter,
+            hitches,
+            stability: stability_score(jitter_ratio, spread, hitch_ratio),
+        }
+    }
+
+    /// the statistics as plain words, e.g. to publish them through
+    /// atomics, see [`Self::from_words`]
+    #[cfg(feature = "std")]
+    pub(crate) fn to_words(self) -> [u64; STATS_WORDS] {
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        [
+            self.frames,
+            nanos(self.min),
+            nanos(self.max),
+            nanos(self.median),
+            nanos(self.p99),
+            nanos(self.jitter),
+            self.hitches,
+            self.stability.to_bits(),
+        ]
+    }
+
+    /// statistics from words written by [`Self::to_words`]
+    #[cfg(feature = "std")]
+    pub(crate) fn from_words(words: [u64; STATS_WORDS]) -> Self {
+        let [frames, min, max, median, p99, jitter, hitches, stability] = words;
+        Self {
+            frames,
+            min: Duration::from_nanos(min),
+            max: Duration::from_nanos(max),
+            median: Duration::from_nanos(median),
+            p99: Duration::from_nanos(p99),
+            jitter: Duration::from_nanos(jitter),
+            hitches,
+            stability: f64::from_bits(stability),
+        }
+    }
+
+    /// Statistics of consecutive `frame_times`, counting frames longer
+    /// than `hitch_threshold` as hitches. Sorts `frame_times` in place
+    /// instead of allocating.
+    ///
+    /// # Arguments
+    /// * `frame_times` - frametimes in the order of the frames
+    /// * `hitch_threshold` - frametime a frame must exceed to be a hitch
+    pub fn from_frame_times(frame_times: &mut [Duration], hitch_threshold: Duration) -> Self {
+        let frames = frame_times.len() as u64;
+        if frames == 0 {
+            return Self::default();
+        }
+        let jitter: Duration = frame_times
+            .windows(2)
+            .map(|pair| pair[0].abs_diff(pair[1]))
+            .sum();
+        let hitches = frame_times.iter().filter(|&&d| d > hitch_threshold).count();
+        let total: Duration = frame_times.iter().sum();
+        frame_times.sort_unstable();
+        // rounds to the nearest rank without `f64::round`, which needs std
+        let percentile = |p: f64| frame_times[((frames - 1) as f64 * p + 0.5) as usize];
+        let sorted = Sorted {
+            min: frame_times[0],
+            max: frame_times[frame_times.len() - 1],
+            median: percentile(0.5),
+            p99: percentile(0.99),
+        };
+        let mean = total.div_f64(frames as f64);
+        let jitter = match frames {
+            1 => Duration::ZERO,
+            frames => jitter.div_f64((frames - 1) as f64),
+        };
+        Self::new(frames, sorted, jitter, mean, hitches as u64)
+    }
+
+    /// Combines the statistics of concurrent `parts`, given with their
+    /// mean frametimes. Medians are averaged weighted by frames and the
+    /// largest 99th percentile is kept, as exact percentiles cannot be
+    /// recovered from the parts.
+    #[cfg_attr(not(feature = "sinks"), allow(dead_code))]
+    pub(crate) fn merge(parts: &[(FrameTimeStats, Duration)]) -> Self {
+        let frames: u64 = parts.iter().map(|(stats, _)| stats.frames).sum();
+        if frames == 0 {
+            return Self::default();
+        }
+        let weighted = |value: fn(&FrameTimeStats, Duration) -> Duration| {
+            let sum: f64 = parts
+                .iter()
+                .map(|(stats, mean)| value(stats, *mean).as_secs_f64() * stats.frames as f64)
+                .sum();
+            Duration::from_secs_f64(sum / frames as f64)
+        };
+        let parts_with_frames = || parts.iter().filter(|(stats, _)| stats.frames > 0);
+        let sorted = Sorted {
+            min: parts_with_frames()
+                .map(|(stats, _)| stats.min)
+                .min()
+                .unwrap_or_default(),
+            max: parts_with_frames()
+                .map(|(stats, _)| stats.max)
+                .max()
+                .unwrap_or_default(),
+            median: weighted(|stats, _| stats.median),
+            p99: parts_with_frames()
+                .map(|(stats, _)| stats.p99)
+                .max()
+                .unwrap_or_default(),
+        };
+        let jitter = weighted(|stats, _| stats.jitter);
+        let mean = weighted(|_, mean| mean);
+        let hitches = parts.iter().map(|(stats, _)| stats.hitches).sum();
+        Self::new(frames, sorted, jitter, mean, hitches)
+    }
+
+    /// number of frames
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// shortest frametime
+    pub fn min(&self) -> Duration {
+        self.min
+    }
+
+    /// longest frametime
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// median frametime
+    pub fn median(&self) -> Duration {
+        self.median
+    }
+
+    /// 99th percentile frametime
+    pub fn p99(&self) -> Duration {
+        self.p99
+    }
+
+    /// mean absolute difference between consecutive frametimes
+    pub fn jitter(&self) -> Duration {
+        self.jitter
+    }
+
+    /// number of hitches, i.e. frames taking more than
+    /// twice as long as expected
+    pub fn hitches(&self) -> u64 {
+        self.hitches
+    }
+
+    /// Single number summarizing frame pacing consistency,
+    /// ranging from `100` (perfectly even frametimes) towards `0`.
+    ///
+    /// The score is computed as
+    /// `100 / (1 + 5 * jitter / mean + 2 * (p99 - median) / median + 20 * hitches / frames)`.
+    pub fn stability_score(&self) -> f64 {
+        self.stability
+    }
+}
+
+/// stability score from relative jitter, percentile spread and hitch ratio
+fn stability_score(jitter: f64, spread: f64, hitch_ratio: f64) -> f64 {
+    100. / (1. + 5. * jitter + 2. * spread + 20. * hitch_ratio)
+}
+
+/// order statistics of a set of frametimes
+pub(crate) struct Sorted {
+    pub(crate) min: Duration,
+    pub(crate) max: Duration,
+    pub(crate) median: Duration,
+    pub(crate) p99: Duration,
+}
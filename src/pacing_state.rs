@@ -0,0 +1,157 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    seqlock::{nanos, nanos_since, SeqLock},
+    Timer,
+};
+
+/// number of published words: frames completed, frametime of the most
+/// recent frame, and its end and the upcoming target in nanoseconds
+/// since the base instant
+const WORDS: usize = 4;
+
+/// pacing state published by a [`Timer`] at the end of every frame
+#[derive(Debug)]
+pub(crate) struct Shared {
+    /// reference instant of the stored instants
+    base: Instant,
+    /// the published state
+    state: SeqLock<WORDS>,
+}
+
+/// A consistent sample of the pacing state of a [`Timer`],
+/// see [`PacingState::read`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PacingSample {
+    /// frames completed
+    frames: u64,
+    /// frametime of the most recent frame
+    last_delta: Duration,
+    /// end of the most recent frame
+    last_frame_end: Instant,
+    /// target of the upcoming frame
+    next_deadline: Instant,
+}
+
+impl PacingSample {
+    /// frames completed
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// frametime of the most recent frame
+    pub fn last_delta(&self) -> Duration {
+        self.last_delta
+    }
+
+    /// end of the most recent frame
+    pub fn last_frame_end(&self) -> Instant {
+        self.last_frame_end
+    }
+
+    /// target of the upcoming frame, equal to [`Self::last_frame_end`]
+    /// for uncapped timers
+    pub fn next_deadline(&self) -> Instant {
+        self.next_deadline
+    }
+}
+
+/// Read-only handle to a copy of the frame count, last frametime and
+/// deadline of a [`Timer`], as returned by [`Timer::pacing_state`].
+///
+/// The statistics of the timer (e.g. [`crate::Log`]) are unchanged and
+/// are not safe to read from a signal handler. Creating this handle
+/// opts into a second copy of these three values, which the timer
+/// publishes in atomics at the end of every frame. Reading that copy
+/// never blocks and is async-signal-safe on platforms with lock-free
+/// 64-bit atomics, so crash reporters, signal handlers and external
+/// watchdogs can inspect the pacing state even when the frame loop is
+/// wedged. Timers without a handle do not pay for the copy.
+/// The handle is `Clone + Send + Sync` and can be stored in a static.
+///
+/// # Example
+/// ```rust
+/// use std::sync::OnceLock;
+/// use fps_timer::{PacingState, Timer};
+///
+/// static PACING: OnceLock<PacingState> = OnceLock::new();
+///
+/// /// e.g. called from a crash handler
+/// fn report() -> Option<u64> {
+///     Some(PACING.get()?.read()?.frames())
+/// }
+///
+/// let mut timer = Timer::default().fps(500.);
+/// PACING.set(timer.pacing_state()).unwrap();
+/// for _ in 0..3 {
+///     timer.frame();
+/// }
+/// assert_eq!(report(), Some(3));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PacingState {
+    shared: Arc<Shared>,
+}
+
+impl PacingState {
+    /// Frames completed, readable at any time.
+    pub fn frames(&self) -> u64 {
+        self.shared.state.word(0)
+    }
+
+    /// Reads all values from the same frame.
+    ///
+    /// Retries a bounded number of times while the timer is updating
+    /// the state, returning [`None`] if it did not finish, e.g. when
+    /// called from a signal handler interrupting the update on the
+    /// same thread.
+    pub fn read(&self) -> Option<PacingSample> {
+        let shared = &*self.shared;
+        let [frames, last_delta, last_frame_end, next_deadline] = shared.state.read()?;
+        Some(PacingSample {
+            frames,
+            last_delta: Duration::from_nanos(last_delta),
+            last_frame_end: shared.base + Duration::from_nanos(last_frame_end),
+            next_deadline: shared.base + Duration::from_nanos(next_deadline),
+        })
+    }
+}
+
+impl Timer {
+    /// Returns a handle to a copy of the frame count, last frametime
+    /// and next deadline of this timer, which is published lock-free
+    /// at the end of every frame from now on, see [`PacingState`].
+    pub fn pacing_state(&mut self) -> PacingState {
+        let (previous, fresh) = (self.previous, self.pacing_state.is_none());
+        let shared = self.pacing_state.get_or_insert_with(|| {
+            Arc::new(Shared {
+                base: previous,
+                state: SeqLock::new(),
+            })
+        });
+        let state = PacingState {
+            shared: shared.clone(),
+        };
+        if fresh {
+            self.publish_pacing_state(Duration::ZERO);
+        }
+        state
+    }
+
+    /// publishes the pacing state at the end of a frame
+    pub(crate) fn publish_pacing_state(&self, frame_time: Duration) {
+        let Some(shared) = &self.pacing_state else {
+            return;
+        };
+        let deadline = self.target.max(self.previous);
+        shared.state.write([
+            self.framecount,
+            nanos(frame_time),
+            nanos_since(shared.base, self.previous),
+            nanos_since(shared.base, deadline),
+        ]);
+    }
+}
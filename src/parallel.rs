@@ -0,0 +1,194 @@
+use std::{
+    thread::{self, Scope},
+    time::{Duration, Instant},
+};
+
+use crate::Timer;
+
+/// Deadlines of a parallel section, shared with its jobs.
+///
+/// The handle is `Copy + Send + Sync`, so it can be moved into
+/// any number of parallel jobs (e.g. spawned on a rayon scope).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameDeadline {
+    /// target of the current frame
+    frame: Instant,
+    /// end of the share of the frame budget granted to the section
+    section: Instant,
+}
+
+impl FrameDeadline {
+    /// time left until the frame target
+    pub fn remaining(&self) -> Duration {
+        self.frame.saturating_duration_since(Instant::now())
+    }
+
+    /// time left of the share of the frame budget granted to the section
+    pub fn section_remaining(&self) -> Duration {
+        self.section.saturating_duration_since(Instant::now())
+    }
+
+    /// `true` once the section has used up its share of the frame budget,
+    /// e.g. to skip optional work
+    pub fn is_over_budget(&self) -> bool {
+        Instant::now() >= self.section
+    }
+}
+
+/// Timing of a parallel section,
+/// as returned by [`Timer::parallel_section`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParallelSection {
+    /// time the section took
+    elapsed: Duration,
+    /// share of the frame budget granted to the section
+    budget: Duration,
+}
+
+impl ParallelSection {
+    /// time the section took
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// share of the frame budget granted to the section
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// `true` if the section took longer than its budget
+    pub fn overran(&self) -> bool {
+        self.elapsed > self.budget
+    }
+}
+
+impl Timer {
+    /// Runs the data-parallel section `f` as the named scope `name`
+    /// (see [`Self::scope`]), granting it `share` of the frame budget.
+    ///
+    /// `f` receives a [`FrameDeadline`] that jobs spawned inside the section
+    /// can query for the remaining time. Any executor can be used inside
+    /// `f`, see [`Self::parallel_scope`] and `Self::rayon_scope` for
+    /// ready-made variants based on [`std::thread::scope`] and
+    /// `rayon::scope`. Overruns are counted in
+    /// [`crate::ScopeStats::overruns`].
+    ///
+    /// # Arguments
+    /// * `name` - name of the scope
+    /// * `share` - share of the frametime granted to the section in `[0, 1]`
+    /// * `f` - the parallel section
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(60.);
+    /// timer.frame();
+    /// let (sum, section) = timer.parallel_section("simulate", 0.5, |deadline| {
+    ///     // e.g. rayon::scope(|s| ...) with jobs checking the deadline
+    ///     assert!(!deadline.is_over_budget());
+    ///     (0..1000u64).sum::<u64>()
+    /// });
+    /// assert_eq!(sum, 499_500);
+    /// assert!(!section.overran());
+    /// ```
+    pub fn parallel_section<R>(
+        &mut self,
+        name: &'static str,
+        share: f64,
+        f: impl FnOnce(FrameDeadline) -> R,
+    ) -> (R, ParallelSection) {
+        let start = Instant::now();
+        let budget = self.delta_time.mul_f64(share.clamp(0., 1.));
+        let deadline = FrameDeadline {
+            frame: self.target,
+            section: start + budget,
+        };
+        let result = f(deadline);
+        let section = ParallelSection {
+            elapsed: start.elapsed(),
+            budget,
+        };
+        #[cfg(feature = "scopes")]
+        self.scopes
+            .record_section(name, section.elapsed, section.overran());
+        #[cfg(not(feature = "scopes"))]
+        let _ = name;
+        (result, section)
+    }
+
+    /// Same as [`Self::parallel_section`], running `f` inside a
+    /// [`std::thread::scope`] to spawn the parallel jobs on.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(60.);
+    /// let chunks = [[1, 2], [3, 4]];
+    /// let (sums, _) = timer.parallel_scope("sum", 0.25, |scope, deadline| {
+    ///     let jobs: Vec<_> = chunks
+    ///         .iter()
+    ///         .map(|chunk| scope.spawn(move || {
+    ///             let _ = deadline.remaining();
+    ///             chunk.iter().sum::<i32>()
+    ///         }))
+    ///         .collect();
+    ///     jobs.into_iter().map(|job| job.join().unwrap()).collect::<Vec<_>>()
+    /// });
+    /// assert_eq!(sums, [3, 7]);
+    /// ```
+    pub fn parallel_scope<'env, R>(
+        &mut self,
+        name: &'static str,
+        share: f64,
+        f: impl for<'scope> FnOnce(&'scope Scope<'scope, 'env>, FrameDeadline) -> R,
+    ) -> (R, ParallelSection) {
+        self.parallel_section(name, share, |deadline| {
+            thread::scope(|scope| f(scope, deadline))
+        })
+    }
+
+    /// Same as [`Self::parallel_section`], running `f` inside a
+    /// [`rayon::scope`] to spawn the parallel jobs on the rayon
+    /// thread pool.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Arguments
+    /// * `name` - name of the scope
+    /// * `share` - share of the frametime granted to the section in `[0, 1]`
+    /// * `f` - the parallel section
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(60.);
+    /// let sum = AtomicU64::new(0);
+    /// let (_, section) = timer.rayon_scope("simulate", 0.5, |scope, deadline| {
+    ///     for chunk in [1..=50u64, 51..=100] {
+    ///         let sum = &sum;
+    ///         scope.spawn(move |_| {
+    ///             if !deadline.is_over_budget() {
+    ///                 sum.fetch_add(chunk.sum(), Ordering::Relaxed);
+    ///             }
+    ///         });
+    ///     }
+    /// });
+    /// assert_eq!(sum.into_inner(), 5050);
+    /// assert!(!section.overran());
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn rayon_scope<'scope, R: Send>(
+        &mut self,
+        name: &'static str,
+        share: f64,
+        f: impl FnOnce(&rayon::Scope<'scope>, FrameDeadline) -> R + Send,
+    ) -> (R, ParallelSection) {
+        self.parallel_section(name, share, |deadline| {
+            rayon::scope(|scope| f(scope, deadline))
+        })
+    }
+}
@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// How a [`Timer`] waits for a single frame, see
+/// [`Timer::next_frame_precision`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Precision {
+    /// sleep only, cheapest but least accurate
+    Sleep,
+    /// sleep, then busy wait for the spin margin,
+    /// see [`Timer::high_precision`]
+    Hybrid,
+    /// busy wait for the whole frame, most accurate
+    /// but occupying a core
+    Spin,
+}
+
+impl Precision {
+    /// maximum busy wait of this precision with the spin margin `margin`
+    pub(crate) fn spin_margin(self, margin: Duration) -> Option<Duration> {
+        match self {
+            Precision::Sleep => None,
+            Precision::Hybrid => Some(margin),
+            Precision::Spin => Some(Duration::MAX),
+        }
+    }
+}
+
+impl Timer {
+    /// Overrides how the timer waits for the upcoming frame only,
+    /// e.g. spinning right before a latency-critical capture or only
+    /// sleeping while a loading screen shows. The override takes
+    /// precedence over [`Self::high_precision`] and any custom
+    /// [`Self::wait_strategy`] and reverts after the next frame.
+    ///
+    /// # Arguments
+    /// * `precision` - how to wait for the next frame
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{Precision, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(200.).high_precision(false);
+    /// timer.frame();
+    /// timer.next_frame_precision(Precision::Spin);
+    /// timer.frame();
+    /// // back to sleeping
+    /// timer.frame();
+    /// ```
+    pub fn next_frame_precision(&mut self, precision: Precision) {
+        self.next_precision = Some(precision);
+    }
+}
@@ -0,0 +1,104 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// Bundled settings for common scenarios, see [`Timer::preset`].
+///
+/// Every preset is a starting point: all settings can be adjusted
+/// with the builder methods of [`Timer`] afterwards.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Preset {
+    /// Lowest latency and tightest pacing at 240fps.
+    ///
+    /// * high precision waits with an OS timer slack of 1µs, at the
+    ///   cost of a busy-waiting core
+    /// * frames more than one frametime late re-base the schedule
+    ///   instead of catching up with a burst of short frames, trading
+    ///   a consistent frame count for consistent input latency
+    /// * responsive delta smoothing (`0.5`) and a one second
+    ///   logging interval
+    CompetitiveShooter,
+    /// Even cadence for video and animation playback at 60fps.
+    ///
+    /// * high precision waits
+    /// * late frames re-base the schedule after one frametime, so a
+    ///   hitch is never followed by a visible burst of frames
+    /// * late frames are compensated over the following frames
+    ///   (see [`Timer::late_compensation`]) and deltas are smoothed
+    ///   heavily (`0.05`), keeping motion smooth at the cost of
+    ///   briefly lagging behind the wall clock
+    /// * a five second logging interval
+    MediaPlayback,
+    /// Low power consumption at 30fps.
+    ///
+    /// * plain sleeps without busy waiting, so frames may end up to a
+    ///   scheduler tick late
+    /// * the default catch-up slack of two frames and default smoothing
+    /// * a ten second logging interval
+    BatterySaver,
+    /// Fixed tick rate for simulation servers at 30 ticks per second.
+    ///
+    /// * plain sleeps without busy waiting, leaving the cores to the
+    ///   simulation
+    /// * up to ten late ticks are caught up back to back, so the
+    ///   simulation keeps pace with the clients through short load
+    ///   spikes, at the cost of bursts
+    /// * a one minute logging interval
+    Server,
+}
+
+impl Timer {
+    /// Creates a timer with the settings of `preset`.
+    ///
+    /// # Arguments
+    /// * `preset` - scenario to configure the timer for
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{Preset, Timer};
+    ///
+    /// let mut timer = Timer::preset(Preset::CompetitiveShooter);
+    /// timer.frame();
+    /// // adjust to the display
+    /// let mut timer = Timer::preset(Preset::MediaPlayback).fps(24.);
+    /// timer.frame();
+    /// ```
+    pub fn preset(preset: Preset) -> Self {
+        let (mut timer, max_delay_frames) = match preset {
+            Preset::CompetitiveShooter => (
+                Timer::default()
+                    .fps(240.)
+                    .high_precision(true)
+                    .timer_slack(Duration::from_micros(1))
+                    .delta_smoothing(0.5)
+                    .log_interval(Duration::from_secs(1)),
+                1,
+            ),
+            Preset::MediaPlayback => (
+                Timer::default()
+                    .fps(60.)
+                    .high_precision(true)
+                    .late_compensation(0.25)
+                    .delta_smoothing(0.05)
+                    .log_interval(Duration::from_secs(5)),
+                1,
+            ),
+            Preset::BatterySaver => (
+                Timer::default()
+                    .fps(30.)
+                    .high_precision(false)
+                    .log_interval(Duration::from_secs(10)),
+                2,
+            ),
+            Preset::Server => (
+                Timer::default()
+                    .fps(30.)
+                    .high_precision(false)
+                    .log_interval(Duration::from_secs(60)),
+                10,
+            ),
+        };
+        timer.max_delay_frames = max_delay_frames;
+        timer
+    }
+}
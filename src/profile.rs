@@ -0,0 +1,156 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::{pacing_core::Schedule, FrameTime, Rate, Timer};
+
+/// Pacing settings that can be switched as a unit,
+/// see [`Timer::profile`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Profile {
+    /// schedule with the exact target frametime
+    schedule: Schedule,
+    /// improved accuracy
+    high_precision: bool,
+}
+
+impl Profile {
+    /// Creates a profile targeting the given framerate
+    /// (`0` meaning uncapped) with high precision enabled.
+    pub fn fps(fps: impl Into<Rate>) -> Self {
+        Self {
+            schedule: Schedule::from_fps(fps.into().as_hz()),
+            high_precision: true,
+        }
+    }
+
+    /// Creates a profile targeting the given frametime
+    /// with high precision enabled.
+    pub fn frame_time(delta: impl Into<FrameTime>) -> Self {
+        Self {
+            schedule: Schedule::new(delta.into().as_duration()),
+            high_precision: true,
+        }
+    }
+
+    /// Creates a profile without a framerate cap.
+    pub fn uncapped() -> Self {
+        Self::frame_time(Duration::ZERO)
+    }
+
+    /// Enable or disable improved accuracy for this profile,
+    /// see [`Timer::high_precision`].
+    pub fn high_precision(mut self, enabled: bool) -> Self {
+        self.high_precision = enabled;
+        self
+    }
+}
+
+/// Error returned by [`Timer::set_profile`] if no
+/// profile with the requested name was defined
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnknownProfile {
+    /// requested profile name
+    name: String,
+}
+
+impl Display for UnknownProfile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown profile \"{}\"", self.name)
+    }
+}
+
+impl Error for UnknownProfile {}
+
+impl Timer {
+    /// Defines a named profile that can be activated with [`Self::set_profile`].
+    ///
+    /// Defining a profile with an existing name replaces it.
+    ///
+    /// # Arguments
+    /// * `name` - name of the profile
+    /// * `profile` - pacing settings of the profile
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{Profile, Timer};
+    /// let mut timer = Timer::default()
+    ///     .profile("menu", Profile::fps(30.).high_precision(false))
+    ///     .profile("gameplay", Profile::fps(144.))
+    ///     .profile("loading", Profile::uncapped());
+    ///
+    /// timer.set_profile("menu").unwrap();
+    /// assert_eq!(timer.active_profile(), Some("menu"));
+    /// assert!(timer.set_profile("credits").is_err());
+    /// ```
+    pub fn profile(mut self, name: impl Into<String>, profile: Profile) -> Self {
+        let name = name.into();
+        self.profiles.retain(|(existing, _)| *existing != name);
+        self.profiles.push((name, profile));
+        self
+    }
+
+    /// Activates the profile named `name`, applying all of its settings at once.
+    ///
+    /// The frame schedule is re-based on the previous frame, so the next
+    /// frame is paced with the new settings right away. The current
+    /// logging interval ends with the switch, so every [`crate::Log`]
+    /// covers a single profile (see [`crate::Log::profile`]). Logs of
+    /// intervals ended by switches are queued until they are returned
+    /// by [`Self::log`], keeping the 16 most recent ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{Profile, Timer};
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(0.)
+    ///     .profile("menu", Profile::uncapped())
+    ///     .profile("gameplay", Profile::uncapped());
+    /// let start = timer.timebase_epoch();
+    /// timer.set_profile("menu").unwrap();
+    /// timer.frame_at(start + Duration::from_millis(10));
+    /// timer.set_profile("gameplay").unwrap();
+    /// timer.frame_at(start + Duration::from_millis(20));
+    /// timer.set_profile("menu").unwrap();
+    ///
+    /// // both segments are kept
+    /// assert_eq!(timer.log().unwrap().profile(), Some("menu"));
+    /// assert_eq!(timer.log().unwrap().profile(), Some("gameplay"));
+    /// assert!(timer.log().is_none());
+    /// ```
+    ///
+    /// # Arguments
+    /// * `name` - name of a profile defined with [`Self::profile`]
+    pub fn set_profile(&mut self, name: &str) -> Result<(), UnknownProfile> {
+        let Some(&(_, profile)) = self.profiles.iter().find(|(n, _)| n == name) else {
+            return Err(UnknownProfile {
+                name: name.to_owned(),
+            });
+        };
+
+        // segment the logs by profile
+        let now = self.previous;
+        if let Some(log) = self.close_log_interval(now) {
+            self.emit_log(log);
+        }
+        self.previous_log = now;
+        self.log_target = self.next_log_target(now);
+
+        self.active_profile = Some(name.to_owned());
+        self.high_precision = profile.high_precision;
+        self.set_schedule(profile.schedule);
+        Ok(())
+    }
+
+    /// name of the active profile, if any
+    pub fn active_profile(&self) -> Option<&str> {
+        self.active_profile.as_deref()
+    }
+}
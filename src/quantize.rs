@@ -0,0 +1,55 @@
+use std::time::{Duration, Instant};
+
+use crate::{duration_from_nanos, Timer};
+
+impl Timer {
+    /// Quantizes frametimes to integer multiples of `refresh_period`.
+    ///
+    /// A frame that misses its target normally ends as soon as it is
+    /// done, so late frames take arbitrary times, e.g. 19ms between
+    /// frames of 16.7ms, which a fixed refresh display presents with
+    /// uneven judder. With quantization, a late frame instead waits
+    /// for the next refresh on the grid of its targets, so frametimes
+    /// are multiples of the refresh period (33.3ms, 50ms, ... at 60Hz),
+    /// and the schedule continues from there, trading average rate for
+    /// even presentation, like the frame pacers of consoles.
+    ///
+    /// The target frametime should be a multiple of the refresh period.
+    /// Has no effect on uncapped timers.
+    ///
+    /// # Arguments
+    /// * `refresh_period` - refresh period of the display,
+    ///   zero disables quantization
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// let refresh = Duration::from_millis(5);
+    /// let mut timer = Timer::default().frame_time(refresh).quantize(refresh);
+    /// timer.frame();
+    /// // a frame taking 7ms ends after two refresh periods
+    /// thread::sleep(Duration::from_millis(7));
+    /// let frame_time = timer.frame();
+    /// assert!(frame_time > Duration::from_millis(9));
+    /// ```
+    pub fn quantize(mut self, refresh_period: Duration) -> Self {
+        self.quantum = refresh_period;
+        self
+    }
+
+    /// `target` delayed to the next refresh after `current` if the
+    /// frame missed it
+    pub(crate) fn quantized(&self, current: Instant, target: Instant) -> Instant {
+        if self.quantum.is_zero() || current <= target {
+            return target;
+        }
+        let quantum = self.quantum.as_nanos();
+        let periods = (current - target).as_nanos().div_ceil(quantum);
+        target + duration_from_nanos(periods * quantum)
+    }
+}
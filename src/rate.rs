@@ -0,0 +1,323 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{frame_time_from_fps, MAX_INTERVAL};
+
+/// A framerate in Hz, as accepted by [`crate::Timer::fps`].
+///
+/// Non-positive and NaN rates mean uncapped. Rates can be parsed from
+/// configuration values like `"59.94"`, `"144hz"`, `"60000/1001"` or
+/// frametimes like `"16.7ms"`.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::{FrameTime, Rate, Timer};
+///
+/// let rate: Rate = "60000/1001".parse().unwrap();
+/// assert_eq!(format!("{rate:.2}"), "59.94hz");
+/// assert_eq!(rate.frame_time().as_duration().as_nanos(), 16_683_333);
+///
+/// let rate: Rate = "16.7ms".parse().unwrap();
+/// assert!((rate.as_hz() - 59.88).abs() < 0.01);
+///
+/// let mut timer = Timer::default().fps(rate * 2.);
+/// timer.frame();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Rate(f64);
+
+impl Rate {
+    /// uncapped framerate
+    pub const UNCAPPED: Self = Self(0.);
+
+    /// Creates a rate of `hz` frames per second.
+    pub const fn hz(hz: f64) -> Self {
+        Self(hz)
+    }
+
+    /// Creates the exact rate `numerator / denominator`,
+    /// e.g. `60_000 / 1001` for NTSC video. A zero denominator
+    /// means uncapped.
+    pub fn ratio(numerator: u64, denominator: u64) -> Self {
+        match denominator {
+            0 => Self::UNCAPPED,
+            denominator => Self(numerator as f64 / denominator as f64),
+        }
+    }
+
+    /// frames per second
+    pub fn as_hz(&self) -> f64 {
+        self.0
+    }
+
+    /// `true` if the rate does not cap the framerate
+    pub fn is_uncapped(&self) -> bool {
+        self.0.is_nan() || self.0 <= 0.
+    }
+
+    /// frametime of the rate, zero if uncapped
+    pub fn frame_time(&self) -> FrameTime {
+        FrameTime(frame_time_from_fps(self.0))
+    }
+}
+
+impl From<f64> for Rate {
+    fn from(hz: f64) -> Self {
+        Self(hz)
+    }
+}
+
+impl From<Rate> for f64 {
+    fn from(rate: Rate) -> Self {
+        rate.0
+    }
+}
+
+impl From<FrameTime> for Rate {
+    fn from(frame_time: FrameTime) -> Self {
+        frame_time.rate()
+    }
+}
+
+impl Mul<f64> for Rate {
+    type Output = Self;
+
+    fn mul(self, factor: f64) -> Self {
+        Self(self.0 * factor)
+    }
+}
+
+impl Div<f64> for Rate {
+    type Output = Self;
+
+    fn div(self, divisor: f64) -> Self {
+        Self(self.0 / divisor)
+    }
+}
+
+impl Display for Rate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}hz", precision, self.0),
+            None => write!(f, "{}hz", self.0),
+        }
+    }
+}
+
+/// Parses a rate from a number of Hz with an optional `hz` or `fps`
+/// suffix (`"59.94"`, `"144hz"`), a ratio (`"60000/1001"`) or a
+/// frametime (`"16.7ms"`, see [`FrameTime`]).
+impl FromStr for Rate {
+    type Err = ParseRateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse(s) {
+            Some(Parsed::Rate(rate)) => Ok(rate),
+            Some(Parsed::FrameTime(frame_time)) => Ok(frame_time.rate()),
+            None => Err(ParseRateError::new(s)),
+        }
+    }
+}
+
+/// A target frametime, as accepted by [`crate::Timer::frame_time`].
+///
+/// Zero means uncapped. Frametimes can be parsed from configuration
+/// values like `"16.7ms"`, `"500us"` or `"1s"` as well as from
+/// rates like `"60"` or `"60000/1001"`, see [`Rate`].
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{FrameTime, Rate, Timer};
+///
+/// let frame_time: FrameTime = "16.7ms".parse().unwrap();
+/// assert_eq!(frame_time.as_duration(), Duration::from_micros(16_700));
+///
+/// let frame_time: FrameTime = "50hz".parse().unwrap();
+/// assert_eq!(frame_time, FrameTime::from(Duration::from_millis(20)));
+/// assert_eq!(frame_time.rate(), Rate::hz(50.));
+///
+/// let mut timer = Timer::default().frame_time(frame_time / 2);
+/// timer.frame();
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FrameTime(Duration);
+
+impl FrameTime {
+    /// uncapped frametime
+    pub const UNCAPPED: Self = Self(Duration::ZERO);
+
+    /// the frametime as a [`Duration`]
+    pub const fn as_duration(&self) -> Duration {
+        self.0
+    }
+
+    /// `true` if the frametime does not cap the framerate
+    pub const fn is_uncapped(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// rate of the frametime, zero if uncapped
+    pub fn rate(&self) -> Rate {
+        match self.0 {
+            Duration::ZERO => Rate::UNCAPPED,
+            delta => Rate(1. / delta.as_secs_f64()),
+        }
+    }
+}
+
+impl From<Duration> for FrameTime {
+    fn from(delta: Duration) -> Self {
+        Self(delta)
+    }
+}
+
+impl From<FrameTime> for Duration {
+    fn from(frame_time: FrameTime) -> Self {
+        frame_time.0
+    }
+}
+
+impl From<Rate> for FrameTime {
+    fn from(rate: Rate) -> Self {
+        rate.frame_time()
+    }
+}
+
+impl Add for FrameTime {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0.saturating_add(other.0).min(MAX_INTERVAL))
+    }
+}
+
+impl Sub for FrameTime {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self(self.0.saturating_sub(other.0))
+    }
+}
+
+impl Mul<u32> for FrameTime {
+    type Output = Self;
+
+    fn mul(self, factor: u32) -> Self {
+        Self(self.0.saturating_mul(factor).min(MAX_INTERVAL))
+    }
+}
+
+impl Div<u32> for FrameTime {
+    type Output = Self;
+
+    fn div(self, divisor: u32) -> Self {
+        Self(self.0 / divisor)
+    }
+}
+
+impl Display for FrameTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let millis = self.0.as_secs_f64() * 1e3;
+        match f.precision() {
+            Some(precision) => write!(f, "{millis:.precision$}ms"),
+            None => write!(f, "{millis}ms"),
+        }
+    }
+}
+
+/// Parses a frametime from a number with a unit of `s`, `ms`, `us`
+/// (or `µs`) or `ns` (`"16.7ms"`), or from a rate without a unit or
+/// with a `hz` or `fps` suffix (`"60"`, `"60000/1001"`, see [`Rate`]).
+impl FromStr for FrameTime {
+    type Err = ParseRateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse(s) {
+            Some(Parsed::Rate(rate)) => Ok(rate.frame_time()),
+            Some(Parsed::FrameTime(frame_time)) => Ok(frame_time),
+            None => Err(ParseRateError::new(s)),
+        }
+    }
+}
+
+/// Error returned when parsing a [`Rate`] or a [`FrameTime`] fails
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseRateError {
+    /// input that failed to parse
+    input: String,
+}
+
+impl ParseRateError {
+    fn new(input: &str) -> Self {
+        Self {
+            input: input.to_owned(),
+        }
+    }
+}
+
+impl Display for ParseRateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid rate or frametime \"{}\"", self.input)
+    }
+}
+
+impl Error for ParseRateError {}
+
+/// a parsed rate or frametime
+enum Parsed {
+    Rate(Rate),
+    FrameTime(FrameTime),
+}
+
+/// units of frametimes in seconds, longest suffix first
+const UNITS: [(&str, f64); 5] = [
+    ("ms", 1e-3),
+    ("us", 1e-6),
+    ("µs", 1e-6),
+    ("ns", 1e-9),
+    ("s", 1.),
+];
+
+/// parses a rate or a frametime, see the [`FromStr`]
+/// implementations of [`Rate`] and [`FrameTime`]
+fn parse(s: &str) -> Option<Parsed> {
+    let lower = s.trim().to_ascii_lowercase();
+    let rate = ["hz", "fps"]
+        .into_iter()
+        .find_map(|suffix| lower.strip_suffix(suffix));
+    if rate.is_none() {
+        for (unit, seconds) in UNITS {
+            if let Some(value) = lower.strip_suffix(unit) {
+                let value = parse_number(value)? * seconds;
+                let delta = Duration::try_from_secs_f64(value).ok()?;
+                return Some(Parsed::FrameTime(FrameTime(delta.min(MAX_INTERVAL))));
+            }
+        }
+    }
+    let value = rate.unwrap_or(&lower);
+    let hz = match value.split_once('/') {
+        Some((numerator, denominator)) => {
+            let numerator = parse_number(numerator)?;
+            match parse_number(denominator)? {
+                0. => 0.,
+                denominator => numerator / denominator,
+            }
+        }
+        None => parse_number(value)?,
+    };
+    Some(Parsed::Rate(Rate(hz)))
+}
+
+/// parses a finite, non-negative number
+fn parse_number(s: &str) -> Option<f64> {
+    s.trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite() && *n >= 0.)
+}
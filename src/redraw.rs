@@ -0,0 +1,145 @@
+use std::time::{Duration, Instant};
+
+use crate::{duration_from_nanos, pacing_core::Schedule};
+
+/// Redraw scheduler for event-driven applications.
+///
+/// Event loops (winit, iced, druid, ...) can not block inside
+/// [`crate::Timer::frame`]. Instead they need to know whether a redraw
+/// is due and when to wake up next. [`RedrawScheduler`] answers both
+/// questions while applying the same target and slack logic as
+/// [`crate::Timer`], on the same [`Schedule`], so redraws do not drift
+/// even if the frametime is not a whole number of nanoseconds.
+///
+/// # Example
+/// ```rust
+/// use std::time::{Duration, Instant};
+/// use fps_timer::RedrawScheduler;
+///
+/// let mut scheduler = RedrawScheduler::default().fps(60.);
+/// // inside the event loop
+/// let now = Instant::now();
+/// if scheduler.should_redraw(now) {
+///     // draw the frame
+///     scheduler.redrawn(now);
+/// }
+/// // e.g. `ControlFlow::WaitUntil(wake)` in winit
+/// let wake = scheduler.next_redraw();
+/// assert!(wake > now);
+///
+/// // 3 fps, i.e. a frametime of 333333333.3ns, without accumulated rounding
+/// let mut scheduler = RedrawScheduler::default().fps(3.);
+/// let first = scheduler.next_redraw();
+/// for _ in 0..3 {
+///     scheduler.redrawn(scheduler.next_redraw());
+/// }
+/// assert_eq!(scheduler.next_redraw() - first, Duration::from_secs(1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct RedrawScheduler {
+    /// reference instant of the schedule
+    epoch: Instant,
+    /// schedule of redraws in nanoseconds since the epoch,
+    /// shared with [`crate::Timer`]
+    schedule: Schedule,
+    /// target time for the next redraw
+    target: Instant,
+    /// instant of the previous redraw
+    previous: Instant,
+    /// maximum amount of frames to lag behind
+    max_delay_frames: u32,
+}
+
+impl Default for RedrawScheduler {
+    fn default() -> Self {
+        let now = Instant::now();
+        let mut scheduler = Self {
+            epoch: now,
+            schedule: Schedule::from_fps(60.),
+            target: now,
+            previous: now,
+            max_delay_frames: 2,
+        };
+        scheduler.start(now);
+        scheduler
+    }
+}
+
+impl RedrawScheduler {
+    /// Sets the target frametime to the specified amount.
+    ///
+    /// # Arguments
+    /// * `delta` - target frametime
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) scheduler
+    pub fn frame_time(mut self, delta: Duration) -> Self {
+        self.schedule = Schedule::new(delta);
+        self.start(self.previous);
+        self
+    }
+
+    /// Sets the framerate target to the specified amount.
+    ///
+    /// # Arguments
+    /// * `fps` - target framerate
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) scheduler
+    pub fn fps(mut self, fps: f64) -> Self {
+        self.schedule = Schedule::from_fps(fps);
+        self.start(self.previous);
+        self
+    }
+
+    /// Returns `true` if a redraw is due at `now`.
+    pub fn should_redraw(&self, now: Instant) -> bool {
+        now >= self.target
+    }
+
+    /// The instant at which the next redraw is due,
+    /// i.e. when the event loop should wake up next.
+    pub fn next_redraw(&self) -> Instant {
+        self.target
+    }
+
+    /// Notifies the scheduler that a redraw happened at `now`
+    /// and schedules the next one.
+    ///
+    /// Returns the time since the previous redraw.
+    pub fn redrawn(&mut self, now: Instant) -> Duration {
+        // re-base the target if we lag behind by more than the slack
+        let behind = now.saturating_duration_since(self.target);
+        let slack = self.schedule.frame_time() * self.max_delay_frames;
+        if behind > slack {
+            self.schedule.restart(self.nanos_since_epoch(now));
+            self.target = now;
+        }
+
+        // redraws requested early (e.g. by a resize) don't advance the schedule
+        if now >= self.target {
+            let target = self.schedule.advance();
+            self.target = self.instant(target);
+        }
+
+        let frame_time = now.saturating_duration_since(self.previous);
+        self.previous = now;
+        frame_time
+    }
+
+    /// starts the schedule at `start`, targeting the first redraw
+    fn start(&mut self, start: Instant) {
+        let target = self.schedule.start(self.nanos_since_epoch(start));
+        self.target = self.instant(target);
+    }
+
+    /// nanoseconds from the epoch to `instant`
+    fn nanos_since_epoch(&self, instant: Instant) -> u128 {
+        instant.saturating_duration_since(self.epoch).as_nanos()
+    }
+
+    /// instant `nanos` nanoseconds after the epoch
+    fn instant(&self, nanos: u128) -> Instant {
+        self.epoch + duration_from_nanos(nanos)
+    }
+}
@@ -0,0 +1,102 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::Timer;
+
+/// number of frame intervals the estimate is based on
+const HISTORY: usize = 120;
+/// minimum number of intervals required for an estimate
+const MIN_SAMPLES: usize = 30;
+/// relative tolerance of an interval around a multiple of the period
+const TOLERANCE: f64 = 0.05;
+/// minimum share of intervals that have to fit the period
+const MIN_FIT: f64 = 0.75;
+/// range of plausible refresh periods (500Hz to 20Hz)
+const MIN_PERIOD: f64 = 0.002;
+const MAX_PERIOD: f64 = 0.05;
+
+/// infers the display refresh period from frame completion times
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RefreshEstimator {
+    /// most recent frame intervals in seconds
+    intervals: VecDeque<f64>,
+}
+
+impl RefreshEstimator {
+    pub(crate) fn record(&mut self, frame_time: Duration) {
+        if self.intervals.len() == HISTORY {
+            self.intervals.pop_front();
+        }
+        self.intervals.push_back(frame_time.as_secs_f64());
+    }
+
+    /// Frames presented with vsync complete at multiples of the refresh
+    /// period. The shortest common interval (10th percentile) is taken as
+    /// the candidate period, which is then refined by averaging all
+    /// intervals that lie close to a multiple of it, each divided by its
+    /// multiple.
+    pub(crate) fn estimate(&self) -> Option<Duration> {
+        if self.intervals.len() < MIN_SAMPLES {
+            return None;
+        }
+        let mut sorted: Vec<f64> = self.intervals.iter().copied().collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+        let candidate = sorted[sorted.len() / 10];
+        if !(MIN_PERIOD..=MAX_PERIOD).contains(&candidate) {
+            return None;
+        }
+
+        let (mut sum, mut fitting) = (0., 0);
+        for &interval in &self.intervals {
+            let multiple = (interval / candidate).round();
+            if multiple < 1. {
+                continue;
+            }
+            let period = interval / multiple;
+            if (period - candidate).abs() <= candidate * TOLERANCE {
+                sum += period;
+                fitting += 1;
+            }
+        }
+        if (fitting as f64) < self.intervals.len() as f64 * MIN_FIT {
+            return None;
+        }
+        Some(Duration::from_secs_f64(sum / fitting as f64))
+    }
+}
+
+impl Timer {
+    /// Estimates the refresh period of the display from the completion
+    /// times of recent frames.
+    ///
+    /// When frames are presented with vsync, their intervals cluster
+    /// around multiples of the refresh period. This is useful for
+    /// applications without access to platform refresh rate APIs, e.g.
+    /// to snap the framerate cap to the display. The estimate is only
+    /// meaningful while the presentation (not the cap of this timer)
+    /// limits the framerate, e.g. with an uncapped timer.
+    ///
+    /// Returns [`None`] until enough frames were observed or if the
+    /// frame intervals do not show a consistent period.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.);
+    /// let refresh = Duration::from_nanos(16_666_667);
+    /// let mut now = Instant::now();
+    /// for frame in 0..100 {
+    ///     // every 5th frame misses a vblank
+    ///     now += if frame % 5 == 0 { refresh * 2 } else { refresh };
+    ///     timer.frame_at(now);
+    /// }
+    /// let estimate = timer.estimated_refresh().unwrap();
+    /// assert!(estimate.abs_diff(refresh) < Duration::from_micros(10));
+    /// ```
+    pub fn estimated_refresh(&self) -> Option<Duration> {
+        self.refresh.estimate()
+    }
+}
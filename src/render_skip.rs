@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// render patterns `(rendered, frames)`, from rendering every frame
+/// to rendering every fourth frame
+const PATTERNS: [(u32, u32); 6] = [(1, 1), (3, 4), (2, 3), (1, 2), (1, 3), (1, 4)];
+
+/// render skipping state of a [`Timer`]
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct RenderSkip {
+    /// smoothed work of frames that were rendered
+    render_cost: Option<Duration>,
+    /// smoothed work of frames that were skipped
+    skip_cost: Option<Duration>,
+    /// frames rendered out of the frames of the pattern
+    pattern: (u32, u32),
+    /// position of the current frame in the pattern
+    phase: u32,
+    /// whether the current frame is rendered
+    render: bool,
+}
+
+impl RenderSkip {
+    fn new() -> Self {
+        Self {
+            pattern: (1, 1),
+            render: true,
+            ..Default::default()
+        }
+    }
+
+    /// accounts the work of the previous frame and decides whether
+    /// to render the current one at a frametime of `period`
+    fn update(&mut self, work: Duration, period: Duration) {
+        let cost = match self.render {
+            true => &mut self.render_cost,
+            false => &mut self.skip_cost,
+        };
+        *cost = Some(cost.map_or(work, |avg| avg * 7 / 8 + work / 8));
+
+        let pattern = self.fitting_pattern(period);
+        if pattern != self.pattern {
+            self.pattern = pattern;
+            self.phase = 0;
+        } else {
+            self.phase = (self.phase + 1) % pattern.1;
+        }
+        // spread the rendered frames evenly over the pattern
+        let (rendered, frames) = pattern;
+        self.render = (self.phase + 1) * rendered / frames > self.phase * rendered / frames;
+    }
+
+    /// densest pattern whose frames fit into their frametimes
+    fn fitting_pattern(&self, period: Duration) -> (u32, u32) {
+        let render = self.render_cost.unwrap_or_default();
+        let skip = self.skip_cost.unwrap_or_default();
+        if render <= period || render <= skip {
+            return PATTERNS[0];
+        }
+        // rendered * render + (frames - rendered) * skip <= frames * period
+        let max_ratio = period.saturating_sub(skip).as_secs_f64() / (render - skip).as_secs_f64();
+        PATTERNS
+            .into_iter()
+            .find(|&(rendered, frames)| rendered as f64 / frames as f64 <= max_ratio)
+            .unwrap_or(PATTERNS[PATTERNS.len() - 1])
+    }
+}
+
+impl Timer {
+    /// Enables or disables render skipping for overloaded render paths.
+    ///
+    /// When frames consistently take longer than the frametime, e.g.
+    /// because rendering is too expensive, irregular misses make
+    /// motion stutter. With render skipping, the timer measures the
+    /// time between rendered and skipped frames and picks an explicit
+    /// pattern, e.g. rendering 2 of every 3 frames, spread evenly,
+    /// while simulation keeps running every frame at the full rate.
+    /// [`Self::should_render`] tells whether to render the current
+    /// frame. Has no effect on uncapped timers.
+    ///
+    /// # Arguments
+    /// * `enabled` - whether to enable render skipping
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(500.).render_skipping(true);
+    /// let mut rendered = 0;
+    /// for _ in 0..60 {
+    ///     timer.frame();
+    ///     // simulation runs every frame
+    ///     if timer.should_render() {
+    ///         // rendering takes 3ms, more than the 2ms frametime
+    ///         thread::sleep(Duration::from_millis(3));
+    ///         rendered += 1;
+    ///     }
+    /// }
+    /// let (frames_rendered, frames) = timer.render_pattern();
+    /// assert!(frames_rendered < frames);
+    /// assert!(rendered < 60);
+    /// ```
+    pub fn render_skipping(mut self, enabled: bool) -> Self {
+        self.render_skip = enabled.then(RenderSkip::new);
+        self
+    }
+
+    /// Whether the application should render the current frame,
+    /// always `true` unless render skipping is enabled,
+    /// see [`Self::render_skipping`].
+    pub fn should_render(&self) -> bool {
+        self.render_skip.is_none_or(|skip| skip.render)
+    }
+
+    /// Current render pattern as `(rendered, frames)`, e.g. `(2, 3)`
+    /// when rendering 2 of every 3 frames, `(1, 1)` unless render
+    /// skipping is enabled.
+    pub fn render_pattern(&self) -> (u32, u32) {
+        self.render_skip.map_or((1, 1), |skip| skip.pattern)
+    }
+
+    /// accounts the `work` of the previous frame for render skipping
+    pub(crate) fn update_render_skip(&mut self, work: Duration) {
+        let period = self.delta_time;
+        if let Some(skip) = &mut self.render_skip {
+            if !period.is_zero() {
+                skip.update(work, period);
+            }
+        }
+    }
+}
@@ -0,0 +1,449 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{pacing_core::Schedule, Timer};
+
+/// header line of the textual recording format
+const HEADER: &str = "fps-timer-recording 2";
+
+/// panic message if a builder method of the recorded timer panicked
+const TIMER_TAKEN: &str = "recorded timer lost by a panicking builder";
+
+/// A single input to a [`Timer`] captured by a [`Recorder`].
+///
+/// All timestamps are relative to the start of the recording.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecordedEvent {
+    /// a call to [`Timer::frame`], holding the clock sample taken at the
+    /// start of the frame and, if the timer waited, the instant the wait
+    /// finished
+    Frame {
+        start: Duration,
+        end: Option<Duration>,
+    },
+    /// a call to [`Timer::log`]
+    Log,
+    /// the target frametime was changed
+    FrameTime(Duration),
+    /// the framerate target was changed, holding the schedule with its
+    /// exact frametime, see [`Schedule::from_fps`]
+    Schedule(Schedule),
+    /// the logging interval was changed
+    LogInterval(Duration),
+    /// high precision was enabled or disabled
+    HighPrecision(bool),
+    /// an external mark placed by the application (must not contain newlines)
+    Mark(String),
+    /// the session seed was set, see [`Timer::session_seed`]
+    Seed(u64),
+}
+
+/// Output of a [`Timer`] while replaying a [`Recording`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReplayOutput {
+    /// frametime returned by [`Timer::frame`]
+    Frame(Duration),
+    /// average frametime of the [`crate::Log`] returned by [`Timer::log`], if any
+    Log(Option<Duration>),
+    /// an external mark, passed through unchanged
+    Mark(String),
+}
+
+/// Recorded inputs of a [`Timer`] that can be replayed bit-exactly.
+///
+/// A recording holds the clock samples of all frames and the settings
+/// changed through the [`Recorder`]: the framerate target, logging
+/// interval, high precision and session seed. All other settings of
+/// the recorded timer (e.g. [`Timer::phase`], [`Timer::clock`],
+/// [`Timer::pacing`] or [`Timer::hard_cap`]) are not recorded, so
+/// recordings of timers using them only replay bit-exactly against a
+/// timer configured the same way, see [`Self::replay_into`].
+///
+/// Recordings can be stored and shared in a simple line based
+/// text format through their [`Display`] and [`FromStr`] implementations.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recording {
+    /// schedule with the exact frametime at the start of the recording
+    schedule: Schedule,
+    /// logging interval at the start of the recording
+    log_interval: Duration,
+    /// high precision setting at the start of the recording
+    high_precision: bool,
+    /// recorded events in chronological order
+    events: Vec<RecordedEvent>,
+}
+
+/// Records all inputs of a [`Timer`] into a [`Recording`].
+///
+/// Frames are recorded through the same path as [`Timer::frame`],
+/// so sinks, dumps and tags of the recorded timer see them as usual.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::{Recorder, Recording, Timer};
+///
+/// let mut recorder = Recorder::new(Timer::default().fps(1000.));
+/// for _ in 0..10 {
+///     recorder.frame();
+///     recorder.log();
+/// }
+/// recorder.mark("loading done");
+/// let recording = recorder.finish();
+///
+/// // store and load the recording
+/// let text = recording.to_string();
+/// let loaded: Recording = text.parse().unwrap();
+/// assert_eq!(loaded, recording);
+///
+/// // replaying is deterministic
+/// assert_eq!(loaded.replay(), recording.replay());
+/// ```
+pub struct Recorder {
+    /// the recorded timer, only taken while it is configured
+    timer: Option<Timer>,
+    /// start of the recording
+    epoch: Instant,
+    /// recording in progress
+    recording: Recording,
+}
+
+impl Recorder {
+    /// Starts recording the inputs of `timer`.
+    ///
+    /// The frame schedule of the timer is restarted, so the
+    /// recording covers its complete state.
+    pub fn new(mut timer: Timer) -> Self {
+        let epoch = Instant::now();
+        timer.reset_at(epoch);
+        let recording = Recording {
+            schedule: Schedule::from_fixed_period(timer.schedule.fixed_period()),
+            log_interval: timer.log_interval,
+            high_precision: timer.high_precision,
+            events: vec![RecordedEvent::Seed(timer.session_seed)],
+        };
+        Self {
+            timer: Some(timer),
+            epoch,
+            recording,
+        }
+    }
+
+    /// Recorded version of [`Timer::frame`]
+    pub fn frame(&mut self) -> Duration {
+        let timer = self.timer_mut();
+        let start = timer.clock_now();
+        let frame_time = timer.frame_at(start);
+        let end = timer.last_frame.timestamp;
+        self.recording.events.push(RecordedEvent::Frame {
+            start: start - self.epoch,
+            end: (end != start).then(|| end - self.epoch),
+        });
+        frame_time
+    }
+
+    /// Recorded version of [`Timer::log`]
+    pub fn log(&mut self) -> Option<crate::Log> {
+        self.recording.events.push(RecordedEvent::Log);
+        self.timer_mut().log()
+    }
+
+    /// Recorded version of [`Timer::frame_time`]
+    pub fn set_frame_time(&mut self, delta: Duration) {
+        self.recording.events.push(RecordedEvent::FrameTime(delta));
+        self.configure(|timer| timer.frame_time(delta));
+    }
+
+    /// Recorded version of [`Timer::fps`]
+    pub fn set_fps(&mut self, fps: f64) {
+        let schedule = Schedule::from_fps(fps);
+        self.recording
+            .events
+            .push(RecordedEvent::Schedule(schedule));
+        self.timer_mut().set_schedule(schedule);
+    }
+
+    /// Recorded version of [`Timer::log_interval`]
+    pub fn set_log_interval(&mut self, log_interval: Duration) {
+        self.recording
+            .events
+            .push(RecordedEvent::LogInterval(log_interval));
+        self.configure(|timer| timer.log_interval(log_interval));
+    }
+
+    /// Recorded version of [`Timer::high_precision`]
+    pub fn set_high_precision(&mut self, enabled: bool) {
+        self.recording
+            .events
+            .push(RecordedEvent::HighPrecision(enabled));
+        self.configure(|timer| timer.high_precision(enabled));
+    }
+
+    /// Places an external mark in the recording, e.g. to annotate
+    /// the point where a pacing issue was observed.
+    ///
+    /// # Panics
+    /// if `label` contains a newline
+    pub fn mark(&mut self, label: impl Into<String>) {
+        let label = label.into();
+        assert!(!label.contains('\n'), "mark labels must be single-line");
+        self.recording.events.push(RecordedEvent::Mark(label));
+    }
+
+    /// Recorded version of [`Timer::session_seed`]
+    pub fn set_session_seed(&mut self, seed: u64) {
+        self.recording.events.push(RecordedEvent::Seed(seed));
+        self.configure(|timer| timer.session_seed(seed));
+    }
+
+    /// the recorded timer
+    pub fn timer(&self) -> &Timer {
+        self.timer.as_ref().expect(TIMER_TAKEN)
+    }
+
+    /// the recorded timer, mutably
+    fn timer_mut(&mut self) -> &mut Timer {
+        self.timer.as_mut().expect(TIMER_TAKEN)
+    }
+
+    /// applies the builder method `configure` to the recorded timer,
+    /// without creating a placeholder timer in the meantime
+    fn configure(&mut self, configure: impl FnOnce(Timer) -> Timer) {
+        let timer = self.timer.take().expect(TIMER_TAKEN);
+        self.timer = Some(configure(timer));
+    }
+
+    /// Stops recording and returns the [`Recording`]
+    pub fn finish(self) -> Recording {
+        self.recording
+    }
+}
+
+impl Recording {
+    /// recorded events in chronological order
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// Session seed at the start of the recording, see
+    /// [`Timer::frame_seed`] and [`crate::frame_seed`].
+    pub fn session_seed(&self) -> Option<u64> {
+        self.events.iter().find_map(|event| match event {
+            RecordedEvent::Seed(seed) => Some(*seed),
+            _ => None,
+        })
+    }
+
+    /// Replays the recording against a new [`Timer`] and
+    /// returns everything the timer produced.
+    ///
+    /// The replay never sleeps and only uses recorded clock samples,
+    /// so replaying the same recording always produces the same output.
+    /// Framerate targets are recorded with their exact frametime, so
+    /// the replayed timer paces exactly like a recorded timer with
+    /// default settings apart from the recorded ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{Clock, Recorder, ReplayOutput, Timer};
+    ///
+    /// // 3 fps, i.e. a frametime of 333333333.3ns
+    /// let timer = Timer::default().fps(3.).clock(Clock::Synthetic);
+    /// let mut recorder = Recorder::new(timer);
+    /// let mut live = vec![];
+    /// for _ in 0..6 {
+    ///     live.push(ReplayOutput::Frame(recorder.frame()));
+    /// }
+    /// recorder.set_fps(7.);
+    /// for _ in 0..7 {
+    ///     live.push(ReplayOutput::Frame(recorder.frame()));
+    /// }
+    /// let recording = recorder.finish();
+    /// assert_eq!(recording.replay(), live);
+    /// ```
+    pub fn replay(&self) -> Vec<ReplayOutput> {
+        self.replay_into(Timer::default())
+    }
+
+    /// Same as [`Self::replay`], but replays the recording against
+    /// `timer`, e.g. one with sinks, tags or a stats dump attached.
+    ///
+    /// The frametime, logging interval and high precision setting of
+    /// `timer` are replaced with the recorded ones, while all other
+    /// settings are kept, so `timer` should be configured like the
+    /// recorded timer apart from those. Frames run
+    /// through the same path as [`Timer::frame`], so sinks, dumps and
+    /// tags observe the replayed frames like live ones.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use fps_timer::{Recorder, Timer};
+    ///
+    /// let mut recorder = Recorder::new(Timer::default().fps(1000.));
+    /// for _ in 0..5 {
+    ///     recorder.frame();
+    /// }
+    /// let recording = recorder.finish();
+    ///
+    /// # #[cfg(feature = "hooks")]
+    /// # {
+    /// let frames = Arc::new(Mutex::new(0));
+    /// let counter = frames.clone();
+    /// let mut timer = Timer::default();
+    /// timer.on_frame_end(move |_| *counter.lock().unwrap() += 1);
+    /// recording.replay_into(timer);
+    /// assert_eq!(*frames.lock().unwrap(), 5);
+    /// # }
+    /// ```
+    pub fn replay_into(&self, mut timer: Timer) -> Vec<ReplayOutput> {
+        let base = Instant::now();
+        timer.set_schedule(self.schedule);
+        timer = timer
+            .log_interval(self.log_interval)
+            .high_precision(self.high_precision);
+        timer.reset_at(base);
+
+        let mut output = vec![];
+        for event in &self.events {
+            match event {
+                RecordedEvent::Frame { start, end } => {
+                    // a timer that waits where the recorded one didn't
+                    // finishes its wait immediately
+                    let end = end.unwrap_or(*start);
+                    let frame_time = timer.frame_sampled(base + *start, false, Some(base + end));
+                    output.push(ReplayOutput::Frame(frame_time));
+                }
+                RecordedEvent::Log => {
+                    let log = timer.log().map(|log| log.delta_time_avg());
+                    output.push(ReplayOutput::Log(log));
+                }
+                RecordedEvent::FrameTime(delta) => timer = timer.frame_time(*delta),
+                RecordedEvent::Schedule(schedule) => timer.set_schedule(*schedule),
+                RecordedEvent::LogInterval(interval) => timer = timer.log_interval(*interval),
+                RecordedEvent::HighPrecision(enabled) => timer = timer.high_precision(*enabled),
+                RecordedEvent::Mark(label) => output.push(ReplayOutput::Mark(label.clone())),
+                RecordedEvent::Seed(seed) => timer = timer.session_seed(*seed),
+            }
+        }
+        output
+    }
+}
+
+impl Display for Recording {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{HEADER}")?;
+        writeln!(f, "schedule {}", self.schedule.fixed_period())?;
+        writeln!(f, "log_interval {}", self.log_interval.as_nanos())?;
+        writeln!(f, "high_precision {}", self.high_precision as u8)?;
+        for event in &self.events {
+            match event {
+                RecordedEvent::Frame { start, end } => match end {
+                    Some(end) => writeln!(f, "frame {} {}", start.as_nanos(), end.as_nanos())?,
+                    None => writeln!(f, "frame {} -", start.as_nanos())?,
+                },
+                RecordedEvent::Log => writeln!(f, "log")?,
+                RecordedEvent::FrameTime(d) => writeln!(f, "frame_time {}", d.as_nanos())?,
+                RecordedEvent::Schedule(schedule) => {
+                    writeln!(f, "schedule {}", schedule.fixed_period())?
+                }
+                RecordedEvent::LogInterval(d) => writeln!(f, "log_interval {}", d.as_nanos())?,
+                RecordedEvent::HighPrecision(e) => writeln!(f, "high_precision {}", *e as u8)?,
+                RecordedEvent::Mark(label) => writeln!(f, "mark {label}")?,
+                RecordedEvent::Seed(seed) => writeln!(f, "seed {seed}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Error returned when parsing a [`Recording`] fails
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseRecordingError {
+    /// line number (starting at 1) of the offending line
+    line: usize,
+}
+
+impl Display for ParseRecordingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid recording: malformed line {}", self.line)
+    }
+}
+
+impl Error for ParseRecordingError {}
+
+fn parse_nanos(s: &str) -> Option<Duration> {
+    s.parse().ok().map(Duration::from_nanos)
+}
+
+fn parse_schedule(s: &str) -> Option<Schedule> {
+    s.parse().ok().map(Schedule::from_fixed_period)
+}
+
+fn parse_event(line: &str) -> Option<RecordedEvent> {
+    let (keyword, args) = line.split_once(' ').unwrap_or((line, ""));
+    let event = match keyword {
+        "frame" => {
+            let (start, end) = args.split_once(' ')?;
+            let end = match end {
+                "-" => None,
+                end => Some(parse_nanos(end)?),
+            };
+            RecordedEvent::Frame {
+                start: parse_nanos(start)?,
+                end,
+            }
+        }
+        "log" if args.is_empty() => RecordedEvent::Log,
+        "frame_time" => RecordedEvent::FrameTime(parse_nanos(args)?),
+        "schedule" => RecordedEvent::Schedule(parse_schedule(args)?),
+        "log_interval" => RecordedEvent::LogInterval(parse_nanos(args)?),
+        "high_precision" => RecordedEvent::HighPrecision(match args {
+            "0" => false,
+            "1" => true,
+            _ => return None,
+        }),
+        "mark" => RecordedEvent::Mark(args.to_owned()),
+        "seed" => RecordedEvent::Seed(args.parse().ok()?),
+        _ => return None,
+    };
+    Some(event)
+}
+
+impl FromStr for Recording {
+    type Err = ParseRecordingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |line| ParseRecordingError { line };
+        let mut lines = s.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(error(1));
+        }
+        let mut header = |line, key| {
+            lines
+                .next()
+                .and_then(|text| text.strip_prefix(key))
+                .ok_or(error(line))
+        };
+        let schedule = parse_schedule(header(2, "schedule ")?).ok_or(error(2))?;
+        let log_interval = parse_nanos(header(3, "log_interval ")?).ok_or(error(3))?;
+        let high_precision = match header(4, "high_precision ")? {
+            "0" => false,
+            "1" => true,
+            _ => return Err(error(4)),
+        };
+        let events = lines
+            .enumerate()
+            .map(|(i, line)| parse_event(line).ok_or(error(i + 5)))
+            .collect::<Result<_, _>>()?;
+        Ok(Recording {
+            schedule,
+            log_interval,
+            high_precision,
+            events,
+        })
+    }
+}
@@ -0,0 +1,83 @@
+use crate::Timer;
+
+impl Timer {
+    /// Discards all frametime statistics, e.g. the frames of a loading
+    /// screen before a benchmark scene, and starts a new logging
+    /// interval, see [`Self::reset_log_interval`].
+    ///
+    /// Unlike creating a new timer, the frame schedule is kept, so
+    /// this can be called at any time without causing a hitch.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.);
+    /// let start = timer.timebase_epoch();
+    /// // a slow loading screen
+    /// timer.frame_at(start + Duration::from_millis(500));
+    /// timer.reset_stats();
+    /// for ms in 1..=10 {
+    ///     timer.frame_at(start + Duration::from_millis(500 + ms));
+    /// }
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// let stats = *timer.session_summary().frame_time_stats();
+    /// assert_eq!(stats.frames(), 10);
+    /// assert_eq!(stats.max(), Duration::from_millis(1));
+    /// # }
+    /// ```
+    pub fn reset_stats(&mut self) {
+        self.stats.reset();
+        #[cfg(feature = "stats")]
+        {
+            self.wakeups = Default::default();
+            self.tags.reset();
+        }
+        self.reset_log_interval();
+    }
+
+    /// Starts a new logging interval at the end of the most recent
+    /// frame, discarding the measurements of the current one, so the
+    /// next [`crate::Log`] only covers the frames from now on.
+    ///
+    /// Neither the frame schedule nor the session statistics are
+    /// affected.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(0.)
+    ///     .log_interval(Duration::from_millis(100));
+    /// let start = timer.timebase_epoch();
+    /// timer.frame_at(start + Duration::from_millis(50));
+    /// timer.reset_log_interval();
+    /// timer.frame_at(start + Duration::from_millis(100));
+    /// // the interval now ends 100ms after the reset
+    /// assert!(timer.log().is_none());
+    /// timer.frame_at(start + Duration::from_millis(150));
+    /// let log = timer.log().unwrap();
+    /// assert_eq!(log.delta_time_avg(), Duration::from_millis(50));
+    /// ```
+    pub fn reset_log_interval(&mut self) {
+        let now = self.previous;
+        let frames = self.framecount.wrapping_sub(self.prev_framecount);
+        let elapsed = now.saturating_duration_since(self.previous_log);
+        self.stats.take_interval();
+        self.stats.take_interval_wait();
+        self.scopes.take_interval(frames, elapsed);
+        #[cfg(feature = "stats")]
+        {
+            self.checkpoints.take_interval();
+            self.lateness.take_interval();
+        }
+        self.pending_logs.clear();
+        self.previous_log = now;
+        self.log_target = self.next_log_target(now);
+        self.prev_framecount = self.framecount;
+    }
+}
@@ -0,0 +1,77 @@
+use std::{ops::ControlFlow, time::Duration};
+
+use crate::{Log, Timer};
+
+/// Per-frame context passed to the closure of [`run_at_fps`]
+pub struct FrameContext<'a> {
+    /// timer driving the loop
+    pub(crate) timer: &'a mut Timer,
+    /// frametime of the current frame
+    pub(crate) delta: Duration,
+}
+
+impl FrameContext<'_> {
+    /// frametime of the current frame, as returned by [`Timer::frame`]
+    pub fn delta_time(&self) -> Duration {
+        self.delta
+    }
+
+    /// frametime of the current frame in seconds
+    pub fn delta_secs(&self) -> f64 {
+        self.delta.as_secs_f64()
+    }
+
+    /// interval statistics, see [`Timer::log`]
+    pub fn log(&mut self) -> Option<Log> {
+        self.timer.log()
+    }
+
+    /// the timer driving the loop, e.g. to change the framerate
+    pub fn timer(&mut self) -> &mut Timer {
+        self.timer
+    }
+}
+
+/// Runs `frame` at `fps` frames per second until it returns
+/// [`ControlFlow::Break`], and returns the break value.
+///
+/// This is a shorthand for the common loop around [`Timer::frame`]
+/// for small tools and examples. Use a [`Timer`] directly for
+/// more control.
+///
+/// # Arguments
+/// * `fps` - target framerate, see [`Timer::fps`]
+/// * `frame` - called once per frame
+///
+/// # Example
+/// ```rust
+/// use std::ops::ControlFlow;
+///
+/// let mut frames = 0;
+/// let last = fps_timer::run_at_fps(1000., |ctx| {
+///     frames += 1;
+///     if let Some(log) = ctx.log() {
+///         println!("{:.3}ms", log.delta_time_avg_ms());
+///     }
+///     if frames == 10 {
+///         ControlFlow::Break(ctx.delta_time())
+///     } else {
+///         ControlFlow::Continue(())
+///     }
+/// });
+/// assert_eq!(frames, 10);
+/// assert!(last.as_secs_f64() > 0.);
+/// ```
+pub fn run_at_fps<B>(fps: f64, mut frame: impl FnMut(&mut FrameContext) -> ControlFlow<B>) -> B {
+    let mut timer = Timer::default().fps(fps);
+    loop {
+        let delta = timer.frame();
+        let mut ctx = FrameContext {
+            timer: &mut timer,
+            delta,
+        };
+        if let ControlFlow::Break(result) = frame(&mut ctx) {
+            return result;
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::{ops::ControlFlow, time::Instant};
+
+use crate::{FrameContext, Timer};
+
+/// callback of a task, called once per frame
+type Callback<'a> = Box<dyn FnMut(&mut FrameContext) -> ControlFlow<()> + 'a>;
+
+/// a timer and the callback it paces
+struct Task<'a> {
+    timer: Timer,
+    callback: Callback<'a>,
+}
+
+impl Task<'_> {
+    /// target of the next frame, uncapped timers are always due
+    fn deadline(&self) -> Option<Instant> {
+        (!self.timer.delta_time.is_zero()).then_some(self.timer.target)
+    }
+}
+
+/// Runs the callbacks of several timers interleaved on a single thread.
+///
+/// The scheduler always waits for the nearest frame target of all its
+/// timers and then runs the callback of that timer, e.g. a 60 Hz UI
+/// tick, a 10 Hz network tick and a 1 Hz statistics tick without a
+/// thread each. Every timer keeps its own schedule, precision and
+/// statistics. A callback taking long delays the others, which catch
+/// up within their slack like any late frame.
+///
+/// # Example
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use fps_timer::{Scheduler, Timer};
+///
+/// let (mut ui, mut network) = (0, 0);
+/// let mut scheduler = Scheduler::new()
+///     .task(Timer::default().fps(200.), |_| {
+///         ui += 1;
+///         ControlFlow::Continue(())
+///     })
+///     .task(Timer::default().fps(50.), |_| {
+///         network += 1;
+///         match network {
+///             5 => ControlFlow::Break(()),
+///             _ => ControlFlow::Continue(()),
+///         }
+///     })
+///     .task(Timer::default().fps(1.), |ctx| {
+///         if let Some(log) = ctx.log() {
+///             println!("{log}");
+///         }
+///         ControlFlow::Continue(())
+///     });
+/// // run until the network task is done
+/// while scheduler.len() == 3 {
+///     scheduler.step();
+/// }
+/// drop(scheduler);
+/// assert_eq!(network, 5);
+/// assert!(ui >= 15);
+/// ```
+#[derive(Default)]
+pub struct Scheduler<'a> {
+    tasks: Vec<Task<'a>>,
+}
+
+impl<'a> Scheduler<'a> {
+    /// Creates a scheduler without tasks
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a task running `callback` once per frame of `timer`, until
+    /// it returns [`ControlFlow::Break`].
+    ///
+    /// # Arguments
+    /// * `timer` - timer pacing the task
+    /// * `callback` - called once per frame of the timer
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) scheduler
+    pub fn task(
+        mut self,
+        timer: Timer,
+        callback: impl FnMut(&mut FrameContext) -> ControlFlow<()> + 'a,
+    ) -> Self {
+        self.tasks.push(Task {
+            timer,
+            callback: Box::new(callback),
+        });
+        self
+    }
+
+    /// number of tasks that did not finish yet
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// `true` once all tasks finished
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Waits for the nearest frame target and runs the callback of
+    /// its task, removing the task if it finished.
+    ///
+    /// # Returns
+    /// the index of the task in the order of [`Self::task`] calls,
+    /// counting only unfinished tasks, [`None`] without tasks
+    pub fn step(&mut self) -> Option<usize> {
+        let index = self
+            .tasks
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, task)| task.deadline())
+            .map(|(index, _)| index)?;
+        let task = &mut self.tasks[index];
+        let delta = task.timer.frame();
+        let mut ctx = FrameContext {
+            timer: &mut task.timer,
+            delta,
+        };
+        if (task.callback)(&mut ctx).is_break() {
+            self.tasks.remove(index);
+        }
+        Some(index)
+    }
+
+    /// Runs all tasks until every one of them finished.
+    pub fn run(&mut self) {
+        while self.step().is_some() {}
+    }
+}
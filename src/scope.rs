@@ -0,0 +1,178 @@
+use std::time::Duration;
+#[cfg(feature = "scopes")]
+use std::time::Instant;
+
+use crate::Timer;
+
+/// Time spent in a named scope during a logging interval,
+/// as returned by [`crate::Log::scopes`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScopeStats {
+    /// name of the scope
+    name: &'static str,
+    /// number of times the scope was entered
+    calls: u64,
+    /// average time spent in the scope per frame
+    avg: Duration,
+    /// longest single pass through the scope
+    max: Duration,
+    /// share of the interval spent in the scope
+    share: f64,
+    /// number of passes that exceeded their budget
+    overruns: u64,
+}
+
+impl ScopeStats {
+    /// name of the scope
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// number of times the scope was entered during the interval
+    pub fn calls(&self) -> u64 {
+        self.calls
+    }
+
+    /// average time spent in the scope per frame
+    pub fn avg(&self) -> Duration {
+        self.avg
+    }
+
+    /// longest single pass through the scope
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// share of the interval spent in the scope in `[0, 1]`
+    pub fn share(&self) -> f64 {
+        self.share
+    }
+
+    /// number of passes that exceeded their share of the frame budget,
+    /// see [`Timer::parallel_section`]
+    pub fn overruns(&self) -> u64 {
+        self.overruns
+    }
+}
+
+/// accumulated time of a single scope
+#[derive(Clone, Debug)]
+struct ScopeAccum {
+    name: &'static str,
+    calls: u64,
+    total: Duration,
+    max: Duration,
+    overruns: u64,
+}
+
+/// per-interval accumulation of scope timings
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Scopes {
+    /// scopes in the order they were first entered
+    entries: Vec<ScopeAccum>,
+}
+
+impl Scopes {
+    fn record(&mut self, name: &'static str, elapsed: Duration) {
+        self.record_section(name, elapsed, false);
+    }
+
+    /// records a pass through a scope, `overran` if it exceeded its budget
+    pub(crate) fn record_section(&mut self, name: &'static str, elapsed: Duration, overran: bool) {
+        let entry = match self.entries.iter_mut().position(|e| e.name == name) {
+            Some(i) => &mut self.entries[i],
+            None => {
+                self.entries.push(ScopeAccum {
+                    name,
+                    calls: 0,
+                    total: Duration::ZERO,
+                    max: Duration::ZERO,
+                    overruns: 0,
+                });
+                self.entries.last_mut().expect("just pushed")
+            }
+        };
+        entry.calls += 1;
+        entry.total += elapsed;
+        entry.max = entry.max.max(elapsed);
+        entry.overruns += u64::from(overran);
+    }
+
+    /// statistics of the interval of `frames` frames lasting `elapsed`,
+    /// resetting the accumulation
+    pub(crate) fn take_interval(&mut self, frames: u64, elapsed: Duration) -> Vec<ScopeStats> {
+        let frames = u32::try_from(frames.max(1)).unwrap_or(u32::MAX);
+        self.entries
+            .drain(..)
+            .map(|e| ScopeStats {
+                name: e.name,
+                calls: e.calls,
+                avg: e.total / frames,
+                max: e.max,
+                share: match elapsed {
+                    Duration::ZERO => 0.,
+                    elapsed => (e.total.as_secs_f64() / elapsed.as_secs_f64()).min(1.),
+                },
+                overruns: e.overruns,
+            })
+            .collect()
+    }
+}
+
+impl Timer {
+    /// Runs `f` as the named scope `name` and returns its result.
+    ///
+    /// The time spent in every scope is broken down in each [`crate::Log`]
+    /// (see [`crate::Log::scopes`]), answering where the time of the
+    /// frames went during the interval.
+    ///
+    /// Without the `scopes` feature, `f` is run without being timed.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(100.)
+    ///     .log_interval(Duration::from_millis(30));
+    /// let log = loop {
+    ///     timer.frame();
+    ///     timer.scope("physics", || thread::sleep(Duration::from_millis(2)));
+    ///     timer.scope("render", || thread::sleep(Duration::from_millis(3)));
+    ///     if let Some(log) = timer.log() {
+    ///         break log;
+    ///     }
+    /// };
+    /// # #[cfg(feature = "scopes")]
+    /// # {
+    /// let render = &log.scopes()[1];
+    /// assert_eq!(render.name(), "render");
+    /// assert!(render.avg() >= Duration::from_millis(3));
+    /// assert!(render.share() > 0.2);
+    /// # }
+    /// ```
+    pub fn scope<R>(&mut self, name: &'static str, f: impl FnOnce() -> R) -> R {
+        #[cfg(feature = "scopes")]
+        {
+            let start = Instant::now();
+            let result = f();
+            self.record_scope(name, start.elapsed());
+            result
+        }
+        #[cfg(not(feature = "scopes"))]
+        {
+            let _ = name;
+            f()
+        }
+    }
+
+    /// Records `elapsed` as time spent in the named scope `name`,
+    /// for scopes timed by the application.
+    pub fn record_scope(&mut self, name: &'static str, elapsed: Duration) {
+        #[cfg(feature = "scopes")]
+        self.scopes.record(name, elapsed);
+        #[cfg(not(feature = "scopes"))]
+        let _ = (name, elapsed);
+    }
+}
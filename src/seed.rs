@@ -0,0 +1,74 @@
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+};
+
+use crate::Timer;
+
+/// a random session seed
+pub(crate) fn random_seed() -> u64 {
+    RandomState::new().build_hasher().finish()
+}
+
+/// finalizer of SplitMix64, a bijective mix of all bits of `x`
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Derives the seed of frame `frame` from the session seed `session_seed`,
+/// see [`Timer::frame_seed`].
+///
+/// Seeds of consecutive frames are statistically independent, so they
+/// can seed a fresh random number generator every frame.
+pub fn frame_seed(session_seed: u64, frame: u64) -> u64 {
+    mix(session_seed ^ mix(frame.wrapping_add(0x9e37_79b9_7f4a_7c15)))
+}
+
+impl Timer {
+    /// Sets the seed all frame seeds are derived from,
+    /// see [`Self::frame_seed`]. Random by default.
+    ///
+    /// # Arguments
+    /// * `seed` - seed of the session
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn session_seed(mut self, seed: u64) -> Self {
+        self.session_seed = seed;
+        self
+    }
+
+    /// seed all frame seeds are derived from, see [`Self::session_seed`]
+    pub fn seed(&self) -> u64 {
+        self.session_seed
+    }
+
+    /// Deterministic seed of the current frame, derived from the session
+    /// seed and the index of the frame, see [`frame_seed`].
+    ///
+    /// The current frame is the one started by the most recent call to
+    /// [`Self::frame`], so its index is the number of completed frames.
+    /// Lockstep and replay modes can seed their per-frame randomness
+    /// with it: a [`crate::Recorder`] stores the session seed, so frame
+    /// seeds match between recording and replay.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{frame_seed, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(1000.).session_seed(42);
+    /// let mut seeds = vec![];
+    /// for _ in 0..3 {
+    ///     timer.frame();
+    ///     seeds.push(timer.frame_seed());
+    /// }
+    /// assert_ne!(seeds[0], seeds[1]);
+    /// // e.g. on a lockstep peer
+    /// assert_eq!(seeds, [1, 2, 3].map(|frame| frame_seed(42, frame)));
+    /// ```
+    pub fn frame_seed(&self) -> u64 {
+        frame_seed(self.session_seed, self.framecount)
+    }
+}
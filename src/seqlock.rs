@@ -0,0 +1,83 @@
+use std::{
+    array, hint,
+    sync::atomic::{fence, AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+/// attempts of [`SeqLock::read`] to get a consistent value, which fail
+/// if the writer is interrupted while publishing, e.g. by a signal
+/// handler or a panic hook on the same thread
+const READ_ATTEMPTS: usize = 64;
+
+/// Value published by a single thread without locking, as sequence
+/// lock over atomic words: the sequence is odd while a write is in
+/// progress and readers retry if it changed while reading.
+///
+/// Instants are published as nanoseconds since a reference instant,
+/// see [`nanos_since`].
+#[derive(Debug)]
+pub(crate) struct SeqLock<const N: usize> {
+    /// number of started and completed writes
+    sequence: AtomicU64,
+    /// the words of the value
+    words: [AtomicU64; N],
+}
+
+impl<const N: usize> SeqLock<N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            sequence: AtomicU64::new(0),
+            words: array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+
+    /// publishes `words`, only ever called by a single thread
+    pub(crate) fn write(&self, words: [u64; N]) {
+        let sequence = self.sequence.load(Ordering::Relaxed);
+        self.sequence
+            .store(sequence.wrapping_add(1), Ordering::Relaxed);
+        fence(Ordering::Release);
+        for (word, value) in self.words.iter().zip(words) {
+            word.store(value, Ordering::Relaxed);
+        }
+        self.sequence
+            .store(sequence.wrapping_add(2), Ordering::Release);
+    }
+
+    /// the most recently published words, [`None`] if nothing was
+    /// published yet or no consistent value could be read
+    pub(crate) fn read(&self) -> Option<[u64; N]> {
+        for _ in 0..READ_ATTEMPTS {
+            let sequence = self.sequence.load(Ordering::Acquire);
+            if sequence == 0 {
+                return None;
+            }
+            if sequence.is_multiple_of(2) {
+                let words = array::from_fn(|i| self.words[i].load(Ordering::Relaxed));
+                fence(Ordering::Acquire);
+                if self.sequence.load(Ordering::Relaxed) == sequence {
+                    return Some(words);
+                }
+            }
+            hint::spin_loop();
+        }
+        None
+    }
+
+    /// the most recently published `index`-th word on its own,
+    /// which never fails
+    pub(crate) fn word(&self, index: usize) -> u64 {
+        self.words[index].load(Ordering::Relaxed)
+    }
+}
+
+/// `duration` in nanoseconds, saturating at [`u64::MAX`]
+pub(crate) fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+/// `instant` as nanoseconds since `base`, saturating at zero
+/// and [`u64::MAX`]
+pub(crate) fn nanos_since(base: Instant, instant: Instant) -> u64 {
+    nanos(instant.saturating_duration_since(base))
+}
@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use crate::{Timer, MAX_INTERVAL};
+
+/// largest supported time scale
+const MAX_TIME_SCALE: f64 = 1e6;
+
+impl Timer {
+    /// Sets the speed of session time relative to real time,
+    /// e.g. `0.5` for slow motion. `1` by default.
+    ///
+    /// The time scale only affects [`Self::session_time`],
+    /// [`Self::scaled_delta`] and [`crate::Transition`]s, not the
+    /// framerate or the frametimes returned by [`Self::frame`].
+    ///
+    /// # Arguments
+    /// * `scale` - non-negative speed of session time,
+    ///   negative and NaN values stop session time
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn time_scale(mut self, scale: f64) -> Self {
+        self.set_time_scale(scale);
+        self
+    }
+
+    /// Changes the speed of session time from the next frame on,
+    /// see [`Self::time_scale`].
+    pub fn set_time_scale(&mut self, scale: f64) {
+        self.time_scale = match scale.is_nan() {
+            true => 0.,
+            false => scale.clamp(0., MAX_TIME_SCALE),
+        };
+    }
+
+    /// speed of session time relative to real time
+    pub fn time_scale_factor(&self) -> f64 {
+        self.time_scale
+    }
+
+    /// Real time covered by all frames since the timer was created.
+    pub fn real_time(&self) -> Duration {
+        self.real_time
+    }
+
+    /// Session (game) time covered by all frames since the timer was
+    /// created, advancing by [`Self::scaled_delta`] every frame.
+    ///
+    /// Unlike [`Self::real_time`], session time stands still while
+    /// the timer is paused (see [`Self::is_paused`]) and runs at the
+    /// speed set with [`Self::time_scale`], so gameplay timers,
+    /// replays and speedrun clocks read from one consistent source.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(100.).time_scale(0.5);
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// let real = timer.real_time();
+    /// assert!(real >= Duration::from_millis(99));
+    /// let session = timer.session_time();
+    /// assert!(session.abs_diff(real / 2) < Duration::from_micros(1));
+    ///
+    /// // session time stands still
+    /// timer.set_time_scale(0.);
+    /// timer.frame();
+    /// assert_eq!(timer.session_time(), session);
+    /// ```
+    pub fn session_time(&self) -> Duration {
+        self.session_time
+    }
+
+    /// Session time covered by the previous frame, i.e. its frametime
+    /// scaled by the time scale, or zero while paused.
+    pub fn scaled_delta(&self) -> Duration {
+        match self.is_paused() {
+            true => Duration::ZERO,
+            false => {
+                Duration::try_from_secs_f64(self.last_frame.delta.as_secs_f64() * self.time_scale)
+                    .map_or(MAX_INTERVAL, |delta| delta.min(MAX_INTERVAL))
+            }
+        }
+    }
+
+    /// advances real and session time by the frame that just
+    /// completed after `frame_time`
+    pub(crate) fn advance_session_time(&mut self, frame_time: Duration) {
+        self.real_time += frame_time;
+        // unscaled session time needs no floating point math
+        self.session_time += match self.time_scale == 1. && !self.is_paused() {
+            true => self.last_frame.delta,
+            false => self.scaled_delta(),
+        };
+    }
+}
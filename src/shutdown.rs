@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// default time [`Timer::shutdown`] may take
+pub(crate) const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(1);
+
+impl Timer {
+    /// Sets the time [`Self::shutdown`] may take to drain all sinks,
+    /// `1s` by default.
+    ///
+    /// # Arguments
+    /// * `timeout` - maximum duration of the shutdown
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Drains all attached sinks, so no statistics are lost when the
+    /// process exits in the middle of a logging interval.
+    ///
+    /// Closes the current logging interval and delivers its [`crate::Log`]
+    /// to all sinks, then lets every sink write out buffered data, rotate
+    /// files that are due and close network streams, see
+    /// [`crate::LogSink::flush`]. Sinks not reached within the
+    /// [`Self::shutdown_timeout`] are skipped.
+    ///
+    /// Called automatically when the timer is dropped. The timer can
+    /// keep running afterwards, though sinks may have closed their
+    /// connections.
+    ///
+    /// # Returns
+    /// `true` if all sinks were drained within the timeout
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "sinks")]
+    /// # {
+    /// use std::{sync::mpsc, time::Duration};
+    /// use fps_timer::{Log, Timer};
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut timer = Timer::default()
+    ///     .fps(1000.)
+    ///     .log_interval(Duration::from_secs(60))
+    ///     .log_sink(move |log: &Log| tx.send(log.frame_time_stats().frames()).unwrap());
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// assert!(timer.shutdown());
+    /// // the interval was cut short
+    /// assert_eq!(rx.try_recv(), Ok(10));
+    /// # }
+    /// ```
+    pub fn shutdown(&mut self) -> bool {
+        #[cfg(feature = "sinks")]
+        {
+            use std::time::Instant;
+
+            let deadline = Instant::now() + self.shutdown_timeout;
+            if !self.sinks.is_empty() {
+                if let Some(log) = self.close_log_interval(self.previous) {
+                    self.emit_log(log);
+                }
+            }
+            for sink in &mut self.sinks {
+                let remaining = deadline.saturating_duration_since(Instant::now());
+                if remaining.is_zero() {
+                    return false;
+                }
+                sink.flush(remaining);
+            }
+            Instant::now() <= deadline
+        }
+        #[cfg(not(feature = "sinks"))]
+        true
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        self.shutdown();
+    }
+}
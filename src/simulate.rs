@@ -0,0 +1,279 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use crate::{duration_from_nanos, Pacing, Timer};
+
+/// A pathological clock behavior injected by a [`Simulation`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClockFault {
+    /// every `every`-th wait returns `by` after its target,
+    /// e.g. a coarse or overloaded scheduler
+    Oversleep { every: u64, by: Duration },
+    /// the clock jumps ahead by `duration` before frame `at`,
+    /// e.g. a system suspend or a debugger breakpoint
+    Suspend { at: u64, duration: Duration },
+    /// every `every` frames, the thread is descheduled for `stall`,
+    /// so the following frames arrive in a burst
+    Bursty { every: u64, stall: Duration },
+}
+
+impl ClockFault {
+    /// delay injected before frame `frame` starts
+    fn stall(&self, frame: u64) -> Duration {
+        match *self {
+            ClockFault::Suspend { at, duration } if frame == at => duration,
+            ClockFault::Bursty { every, stall } if every > 0 && frame % every == every - 1 => stall,
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// oversleep of the wait of frame `frame`
+    fn oversleep(&self, frame: u64) -> Duration {
+        match *self {
+            ClockFault::Oversleep { every, by } if every > 0 && frame % every == every - 1 => by,
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+/// An invariant of the [`Timer`] that was violated during a [`Simulation`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// the timer panicked
+    Panicked(String),
+    /// the frametime returned for `frame` does not match the simulated time
+    DeltaMismatch {
+        frame: u64,
+        expected: Duration,
+        returned: Duration,
+    },
+    /// `frame` fell behind by more than the slack without re-basing
+    MissedRebase { frame: u64, behind: Duration },
+    /// the frames drifted from the target rate by more than the
+    /// injected delays explain
+    Drift {
+        elapsed: Duration,
+        min: Duration,
+        max: Duration,
+    },
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::Panicked(message) => write!(f, "timer panicked: {message}"),
+            InvariantViolation::DeltaMismatch {
+                frame,
+                expected,
+                returned,
+            } => write!(
+                f,
+                "frame {frame} returned {returned:?}, but {expected:?} passed"
+            ),
+            InvariantViolation::MissedRebase { frame, behind } => {
+                write!(f, "frame {frame} was {behind:?} behind without re-basing")
+            }
+            InvariantViolation::Drift { elapsed, min, max } => {
+                write!(f, "frames took {elapsed:?}, expected {min:?} to {max:?}")
+            }
+        }
+    }
+}
+
+impl Error for InvariantViolation {}
+
+/// Outcome of a [`Simulation`] that upheld all invariants
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// number of simulated frames
+    frames: u64,
+    /// simulated time covered by all frames
+    elapsed: Duration,
+    /// number of frames that re-based the schedule
+    rebases: u64,
+    /// longest returned frametime
+    max_delta: Duration,
+}
+
+impl SimulationReport {
+    /// number of simulated frames
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// simulated time covered by all frames
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// number of frames that re-based the schedule
+    pub fn rebases(&self) -> u64 {
+        self.rebases
+    }
+
+    /// longest returned frametime
+    pub fn max_delta(&self) -> Duration {
+        self.max_delta
+    }
+}
+
+/// Replays pathological clock behavior against a [`Timer`] on a
+/// simulated clock, checking that the timer
+///
+/// * does not panic,
+/// * returns frametimes matching the simulated time (including the
+///   debt of [`Timer::late_compensation`]),
+/// * re-bases its schedule whenever a frame falls behind by more than
+///   its slack, and
+/// * neither runs faster than its target rate nor loses more time
+///   than the injected delays explain.
+///
+/// Waits complete instantly on the simulated clock, so long sessions
+/// run in milliseconds. Use it to validate custom configurations,
+/// e.g. slack and compensation settings.
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{ClockFault, Simulation, Timer};
+///
+/// let report = Simulation::new(1000)
+///     .work(Duration::from_millis(4))
+///     .fault(ClockFault::Oversleep { every: 7, by: Duration::from_millis(3) })
+///     .fault(ClockFault::Suspend { at: 500, duration: Duration::from_secs(30) })
+///     .fault(ClockFault::Bursty { every: 100, stall: Duration::from_millis(40) })
+///     .run(Timer::default().fps(100.))?;
+/// assert_eq!(report.frames(), 1000);
+/// assert!(report.rebases() >= 1);
+/// # Ok::<(), fps_timer::InvariantViolation>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Simulation {
+    /// number of frames to simulate
+    frames: u64,
+    /// simulated work of the application per frame
+    work: Duration,
+    /// injected clock behavior
+    faults: Vec<ClockFault>,
+}
+
+impl Simulation {
+    /// Creates a simulation of `frames` frames without work and faults.
+    pub fn new(frames: u64) -> Self {
+        Self {
+            frames,
+            work: Duration::ZERO,
+            faults: Vec::new(),
+        }
+    }
+
+    /// Sets the simulated work of the application per frame.
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) simulation
+    pub fn work(mut self, work: Duration) -> Self {
+        self.work = work;
+        self
+    }
+
+    /// Injects a pathological clock behavior. Faults combine.
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) simulation
+    pub fn fault(mut self, fault: ClockFault) -> Self {
+        self.faults.push(fault);
+        self
+    }
+
+    /// Runs the simulation against `timer`.
+    ///
+    /// # Errors
+    /// the first [`InvariantViolation`]
+    pub fn run(&self, mut timer: Timer) -> Result<SimulationReport, InvariantViolation> {
+        panic::catch_unwind(AssertUnwindSafe(|| self.simulate(&mut timer))).unwrap_or_else(
+            |payload| {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_default();
+                Err(InvariantViolation::Panicked(message))
+            },
+        )
+    }
+
+    fn simulate(&self, timer: &mut Timer) -> Result<SimulationReport, InvariantViolation> {
+        let start = timer.previous;
+        let mut now = start;
+        let mut injected = Duration::ZERO;
+        let mut report = SimulationReport {
+            frames: self.frames,
+            elapsed: Duration::ZERO,
+            rebases: 0,
+            max_delta: Duration::ZERO,
+        };
+        let mut returned = Duration::ZERO;
+        for frame in 0..self.frames {
+            let stall: Duration = self.faults.iter().map(|f| f.stall(frame)).sum();
+            let oversleep: Duration = self.faults.iter().map(|f| f.oversleep(frame)).sum();
+            let period = timer.delta_time;
+            injected += stall + oversleep + self.work.saturating_sub(period);
+
+            now += self.work + stall;
+            let behind = now.saturating_duration_since(timer.target);
+            let must_rebase =
+                period > Duration::ZERO && behind > timer.slack() && timer.average_rate.is_none();
+            let previous = timer.previous;
+            let debt = timer.compensation_debt();
+            let delta = timer.frame_with(now, |target, _| target + oversleep);
+            let end = timer.frame_info().timestamp();
+            now = now.max(end);
+
+            // the returned frametime and the change of the debt
+            // account for exactly the time that passed
+            let expected = end.saturating_duration_since(previous);
+            if delta + timer.compensation_debt() != expected + debt {
+                return Err(InvariantViolation::DeltaMismatch {
+                    frame,
+                    expected,
+                    returned: delta,
+                });
+            }
+            if must_rebase && !timer.frame_info().is_discontinuity() {
+                return Err(InvariantViolation::MissedRebase { frame, behind });
+            }
+            report.rebases += u64::from(timer.frame_info().is_discontinuity());
+            report.max_delta = report.max_delta.max(delta);
+            returned += delta;
+        }
+        report.elapsed = now.saturating_duration_since(start);
+        if returned + timer.compensation_debt() != report.elapsed {
+            return Err(InvariantViolation::DeltaMismatch {
+                frame: self.frames.saturating_sub(1),
+                expected: report.elapsed,
+                returned,
+            });
+        }
+
+        // frames never come faster than the target rate, and
+        // time is only lost to injected delays
+        let period = timer.delta_time;
+        if period > Duration::ZERO && timer.pacing == Pacing::Internal {
+            let nominal = duration_from_nanos(period.as_nanos() * u128::from(self.frames));
+            let max = nominal + injected + timer.slack() + period;
+            let min = nominal.saturating_sub(period);
+            if report.elapsed > max || report.elapsed < min {
+                return Err(InvariantViolation::Drift {
+                    elapsed: report.elapsed,
+                    min,
+                    max,
+                });
+            }
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use crate::{Log, Timer};
+
+/// Destination for the interval statistics of a [`Timer`].
+///
+/// Sinks are attached with [`Timer::log_sink`]. Once a sink is
+/// attached, logging intervals are closed automatically at the end of
+/// the first frame after [`Timer::log_interval`] has passed, and every
+/// [`Log`] is delivered to all sinks in the order they were attached.
+/// [`Timer::log`] keeps returning the most recent log as well.
+///
+/// Closures taking a `&Log` implement this trait as well.
+///
+/// # Example
+/// ```rust
+/// use std::{sync::mpsc, time::Duration};
+/// use fps_timer::{Log, LogSink, Timer};
+///
+/// /// forwards the average framerate to another thread
+/// struct Telemetry(mpsc::Sender<f64>);
+///
+/// impl LogSink for Telemetry {
+///     fn log(&mut self, log: &Log) {
+///         let _ = self.0.send(log.fps_average());
+///     }
+/// }
+///
+/// let (tx, rx) = mpsc::channel();
+/// let mut timer = Timer::default()
+///     .fps(1000.)
+///     .log_interval(Duration::from_millis(5))
+///     .log_sink(Telemetry(tx))
+///     .log_sink(|log: &Log| println!("{:.3}ms", log.delta_time_avg_ms()));
+/// for _ in 0..20 {
+///     timer.frame();
+/// }
+/// assert!(rx.try_iter().count() > 0);
+/// ```
+pub trait LogSink: Send + Sync {
+    /// Receives the statistics of a completed logging interval.
+    fn log(&mut self, log: &Log);
+
+    /// Writes out buffered data and closes connections when the
+    /// timer shuts down, see [`Timer::shutdown`]. Should return
+    /// within `timeout`. Does nothing by default.
+    fn flush(&mut self, timeout: Duration) {
+        let _ = timeout;
+    }
+}
+
+impl<F: FnMut(&Log) + Send + Sync> LogSink for F {
+    fn log(&mut self, log: &Log) {
+        self(log)
+    }
+}
+
+impl Timer {
+    /// Attaches a [`LogSink`] receiving the statistics of every
+    /// logging interval. Multiple sinks can be attached.
+    ///
+    /// # Arguments
+    /// * `sink` - destination for interval statistics
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn log_sink(mut self, sink: impl LogSink + 'static) -> Self {
+        self.sinks.push(Box::new(sink));
+        self
+    }
+
+    /// closes the logging interval if it is due and sinks are attached
+    pub(crate) fn flush_sinks(&mut self) {
+        if self.sinks.is_empty() || !self.log_due(self.previous) {
+            return;
+        }
+        if let Some(log) = self.close_log_interval(self.previous) {
+            self.emit_log(log);
+        }
+    }
+}
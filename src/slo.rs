@@ -0,0 +1,285 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::Timer;
+
+/// number of buckets a window is divided into
+const BUCKETS: u32 = 64;
+
+/// A frametime service-level objective, e.g. "99% of frames under
+/// 20ms over any 5-minute window", see [`Timer::slo`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Slo {
+    /// share of frames that must meet the threshold
+    target: f64,
+    /// frametime a frame must not exceed
+    threshold: Duration,
+    /// window the share is evaluated over
+    window: Duration,
+}
+
+impl Slo {
+    /// Creates an objective requiring a share of `target` in `[0, 1]`
+    /// of the frames in any `window` to take at most `threshold`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Slo;
+    ///
+    /// // 99% of frames under 20ms over any 5-minute window
+    /// let slo = Slo::new(0.99, Duration::from_millis(20), Duration::from_secs(300));
+    /// ```
+    pub fn new(target: f64, threshold: Duration, window: Duration) -> Self {
+        Self {
+            target: target.clamp(0., 1.),
+            threshold,
+            window,
+        }
+    }
+
+    /// share of frames that must meet the threshold
+    pub fn target(&self) -> f64 {
+        self.target
+    }
+
+    /// frametime a frame must not exceed
+    pub fn threshold(&self) -> Duration {
+        self.threshold
+    }
+
+    /// window the share is evaluated over
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+}
+
+/// Current state of an [`Slo`], see [`Timer::slo_status`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SloStatus {
+    /// name of the objective
+    name: String,
+    /// the objective
+    slo: Slo,
+    /// share of frames in the window meeting the threshold
+    compliance: f64,
+    /// whether a full window was observed
+    evaluated: bool,
+    /// number of times the objective became violated
+    violations: u64,
+}
+
+impl SloStatus {
+    /// name of the objective
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// the objective
+    pub fn slo(&self) -> Slo {
+        self.slo
+    }
+
+    /// share of frames in the most recent window meeting the threshold,
+    /// `1` before the first frame
+    pub fn compliance(&self) -> f64 {
+        self.compliance
+    }
+
+    /// `false` while the timer has run for less than one window,
+    /// during which the objective is never reported as violated
+    pub fn is_evaluated(&self) -> bool {
+        self.evaluated
+    }
+
+    /// `true` unless the objective is currently violated
+    pub fn is_met(&self) -> bool {
+        !self.evaluated || self.compliance >= self.slo.target
+    }
+
+    /// number of times the objective became violated
+    pub fn violations(&self) -> u64 {
+        self.violations
+    }
+}
+
+/// frames of a slice of the window
+#[derive(Clone, Copy, Debug)]
+struct Bucket {
+    /// timestamp of the first frame in the bucket
+    start: Instant,
+    /// frames in the bucket
+    frames: u64,
+    /// frames exceeding the threshold
+    over: u64,
+}
+
+/// sliding window evaluation of a single objective
+struct Tracker {
+    /// name of the objective
+    name: String,
+    slo: Slo,
+    /// frames of the most recent window, oldest first
+    buckets: VecDeque<Bucket>,
+    /// timestamp of the first frame
+    first: Option<Instant>,
+    /// whether the objective is currently violated
+    violated: bool,
+    /// number of times the objective became violated
+    violations: u64,
+}
+
+impl Tracker {
+    /// records a frame ending at `timestamp` after `frame_time`,
+    /// returning `true` if the objective just became violated
+    fn record(&mut self, timestamp: Instant, frame_time: Duration) -> bool {
+        let width = (self.slo.window / BUCKETS).max(Duration::from_nanos(1));
+        let first = *self.first.get_or_insert(timestamp);
+        while let Some(bucket) = self.buckets.front() {
+            match bucket.start + width + self.slo.window <= timestamp {
+                true => self.buckets.pop_front(),
+                false => break,
+            };
+        }
+        let over = u64::from(frame_time > self.slo.threshold);
+        match self.buckets.back_mut() {
+            Some(bucket) if timestamp < bucket.start + width => {
+                bucket.frames += 1;
+                bucket.over += over;
+            }
+            _ => self.buckets.push_back(Bucket {
+                start: timestamp,
+                frames: 1,
+                over,
+            }),
+        }
+        let violated = self.evaluated(timestamp, first) && self.compliance() < self.slo.target;
+        let became_violated = violated && !self.violated;
+        self.violated = violated;
+        self.violations += u64::from(became_violated);
+        became_violated
+    }
+
+    /// whether a full window was observed at `timestamp`
+    fn evaluated(&self, timestamp: Instant, first: Instant) -> bool {
+        timestamp.saturating_duration_since(first) >= self.slo.window
+    }
+
+    fn compliance(&self) -> f64 {
+        let (frames, over) = self
+            .buckets
+            .iter()
+            .fold((0, 0), |(f, o), b| (f + b.frames, o + b.over));
+        match frames {
+            0 => 1.,
+            frames => 1. - over as f64 / frames as f64,
+        }
+    }
+
+    fn status(&self, now: Instant) -> SloStatus {
+        SloStatus {
+            name: self.name.clone(),
+            slo: self.slo,
+            compliance: self.compliance(),
+            evaluated: self.first.is_some_and(|first| self.evaluated(now, first)),
+            violations: self.violations,
+        }
+    }
+}
+
+type SloHook = Box<dyn FnMut(&SloStatus) + Send + Sync>;
+
+/// objectives evaluated by a [`Timer`]
+#[derive(Default)]
+pub(crate) struct Slos {
+    trackers: Vec<Tracker>,
+    /// called whenever an objective becomes violated
+    hooks: Vec<SloHook>,
+}
+
+impl Timer {
+    /// Declares a frametime service-level objective that is evaluated
+    /// continuously over a sliding window of frames, see [`Slo`].
+    ///
+    /// The window is tracked in 64 slices, so frames leave the window
+    /// with a granularity of 1/64th of the window. Objectives are only
+    /// reported as violated once the timer has run for a full window.
+    /// Declaring an objective with an existing name replaces it.
+    ///
+    /// # Arguments
+    /// * `name` - name of the objective
+    /// * `slo` - the objective
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{sync::{Arc, Mutex}, time::Duration};
+    /// use fps_timer::{Slo, Timer};
+    ///
+    /// let slo = Slo::new(0.999, Duration::from_millis(20), Duration::from_millis(100));
+    /// let mut timer = Timer::default().fps(0.).slo("frames", slo);
+    /// let violations = Arc::new(Mutex::new(vec![]));
+    /// let log = violations.clone();
+    /// timer.on_slo_violation(move |status| log.lock().unwrap().push(status.compliance()));
+    ///
+    /// let start = timer.timebase_epoch();
+    /// for ms in 1..=150 {
+    ///     timer.frame_at(start + Duration::from_millis(ms));
+    /// }
+    /// assert!(timer.slo_status()[0].is_met());
+    /// // a 50ms frame
+    /// timer.frame_at(start + Duration::from_millis(200));
+    /// assert!(!timer.slo_status()[0].is_met());
+    /// assert_eq!(violations.lock().unwrap().len(), 1);
+    /// ```
+    pub fn slo(mut self, name: &str, slo: Slo) -> Self {
+        let tracker = Tracker {
+            name: name.to_owned(),
+            slo,
+            buckets: VecDeque::new(),
+            first: None,
+            violated: false,
+            violations: 0,
+        };
+        let trackers = &mut self.slos.trackers;
+        match trackers.iter_mut().find(|t| t.name == name) {
+            Some(existing) => *existing = tracker,
+            None => trackers.push(tracker),
+        }
+        self
+    }
+
+    /// Registers a hook that is called with the [`SloStatus`] of an
+    /// objective whenever it becomes violated, e.g. to fail a soak test.
+    pub fn on_slo_violation(&mut self, hook: impl FnMut(&SloStatus) + Send + Sync + 'static) {
+        self.slos.hooks.push(Box::new(hook));
+    }
+
+    /// Current compliance of all objectives declared with [`Self::slo`],
+    /// in the order they were declared.
+    pub fn slo_status(&self) -> Vec<SloStatus> {
+        self.slos
+            .trackers
+            .iter()
+            .map(|tracker| tracker.status(self.previous))
+            .collect()
+    }
+
+    /// evaluates all objectives with the frame that just completed
+    pub(crate) fn track_slos(&mut self, frame_time: Duration) {
+        let timestamp = self.last_frame.timestamp;
+        let Slos { trackers, hooks } = &mut self.slos;
+        for tracker in trackers {
+            if tracker.record(timestamp, frame_time) {
+                let status = tracker.status(timestamp);
+                for hook in hooks.iter_mut() {
+                    hook(&status);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// default smoothing factor of [`Timer::smoothed_delta`]
+pub(crate) const DEFAULT_DELTA_SMOOTHING: f64 = 0.1;
+
+impl Timer {
+    /// Sets the smoothing factor in `(0, 1]` of the exponential moving
+    /// average [`Self::smoothed_delta`], `1` meaning no smoothing.
+    /// Defaults to `0.1`.
+    ///
+    /// # Arguments
+    /// * `factor` - weight of the most recent frametime
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn delta_smoothing(mut self, factor: f64) -> Self {
+        self.delta_smoothing = factor.clamp(f64::EPSILON, 1.);
+        self
+    }
+
+    /// Exponential moving average of the frametime, updated every frame.
+    ///
+    /// Unlike the interval averages of [`Self::log`], it changes
+    /// smoothly from frame to frame, which is what on-screen
+    /// fps counters usually want.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(0.).delta_smoothing(0.5);
+    /// let start = timer.timebase_epoch();
+    /// timer.frame_at(start + Duration::from_millis(10));
+    /// assert_eq!(timer.smoothed_delta(), Duration::from_millis(10));
+    /// timer.frame_at(start + Duration::from_millis(30));
+    /// assert_eq!(timer.smoothed_delta(), Duration::from_millis(15));
+    /// ```
+    pub fn smoothed_delta(&self) -> Duration {
+        self.smoothed_delta
+    }
+
+    /// adds `frame_time` to the moving average
+    pub(crate) fn update_smoothed_delta(&mut self, frame_time: Duration) {
+        self.smoothed_delta = match self.framecount {
+            // start from the first frame instead of zero
+            1 => frame_time,
+            _ => {
+                let previous = self.smoothed_delta.as_secs_f64();
+                let current = frame_time.as_secs_f64();
+                Duration::from_secs_f64(previous + (current - previous) * self.delta_smoothing)
+            }
+        };
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// Compact, `Copy` summary of the pacing state of a [`Timer`],
+/// as returned by [`Timer::stats_snapshot`].
+///
+/// Requires the `stats` feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StatsSnapshot {
+    /// index of the most recent frame
+    frame: u64,
+    /// smoothed frametime
+    smoothed_delta: Duration,
+    /// target frametime, zero if uncapped
+    frame_time: Duration,
+    /// smoothed time between frames spent outside the timer
+    work: Duration,
+    /// hitches in the session
+    hitches: u64,
+    /// lag of the filtered deltas in seconds
+    filter_lag: f64,
+}
+
+impl StatsSnapshot {
+    /// index of the most recent frame, see [`crate::FrameInfo::index`]
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// current framerate, based on the smoothed frametime
+    pub fn fps(&self) -> f64 {
+        match self.smoothed_delta {
+            Duration::ZERO => 0.,
+            delta => 1. / delta.as_secs_f64(),
+        }
+    }
+
+    /// smoothed frametime, see [`Timer::smoothed_delta`]
+    pub fn smoothed_delta(&self) -> Duration {
+        self.smoothed_delta
+    }
+
+    /// Share of the target frametime left unused by the application,
+    /// e.g. `0.25` if it works for three quarters of every frame.
+    /// Negative if frames take longer than the target, zero for
+    /// uncapped timers.
+    pub fn headroom(&self) -> f64 {
+        match self.frame_time {
+            Duration::ZERO => 0.,
+            frame_time => 1. - self.work.as_secs_f64() / frame_time.as_secs_f64(),
+        }
+    }
+
+    /// hitches in the session
+    pub fn hitches(&self) -> u64 {
+        self.hitches
+    }
+
+    /// lag of the filtered deltas in seconds, see [`Timer::filter_lag`]
+    pub fn filter_lag(&self) -> f64 {
+        self.filter_lag
+    }
+}
+
+impl Timer {
+    /// Returns a [`StatsSnapshot`] of the current pacing state.
+    ///
+    /// The snapshot only copies a few counters, so it is cheap enough
+    /// to take every frame and send to a UI thread over a channel,
+    /// without sharing the timer or locking its statistics.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// use std::{sync::mpsc, thread};
+    /// use fps_timer::Timer;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let overlay = thread::spawn(move || rx.iter().last().unwrap());
+    /// let mut timer = Timer::default().fps(500.);
+    /// for _ in 0..20 {
+    ///     timer.frame();
+    ///     tx.send(timer.stats_snapshot()).unwrap();
+    /// }
+    /// drop(tx);
+    /// let snapshot = overlay.join().unwrap();
+    /// assert_eq!(snapshot.frame(), 19);
+    /// assert!(snapshot.fps() > 100.);
+    /// assert!(snapshot.headroom() > 0.);
+    /// # }
+    /// ```
+    pub fn stats_snapshot(&self) -> StatsSnapshot {
+        StatsSnapshot {
+            frame: self.last_frame.index,
+            smoothed_delta: self.smoothed_delta,
+            frame_time: self.delta_time,
+            work: self.work_avg,
+            hitches: self.stats.hitches(),
+            filter_lag: self.filter_lag(),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::{pacing_core::fixed_period, Timer};
+
+/// Drift report of [`Timer::soak`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoakReport {
+    /// time from the end of the first to the end of the last frame
+    elapsed: Duration,
+    /// frames completed after the first one
+    frames: u64,
+    /// frames expected at the target rate within `elapsed`
+    expected_frames: f64,
+    /// number of frames that re-based the schedule
+    rebases: u64,
+    /// longest frametime
+    max_frame_time: Duration,
+}
+
+impl SoakReport {
+    /// time from the end of the first to the end of the last frame
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// frames completed after the first one
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// frames expected at the target rate within [`Self::elapsed`]
+    pub fn expected_frames(&self) -> f64 {
+        self.expected_frames
+    }
+
+    /// relative deviation of the frame count from the expected one,
+    /// negative if frames were lost
+    pub fn drift(&self) -> f64 {
+        match self.expected_frames {
+            0. => 0.,
+            expected => (self.frames as f64 - expected) / expected,
+        }
+    }
+
+    /// number of frames that re-based the schedule, each losing frames
+    pub fn rebases(&self) -> u64 {
+        self.rebases
+    }
+
+    /// longest frametime
+    pub fn max_frame_time(&self) -> Duration {
+        self.max_frame_time
+    }
+}
+
+impl Display for SoakReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "soak elapsed={:?} frames={} expected={:.1} drift={:.1}ppm rebases={} max={:?}",
+            self.elapsed,
+            self.frames,
+            self.expected_frames,
+            self.drift() * 1e6,
+            self.rebases,
+            self.max_frame_time,
+        )
+    }
+}
+
+/// A [`Timer::soak`] run whose frame count drifted beyond the tolerance
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SoakFailure {
+    report: SoakReport,
+    /// tolerated relative drift
+    tolerance: f64,
+}
+
+impl SoakFailure {
+    /// report of the failed run
+    pub fn report(&self) -> &SoakReport {
+        &self.report
+    }
+}
+
+impl Display for SoakFailure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame count drifted by more than {:.1}ppm: {}",
+            self.tolerance * 1e6,
+            self.report
+        )
+    }
+}
+
+impl Error for SoakFailure {}
+
+impl Timer {
+    /// Runs frames for `duration` of wall-clock time and checks that the
+    /// number of frames matches the target rate within `tolerance`.
+    ///
+    /// This turns the pacing guarantee of the timer into an acceptance
+    /// test for a platform, e.g. a burn-in run on new hardware or a CI
+    /// runner: a correctly paced timer neither gains nor loses frames
+    /// over any period of time, regardless of oversleeps, as long as it
+    /// never falls behind by more than its slack.
+    ///
+    /// # Arguments
+    /// * `duration` - wall-clock duration of the run
+    /// * `tolerance` - tolerated relative drift of the frame count,
+    ///   e.g. `0.001` for 0.1%
+    ///
+    /// # Errors
+    /// [`SoakFailure`] with the drift report if the frame count
+    /// drifted by more than `tolerance`
+    ///
+    /// # Panics
+    /// if the timer is uncapped
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(500.);
+    /// let report = timer.soak(Duration::from_millis(100), 0.05)?;
+    /// println!("{report}");
+    /// assert!(report.frames() >= 45);
+    /// # Ok::<(), fps_timer::SoakFailure>(())
+    /// ```
+    pub fn soak(&mut self, duration: Duration, tolerance: f64) -> Result<SoakReport, SoakFailure> {
+        assert!(
+            !self.delta_time.is_zero(),
+            "soak test requires a frame rate"
+        );
+        self.frame();
+        let start = self.last_frame.timestamp;
+        let mut report = SoakReport {
+            elapsed: Duration::ZERO,
+            frames: 0,
+            expected_frames: 0.,
+            rebases: 0,
+            max_frame_time: Duration::ZERO,
+        };
+        while report.elapsed < duration {
+            let frame_time = self.frame();
+            report.frames += 1;
+            report.rebases += u64::from(self.last_frame.discontinuity);
+            report.max_frame_time = report.max_frame_time.max(frame_time);
+            report.elapsed = self.last_frame.timestamp.saturating_duration_since(start);
+        }
+        // compare against the exact frametime, not the rounded one
+        let period = match self.schedule.fixed_period() {
+            0 => fixed_period(self.delta_time),
+            period => period,
+        };
+        report.expected_frames = fixed_period(report.elapsed) as f64 / period as f64;
+        match report.drift().abs() <= tolerance {
+            true => Ok(report),
+            false => Err(SoakFailure { report, tolerance }),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//! Drop-in replacement for the `Interval` of the `spin_sleep_util` crate.
+//!
+//! Projects using `spin_sleep_util::interval` can switch to this crate
+//! by changing the import, and then opt into logging and statistics
+//! through [`Interval::timer`]:
+//!
+//! ```rust
+//! // use spin_sleep_util;
+//! use fps_timer::spin_sleep_util;
+//! use std::time::Duration;
+//!
+//! let mut interval = spin_sleep_util::interval(Duration::from_millis(2));
+//! for _ in 0..5 {
+//!     interval.tick();
+//! }
+//! assert_eq!(interval.period(), Duration::from_millis(2));
+//! ```
+//!
+//! Unlike `spin_sleep_util`, missed ticks are caught up with a burst
+//! of up to two ticks, beyond which the schedule is re-based (see
+//! [`Timer::frame`]), so there is no `MissedTickBehavior`.
+
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// Creates an [`Interval`] ticking every `period`, with the first tick
+/// completing immediately.
+///
+/// # Arguments
+/// * `period` - time between ticks
+pub fn interval(period: Duration) -> Interval {
+    Interval {
+        timer: Timer::default().frame_time(period),
+        started: false,
+    }
+}
+
+/// Fixed rate interval, implemented on top of a [`Timer`]
+pub struct Interval {
+    timer: Timer,
+    /// whether the first tick completed
+    started: bool,
+}
+
+impl Interval {
+    /// Waits until the next tick and returns the instant it completed.
+    pub fn tick(&mut self) -> Instant {
+        if !self.started {
+            self.started = true;
+            return self.timer.previous;
+        }
+        self.timer.frame();
+        self.timer.last_frame.timestamp
+    }
+
+    /// time between ticks
+    pub fn period(&self) -> Duration {
+        self.timer.delta_time
+    }
+
+    /// Sets the time between ticks, starting from the previous tick.
+    pub fn set_period(&mut self, period: Duration) {
+        self.timer.set_frame_time(period);
+    }
+
+    /// Sets the time between ticks to `1 / rate` seconds,
+    /// starting from the previous tick.
+    pub fn set_rate(&mut self, rate: f64) {
+        self.timer.set_fps(rate);
+    }
+
+    /// The underlying timer, e.g. to read its statistics or
+    /// to attach log sinks.
+    pub fn timer(&mut self) -> &mut Timer {
+        &mut self.timer
+    }
+}
@@ -0,0 +1,307 @@
+use std::{collections::VecDeque, time::Duration};
+
+use crate::pacing_core::{FrameTimeStats, Sorted, STATS_WORDS};
+
+/// maximum number of frametimes kept for a single logging interval
+const MAX_INTERVAL_SAMPLES: usize = 1 << 16;
+
+/// histogram buckets per doubling of the frametime
+const BUCKETS_PER_OCTAVE: f64 = 8.;
+/// number of histogram buckets, covering 1µs to ~2^28µs
+const BUCKETS: usize = 28 * BUCKETS_PER_OCTAVE as usize;
+
+/// Time spent waiting for frame targets, split into
+/// sleeping (thread suspended) and spinning (busy waiting)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct WaitTime {
+    /// time the thread was suspended
+    pub(crate) sleeping: Duration,
+    /// time spent in the busy spin loop
+    pub(crate) spinning: Duration,
+}
+
+impl WaitTime {
+    /// time the thread was suspended
+    pub fn sleeping(&self) -> Duration {
+        self.sleeping
+    }
+
+    /// time spent busy-spinning
+    pub fn spinning(&self) -> Duration {
+        self.spinning
+    }
+
+    /// fraction of the waiting time spent busy-spinning
+    pub fn spin_ratio(&self) -> f64 {
+        let total = self.sleeping + self.spinning;
+        match total.is_zero() {
+            true => 0.,
+            false => self.spinning.as_secs_f64() / total.as_secs_f64(),
+        }
+    }
+
+    /// Rough estimate of the energy in joules spent spinning,
+    /// given the power draw of a fully busy core in watts.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    /// let mut timer = Timer::default().fps(500.).high_precision(true);
+    /// for _ in 0..5 {
+    ///     timer.frame();
+    /// }
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// let wait_time = timer.session_summary().wait_time();
+    /// println!("spent ~{:.3}J spinning", wait_time.estimated_spin_energy(10.));
+    /// # }
+    /// ```
+    pub fn estimated_spin_energy(&self, core_watts: f64) -> f64 {
+        self.spinning.as_secs_f64() * core_watts
+    }
+
+    pub(crate) fn add(&mut self, other: WaitTime) {
+        self.sleeping += other.sleeping;
+        self.spinning += other.spinning;
+    }
+}
+
+/// number of words of [`SessionSummary::to_words`]
+pub(crate) const SUMMARY_WORDS: usize = STATS_WORDS + 3;
+
+/// Summary of a whole session, as returned by [`crate::Timer::session_summary`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SessionSummary {
+    /// total time covered by the session
+    elapsed: Duration,
+    /// frametime statistics over the whole session
+    stats: FrameTimeStats,
+    /// time spent waiting over the whole session
+    wait_time: WaitTime,
+}
+
+impl SessionSummary {
+    /// total time covered by all frames of the session
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// fps averaged over the whole session
+    pub fn fps_average(&self) -> f64 {
+        self.stats.frames() as f64 / self.elapsed.as_secs_f64()
+    }
+
+    /// frametime statistics over the whole session
+    ///
+    /// Percentiles are approximated to within ~9%.
+    pub fn frame_time_stats(&self) -> &FrameTimeStats {
+        &self.stats
+    }
+
+    /// stability score of the whole session,
+    /// see [`FrameTimeStats::stability_score`]
+    pub fn stability_score(&self) -> f64 {
+        self.stats.stability_score()
+    }
+
+    /// time spent sleeping and spinning over the whole session
+    pub fn wait_time(&self) -> WaitTime {
+        self.wait_time
+    }
+
+    /// the summary as plain words, e.g. to publish it through atomics,
+    /// see [`Self::from_words`]
+    pub(crate) fn to_words(self) -> [u64; SUMMARY_WORDS] {
+        let nanos = |d: Duration| u64::try_from(d.as_nanos()).unwrap_or(u64::MAX);
+        let mut words = [0; SUMMARY_WORDS];
+        words[..STATS_WORDS].copy_from_slice(&self.stats.to_words());
+        words[STATS_WORDS] = nanos(self.elapsed);
+        words[STATS_WORDS + 1] = nanos(self.wait_time.sleeping);
+        words[STATS_WORDS + 2] = nanos(self.wait_time.spinning);
+        words
+    }
+
+    /// summary from words written by [`Self::to_words`]
+    pub(crate) fn from_words(words: [u64; SUMMARY_WORDS]) -> Self {
+        let mut stats = [0; STATS_WORDS];
+        stats.copy_from_slice(&words[..STATS_WORDS]);
+        Self {
+            elapsed: Duration::from_nanos(words[STATS_WORDS]),
+            stats: FrameTimeStats::from_words(stats),
+            wait_time: WaitTime {
+                sleeping: Duration::from_nanos(words[STATS_WORDS + 1]),
+                spinning: Duration::from_nanos(words[STATS_WORDS + 2]),
+            },
+        }
+    }
+}
+
+/// frametime statistics collected by the timer
+#[derive(Clone, Debug)]
+pub(crate) struct FrameStats {
+    /// frametimes of the current logging interval
+    interval: VecDeque<Duration>,
+    /// hitch threshold of the most recent frame
+    hitch_threshold: Duration,
+    /// logarithmic frametime histogram of the session
+    histogram: Vec<u64>,
+    /// number of frames in the session
+    frames: u64,
+    /// total frametime of the session
+    elapsed: Duration,
+    /// sum of absolute frametime differences in the session
+    jitter: Duration,
+    /// hitches in the session
+    hitches: u64,
+    /// shortest frametime of the session
+    min: Duration,
+    /// longest frametime of the session
+    max: Duration,
+    /// previous frametime
+    previous: Option<Duration>,
+    /// time spent waiting in the current interval
+    interval_wait: WaitTime,
+    /// time spent waiting in the session
+    session_wait: WaitTime,
+    /// whether frametimes are kept for logging intervals
+    intervals: bool,
+}
+
+impl Default for FrameStats {
+    fn default() -> Self {
+        Self {
+            interval: VecDeque::new(),
+            hitch_threshold: Duration::MAX,
+            histogram: vec![0; BUCKETS],
+            frames: 0,
+            elapsed: Duration::ZERO,
+            jitter: Duration::ZERO,
+            hitches: 0,
+            min: Duration::MAX,
+            max: Duration::ZERO,
+            previous: None,
+            interval_wait: WaitTime::default(),
+            session_wait: WaitTime::default(),
+            intervals: true,
+        }
+    }
+}
+
+fn bucket(frame_time: Duration) -> usize {
+    let micros = frame_time.as_secs_f64() * 1e6;
+    let bucket = (micros.max(1.).log2() * BUCKETS_PER_OCTAVE) as usize;
+    bucket.min(BUCKETS - 1)
+}
+
+fn bucket_upper_bound(bucket: usize) -> Duration {
+    let micros = 2f64.powf((bucket + 1) as f64 / BUCKETS_PER_OCTAVE);
+    Duration::from_secs_f64(micros / 1e6)
+}
+
+impl FrameStats {
+    /// statistics of a whole session only, without logging intervals
+    pub(crate) fn session_only() -> Self {
+        Self {
+            intervals: false,
+            ..Self::default()
+        }
+    }
+
+    /// discards all recorded frametimes
+    pub(crate) fn reset(&mut self) {
+        *self = Self {
+            intervals: self.intervals,
+            ..Self::default()
+        };
+    }
+
+    /// records a frametime, counting it as hitch if it exceeds `hitch_threshold`
+    pub(crate) fn record(&mut self, frame_time: Duration, hitch_threshold: Duration) {
+        if self.intervals {
+            if self.interval.len() == MAX_INTERVAL_SAMPLES {
+                self.interval.pop_front();
+            }
+            self.interval.push_back(frame_time);
+        }
+
+        if let Some(previous) = self.previous {
+            let diff = frame_time.abs_diff(previous);
+            self.jitter += diff;
+        }
+        self.previous = Some(frame_time);
+
+        self.hitch_threshold = hitch_threshold;
+        if frame_time > hitch_threshold {
+            self.hitches += 1;
+        }
+
+        self.histogram[bucket(frame_time)] += 1;
+        self.frames += 1;
+        self.elapsed += frame_time;
+        self.min = self.min.min(frame_time);
+        self.max = self.max.max(frame_time);
+    }
+
+    /// hitches in the session
+    pub(crate) fn hitches(&self) -> u64 {
+        self.hitches
+    }
+
+    /// records the time spent waiting for a frame
+    pub(crate) fn record_wait(&mut self, spent: WaitTime) {
+        self.interval_wait.add(spent);
+        self.session_wait.add(spent);
+    }
+
+    /// time spent waiting in the current logging interval, starting a new one
+    pub(crate) fn take_interval_wait(&mut self) -> WaitTime {
+        std::mem::take(&mut self.interval_wait)
+    }
+
+    /// Statistics of the current logging interval, starting a new one.
+    /// Hitches are counted with the hitch threshold of the most recent
+    /// frame.
+    pub(crate) fn take_interval(&mut self) -> FrameTimeStats {
+        let mut samples: Vec<Duration> = self.interval.drain(..).collect();
+        FrameTimeStats::from_frame_times(&mut samples, self.hitch_threshold)
+    }
+
+    /// approximate frametime percentile of the session
+    fn session_percentile(&self, p: f64) -> Duration {
+        let rank = ((self.frames as f64 * p).ceil() as u64).max(1);
+        let mut count = 0;
+        for (bucket, n) in self.histogram.iter().enumerate() {
+            count += n;
+            if count >= rank {
+                return bucket_upper_bound(bucket).clamp(self.min, self.max);
+            }
+        }
+        self.max
+    }
+
+    /// summary of the whole session
+    pub(crate) fn session(&self) -> SessionSummary {
+        let stats = match self.frames {
+            0 => FrameTimeStats::default(),
+            frames => {
+                let sorted = Sorted {
+                    min: self.min,
+                    max: self.max,
+                    median: self.session_percentile(0.5),
+                    p99: self.session_percentile(0.99),
+                };
+                let mean = self.elapsed.div_f64(frames as f64);
+                let jitter = match frames {
+                    1 => Duration::ZERO,
+                    frames => self.jitter.div_f64((frames - 1) as f64),
+                };
+                FrameTimeStats::new(frames, sorted, jitter, mean, self.hitches)
+            }
+        };
+        SessionSummary {
+            elapsed: self.elapsed,
+            stats,
+            wait_time: self.session_wait,
+        }
+    }
+}
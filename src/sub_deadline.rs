@@ -0,0 +1,87 @@
+use std::time::{Duration, Instant};
+
+use crate::{wait_until_on, Clock, Timer, WaitTime};
+
+/// A point in time within the current frame,
+/// as returned by [`Timer::sub_deadline`].
+///
+/// Waiting on a sub-deadline uses the same clock and sleep / spin
+/// strategy as the timer it was created from, so phases of a frame
+/// can be handed off at enforced times.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubDeadline {
+    /// the deadline
+    at: Instant,
+    /// maximum busy wait, [`None`] to only sleep
+    spin_margin: Option<Duration>,
+    /// clock the deadline is measured against
+    clock: Clock,
+}
+
+impl SubDeadline {
+    /// the deadline
+    pub fn instant(&self) -> Instant {
+        self.at
+    }
+
+    /// time left until the deadline
+    pub fn remaining(&self) -> Duration {
+        self.at.saturating_duration_since(self.clock.now())
+    }
+
+    /// `true` once the deadline has passed
+    pub fn is_passed(&self) -> bool {
+        self.clock.now() >= self.at
+    }
+
+    /// Waits until the deadline, returning immediately
+    /// if it has already passed.
+    ///
+    /// # Returns
+    /// the time the wait ended
+    pub fn wait(&self) -> Instant {
+        let mut spent = WaitTime::default();
+        wait_until_on(
+            self.at,
+            self.spin_margin,
+            &mut spent,
+            self.clock.reader(),
+            None,
+        )
+    }
+}
+
+impl Timer {
+    /// Creates a deadline at `fraction` of the current frame, e.g. `0.5`
+    /// for the halfway point between the end of the last frame and the
+    /// target of the next one.
+    ///
+    /// Useful to split a frame into phases, e.g. simulation and rendering,
+    /// with enforced handoff times.
+    ///
+    /// # Arguments
+    /// * `fraction` - position within the frame in `[0, 1]`
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// timer.frame();
+    /// let handoff = timer.sub_deadline(0.5);
+    /// // simulate ...
+    /// handoff.wait();
+    /// assert!(handoff.is_passed());
+    /// // render ...
+    /// assert!(handoff.instant() < timer.sub_deadline(1.).instant());
+    /// timer.frame();
+    /// ```
+    pub fn sub_deadline(&self, fraction: f64) -> SubDeadline {
+        let frame = self.target.saturating_duration_since(self.previous);
+        SubDeadline {
+            at: self.previous + frame.mul_f64(fraction.clamp(0., 1.)),
+            spin_margin: self.high_precision.then_some(self.effective_spin_margin()),
+            clock: self.clock,
+        }
+    }
+}
@@ -0,0 +1,233 @@
+use std::time::{Duration, Instant};
+
+use crate::{pacing_core::Schedule, Timer};
+
+/// maximum swap interval in refresh periods
+const MAX_SWAP_INTERVAL: u32 = 3;
+/// consecutive frames a shorter swap interval has to fit
+/// before switching to it
+const DOWNSHIFT_FRAMES: u32 = 20;
+
+/// state of the swap interval pacing policy
+#[derive(Clone, Debug)]
+pub(crate) struct SwapPacing {
+    /// refresh period of the display
+    refresh: Duration,
+    /// frametime requested by the application
+    requested: Duration,
+    /// peak-hold estimate of the cpu frame duration
+    cpu: Duration,
+    /// peak-hold estimate of the gpu frame duration
+    gpu: Duration,
+    /// current swap interval in refresh periods
+    interval: u32,
+    /// consecutive frames that would have fit a shorter interval
+    fitting_frames: u32,
+}
+
+/// peak-hold smoothing: rises immediately, decays slowly
+fn peak_hold(estimate: Duration, sample: Duration) -> Duration {
+    sample.max(estimate * 15 / 16 + sample / 16)
+}
+
+impl SwapPacing {
+    /// smallest swap interval fitting `duration`
+    fn interval_for(&self, duration: Duration) -> u32 {
+        let periods = duration.as_nanos().div_ceil(self.refresh.as_nanos().max(1));
+        u32::try_from(periods)
+            .unwrap_or(u32::MAX)
+            .clamp(1, MAX_SWAP_INTERVAL)
+    }
+
+    /// updates the estimates with the cpu time of a frame and
+    /// returns the frametime to pace the next frame with
+    fn update(&mut self, cpu: Duration) -> Duration {
+        self.cpu = peak_hold(self.cpu, cpu);
+        let predicted = self.cpu.max(self.gpu);
+        let needed = self.interval_for(self.requested.max(predicted));
+
+        if needed > self.interval {
+            // switch to a longer interval immediately to avoid stutter
+            self.interval = needed;
+            self.fitting_frames = 0;
+        } else if needed < self.interval {
+            // only switch to a shorter interval if it fits with margin
+            let margin = self.interval_for(predicted.saturating_add(predicted / 10));
+            if margin < self.interval {
+                self.fitting_frames += 1;
+            } else {
+                self.fitting_frames = 0;
+            }
+            if self.fitting_frames >= DOWNSHIFT_FRAMES {
+                self.interval -= 1;
+                self.fitting_frames = 0;
+            }
+        } else {
+            self.fitting_frames = 0;
+        }
+        self.refresh.saturating_mul(self.interval)
+    }
+}
+
+impl Timer {
+    /// Enables swap interval pacing for a display with the given
+    /// refresh period, modeled after Android's Frame Pacing library (Swappy).
+    ///
+    /// Instead of pacing at the requested frametime directly, the timer
+    /// predicts the cpu and gpu duration of upcoming frames and picks
+    /// a swap interval of 1, 2 or 3 refresh periods that the workload
+    /// (and the requested frametime) fits into. Frames are paced at that
+    /// interval, so presentation cadence stays even when the workload
+    /// fluctuates, instead of alternating between on-time and late frames.
+    /// Longer intervals are chosen immediately, shorter ones only after
+    /// the workload consistently fits them.
+    ///
+    /// gpu durations are reported through [`Self::present_feedback`].
+    ///
+    /// # Arguments
+    /// * `refresh_period` - refresh period of the display
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use fps_timer::Timer;
+    /// let refresh = Duration::from_secs_f64(1. / 60.);
+    /// let mut timer = Timer::default()
+    ///     .fps(60.)
+    ///     .swap_pacing(refresh);
+    /// assert_eq!(timer.swap_interval(), Some(1));
+    ///
+    /// // a frame taking 20ms does not fit a single refresh period
+    /// timer.frame_at(Instant::now() + Duration::from_millis(20));
+    /// assert_eq!(timer.swap_interval(), Some(2));
+    ///
+    /// // 50 fps do not fit a 60Hz refresh period, so frames
+    /// // are paced at every other refresh
+    /// let mut timer = Timer::default().fps(50.).swap_pacing(refresh);
+    /// let start = Instant::now();
+    /// for _ in 0..7 {
+    ///     timer.frame();
+    /// }
+    /// assert!(start.elapsed() >= refresh * 2 * 6);
+    /// ```
+    ///
+    /// Frame intervals follow the swap interval exactly, also when
+    /// it changes:
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{Clock, Timer};
+    ///
+    /// let refresh = Duration::from_secs_f64(1. / 60.);
+    /// let mut timer = Timer::default()
+    ///     .fps(50.)
+    ///     .swap_pacing(refresh)
+    ///     .clock(Clock::Synthetic);
+    /// let epoch = timer.timebase_epoch();
+    /// timer.frame_at(epoch);
+    /// for _ in 0..3 {
+    ///     assert_eq!(timer.frame_at(epoch), refresh * 2);
+    /// }
+    /// // a frame taking 40ms needs three refresh periods
+    /// let slow = timer.frame_info().timestamp() + Duration::from_millis(40);
+    /// assert_eq!(timer.frame_at(slow), refresh * 3);
+    /// assert_eq!(timer.frame_at(epoch), refresh * 3);
+    /// assert_eq!(timer.swap_interval(), Some(3));
+    /// ```
+    pub fn swap_pacing(mut self, refresh_period: Duration) -> Self {
+        self.swap_pacing = Some(SwapPacing {
+            refresh: refresh_period,
+            requested: self.delta_time,
+            cpu: Duration::ZERO,
+            gpu: Duration::ZERO,
+            interval: 1,
+            fitting_frames: 0,
+        });
+        // picks the swap interval for the requested frametime
+        self.set_frame_time(self.delta_time);
+        self
+    }
+
+    /// Reports the gpu duration of the most recently presented frame
+    /// and, if known, the instant it was presented at.
+    ///
+    /// With [`Self::swap_pacing`], the gpu duration is used to predict
+    /// upcoming frames and the presentation time aligns the frame
+    /// schedule to the refresh cycle of the display, offset by the
+    /// [`Self::phase`] of the timer.
+    /// With [`Self::swapchain`], every reported presentation removes
+    /// a frame from the presentation queue.
+    ///
+    /// # Arguments
+    /// * `gpu_time` - gpu duration of the frame (zero if unknown)
+    /// * `presented` - instant the frame was presented at
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{Clock, Timer};
+    ///
+    /// let refresh = Duration::from_millis(10);
+    /// let phase = Duration::from_millis(3);
+    /// let mut timer = Timer::default()
+    ///     .fps(100.)
+    ///     .swap_pacing(refresh)
+    ///     .phase(phase)
+    ///     .clock(Clock::Synthetic);
+    /// let epoch = timer.timebase_epoch();
+    /// timer.frame_at(epoch);
+    /// let presented = epoch + Duration::from_millis(7);
+    /// timer.present_feedback(Duration::ZERO, Some(presented));
+    ///
+    /// // targets follow the presentation, offset by the phase
+    /// let timer = timer.fps(100.);
+    /// let offset = timer.next_target() - presented;
+    /// assert_eq!(offset.as_nanos() % refresh.as_nanos(), phase.as_nanos());
+    /// ```
+    pub fn present_feedback(&mut self, gpu_time: Duration, presented: Option<Instant>) {
+        if let Some(presented) = presented {
+            self.swapchain_presented(presented);
+        }
+        if let Some(pacing) = &mut self.swap_pacing {
+            pacing.gpu = peak_hold(pacing.gpu, gpu_time);
+            if let Some(presented) = presented {
+                self.anchor = Some(presented + self.phase);
+            }
+        }
+    }
+
+    /// The current swap interval in refresh periods
+    /// if [`Self::swap_pacing`] is enabled
+    pub fn swap_interval(&self) -> Option<u32> {
+        self.swap_pacing.as_ref().map(|pacing| pacing.interval)
+    }
+
+    /// updates the swap interval with the cpu time of the current frame
+    pub(crate) fn update_swap_pacing(&mut self, cpu: Duration) {
+        if let Some(pacing) = &mut self.swap_pacing {
+            let delta = pacing.update(cpu);
+            if delta != self.delta_time {
+                // reschedule the pending frame with the new interval
+                let pending = self.target.checked_sub(self.delta_time);
+                self.target = pending.unwrap_or(self.target) + delta;
+                self.delta_time = delta;
+                self.schedule = Schedule::new(delta);
+                self.restart_schedule();
+            }
+        }
+    }
+
+    /// updates the requested frametime of the swap interval pacing
+    pub(crate) fn request_swap_frame_time(&mut self, delta: Duration) -> Duration {
+        match &mut self.swap_pacing {
+            Some(pacing) => {
+                pacing.requested = delta;
+                pacing.interval = pacing.interval_for(delta);
+                pacing.refresh.saturating_mul(pacing.interval)
+            }
+            None => delta,
+        }
+    }
+}
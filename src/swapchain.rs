@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use crate::{Timer, MAX_INTERVAL};
+
+/// Queueing policy for swapchain aware scheduling,
+/// see [`Timer::swapchain`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueueMode {
+    /// start cpu frames late enough that at most one frame
+    /// is queued for presentation, minimizing latency
+    LowLatency,
+    /// start cpu frames as early as needed to keep the
+    /// swapchain full, maximizing throughput
+    Throughput,
+}
+
+/// swapchain state tracked by the timer
+#[derive(Clone, Debug)]
+pub(crate) struct Swapchain {
+    /// number of images in the swapchain
+    depth: u32,
+    /// queueing policy
+    mode: QueueMode,
+    /// frames submitted (i.e. calls to frame())
+    submitted: u64,
+    /// frames presented
+    presented: u64,
+    /// instant the last frame was presented at
+    last_present: Option<Instant>,
+}
+
+impl Swapchain {
+    /// number of frames submitted but not yet presented
+    fn queued(&self) -> u64 {
+        self.submitted.saturating_sub(self.presented)
+    }
+
+    /// instant the frame starting at `current` should wait for,
+    /// given the regular frame `target`
+    fn wait_target(&self, target: Instant, current: Instant, delta: Duration) -> Instant {
+        let queued = self.queued();
+        match self.mode {
+            QueueMode::LowLatency => match self.last_present {
+                // wait until the queue is expected to drain to one frame
+                Some(last_present) if queued > 1 => {
+                    let ahead = u32::try_from(queued - 1).unwrap_or(u32::MAX);
+                    let drained = last_present + delta.saturating_mul(ahead).min(MAX_INTERVAL);
+                    target.max(drained)
+                }
+                _ => target,
+            },
+            // render ahead without waiting until the queue is full
+            QueueMode::Throughput if queued + 1 < self.depth as u64 => current,
+            QueueMode::Throughput => target,
+        }
+    }
+}
+
+impl Timer {
+    /// Makes the timer aware of a swapchain with `depth` images.
+    ///
+    /// Depending on `mode`, cpu frame starts are paced to keep at most
+    /// one frame queued for presentation ([`QueueMode::LowLatency`]) or
+    /// to keep the swapchain full ([`QueueMode::Throughput`]).
+    ///
+    /// The timer counts every call to [`Self::frame`] as a submitted frame.
+    /// Presented frames have to be reported through
+    /// [`Self::present_feedback`] with the instant of presentation.
+    ///
+    /// # Arguments
+    /// * `depth` - number of images in the swapchain, e.g. 3 for triple buffering
+    /// * `mode` - queueing policy
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{QueueMode, Timer};
+    /// let mut timer = Timer::default()
+    ///     .fps(60.)
+    ///     .swapchain(3, QueueMode::LowLatency);
+    /// ```
+    pub fn swapchain(mut self, depth: u32, mode: QueueMode) -> Self {
+        self.swapchain = Some(Swapchain {
+            depth,
+            mode,
+            submitted: 0,
+            presented: 0,
+            last_present: None,
+        });
+        self
+    }
+
+    /// number of frames submitted but not yet presented, if
+    /// [`Self::swapchain`] is configured
+    pub fn queued_frames(&self) -> Option<u64> {
+        self.swapchain.as_ref().map(Swapchain::queued)
+    }
+
+    /// counts a presented frame
+    pub(crate) fn swapchain_presented(&mut self, presented: Instant) {
+        if let Some(swapchain) = &mut self.swapchain {
+            swapchain.presented += 1;
+            swapchain.last_present = Some(presented);
+        }
+    }
+
+    /// counts a submitted frame
+    pub(crate) fn swapchain_submitted(&mut self) {
+        if let Some(swapchain) = &mut self.swapchain {
+            swapchain.submitted += 1;
+        }
+    }
+
+    /// instant the frame starting at `current` has to wait for
+    pub(crate) fn swapchain_wait_target(&self, current: Instant) -> Instant {
+        match &self.swapchain {
+            Some(swapchain) => swapchain.wait_target(self.target, current, self.delta_time),
+            None => self.target,
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use std::time::Duration;
+
+use crate::{stats::FrameStats, SessionSummary, Timer, WaitTime};
+
+/// statistics per user-defined tag
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Tags {
+    /// statistics of every tag ever pushed, in order of first use
+    stats: Vec<(String, FrameStats)>,
+    /// indices into `stats` of the currently active tags
+    active: Vec<usize>,
+}
+
+impl Tags {
+    /// discards the statistics of all tags, keeping the active tags
+    pub(crate) fn reset(&mut self) {
+        for (_, stats) in &mut self.stats {
+            stats.reset();
+        }
+    }
+
+    /// records a frametime for all active tags
+    pub(crate) fn record(&mut self, frame_time: Duration, hitch_threshold: Duration) {
+        for (i, (_, stats)) in self.stats.iter_mut().enumerate() {
+            if self.active.contains(&i) {
+                stats.record(frame_time, hitch_threshold);
+            }
+        }
+    }
+
+    /// records a frametime for the tag `name`, whether active or not
+    pub(crate) fn record_named(
+        &mut self,
+        name: &str,
+        frame_time: Duration,
+        hitch_threshold: Duration,
+    ) {
+        let index = self.index(name);
+        self.stats[index].1.record(frame_time, hitch_threshold);
+    }
+
+    /// index of the tag `name`, creating it if it was never used
+    fn index(&mut self, name: &str) -> usize {
+        match self.stats.iter().position(|(tag, _)| tag == name) {
+            Some(index) => index,
+            None => {
+                self.stats
+                    .push((name.to_owned(), FrameStats::session_only()));
+                self.stats.len() - 1
+            }
+        }
+    }
+
+    /// records the time spent waiting for all active tags
+    pub(crate) fn record_wait(&mut self, spent: WaitTime) {
+        for (i, (_, stats)) in self.stats.iter_mut().enumerate() {
+            if self.active.contains(&i) {
+                stats.record_wait(spent);
+            }
+        }
+    }
+}
+
+impl Timer {
+    /// Starts attributing frames to the tag `tag`, e.g. the current
+    /// level, scene or benchmark phase, until the matching
+    /// [`Self::pop_tag`].
+    ///
+    /// Tags nest: while several tags are active, frames count towards
+    /// each of them. Pushing a tag again later continues its statistics,
+    /// see [`Self::tag_summary`].
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(1000.);
+    /// timer.push_tag("benchmark");
+    /// for level in ["level_1", "level_2"] {
+    ///     timer.push_tag(level);
+    ///     for _ in 0..5 {
+    ///         timer.frame();
+    ///     }
+    ///     timer.pop_tag();
+    /// }
+    /// assert_eq!(timer.pop_tag().as_deref(), Some("benchmark"));
+    ///
+    /// let level_1 = timer.tag_summary("level_1").unwrap();
+    /// assert_eq!(level_1.frame_time_stats().frames(), 5);
+    /// let benchmark = timer.tag_summary("benchmark").unwrap();
+    /// assert_eq!(benchmark.frame_time_stats().frames(), 10);
+    /// assert_eq!(timer.tag_summaries().len(), 3);
+    /// # }
+    /// ```
+    pub fn push_tag(&mut self, tag: impl Into<String>) {
+        let index = self.tags.index(&tag.into());
+        self.tags.active.push(index);
+    }
+
+    /// Stops attributing frames to the most recently pushed tag,
+    /// returning it.
+    ///
+    /// Requires the `stats` feature.
+    pub fn pop_tag(&mut self) -> Option<String> {
+        let index = self.tags.active.pop()?;
+        Some(self.tags.stats[index].0.clone())
+    }
+
+    /// Summary of all frames while `tag` was active,
+    /// [`None`] if it was never pushed.
+    ///
+    /// Requires the `stats` feature.
+    pub fn tag_summary(&self, tag: &str) -> Option<SessionSummary> {
+        self.tags
+            .stats
+            .iter()
+            .find(|(name, _)| name == tag)
+            .map(|(_, stats)| stats.session())
+    }
+
+    /// Summaries of all tags in the order they were first pushed,
+    /// e.g. to compare phases of a session.
+    ///
+    /// Requires the `stats` feature.
+    pub fn tag_summaries(&self) -> Vec<(&str, SessionSummary)> {
+        self.tags
+            .stats
+            .iter()
+            .map(|(name, stats)| (name.as_str(), stats.session()))
+            .collect()
+    }
+}
@@ -0,0 +1,373 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::Timer;
+
+/// Summary of a single session of an application, as stored in a
+/// [`SessionStore`] and returned by [`Timer::session_record`].
+///
+/// The [`Display`] implementation writes the record as a single line
+/// of `key=value` pairs with times in nanoseconds, the format of
+/// [`SessionStore`] files.
+///
+/// Requires the `stats` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionRecord {
+    /// wall-clock time the record was taken
+    time: SystemTime,
+    /// build of the application, e.g. a version or commit
+    build: String,
+    /// hardware the session ran on, e.g. a CPU or GPU model
+    hardware: String,
+    /// frames of the session
+    frames: u64,
+    /// time covered by the frames of the session
+    elapsed: Duration,
+    /// median frametime
+    median: Duration,
+    /// 99th percentile frametime
+    p99: Duration,
+    /// hitches in the session
+    hitches: u64,
+    /// stability score of the session
+    stability: f64,
+}
+
+impl SessionRecord {
+    /// wall-clock time the record was taken
+    pub fn time(&self) -> SystemTime {
+        self.time
+    }
+
+    /// build of the application
+    pub fn build(&self) -> &str {
+        &self.build
+    }
+
+    /// hardware the session ran on
+    pub fn hardware(&self) -> &str {
+        &self.hardware
+    }
+
+    /// frames of the session
+    pub fn frames(&self) -> u64 {
+        self.frames
+    }
+
+    /// time covered by the frames of the session
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// fps averaged over the whole session
+    pub fn fps_average(&self) -> f64 {
+        match self.elapsed {
+            Duration::ZERO => 0.,
+            elapsed => self.frames as f64 / elapsed.as_secs_f64(),
+        }
+    }
+
+    /// median frametime of the session
+    pub fn median(&self) -> Duration {
+        self.median
+    }
+
+    /// 99th percentile frametime of the session
+    pub fn p99(&self) -> Duration {
+        self.p99
+    }
+
+    /// hitches in the session
+    pub fn hitches(&self) -> u64 {
+        self.hitches
+    }
+
+    /// stability score of the session,
+    /// see [`crate::FrameTimeStats::stability_score`]
+    pub fn stability_score(&self) -> f64 {
+        self.stability
+    }
+}
+
+impl Display for SessionRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let time = self.time.duration_since(UNIX_EPOCH).unwrap_or_default();
+        write!(
+            f,
+            "session time={} build={} hardware={} frames={} elapsed={} median={} p99={} hitches={} stability={}",
+            time.as_millis(),
+            self.build,
+            self.hardware,
+            self.frames,
+            self.elapsed.as_nanos(),
+            self.median.as_nanos(),
+            self.p99.as_nanos(),
+            self.hitches,
+            self.stability,
+        )
+    }
+}
+
+/// replaces characters that would break the line format of a record
+fn sanitize(value: &str) -> String {
+    match value.is_empty() {
+        true => "-".to_owned(),
+        false => value
+            .chars()
+            .map(|c| match c.is_whitespace() || c == '=' {
+                true => '_',
+                false => c,
+            })
+            .collect(),
+    }
+}
+
+/// parses a line written by the [`Display`] implementation of [`SessionRecord`]
+fn parse_record(line: &str) -> Option<SessionRecord> {
+    let mut fields = line
+        .strip_prefix("session ")?
+        .split(' ')
+        .map(|field| field.split_once('='));
+    let mut next = |key: &str| {
+        fields
+            .next()
+            .flatten()
+            .filter(|(k, _)| *k == key)
+            .map(|(_, v)| v)
+    };
+    let nanos = |v: &str| v.parse().ok().map(Duration::from_nanos);
+    Some(SessionRecord {
+        time: UNIX_EPOCH + Duration::from_millis(next("time")?.parse().ok()?),
+        build: next("build")?.to_owned(),
+        hardware: next("hardware")?.to_owned(),
+        frames: next("frames")?.parse().ok()?,
+        elapsed: nanos(next("elapsed")?)?,
+        median: nanos(next("median")?)?,
+        p99: nanos(next("p99")?)?,
+        hitches: next("hitches")?.parse().ok()?,
+        stability: next("stability")?.parse().ok()?,
+    })
+}
+
+/// Append-only file of [`SessionRecord`]s, one line per session,
+/// to track the pacing health of an application across sessions,
+/// builds and machines.
+///
+/// Requires the `stats` feature.
+///
+/// # Example
+/// ```rust
+/// # #[cfg(feature = "stats")]
+/// # {
+/// use fps_timer::{SessionStore, Timer};
+///
+/// # let dir = std::env::temp_dir().join(format!("fps-timer-telemetry-{}", std::process::id()));
+/// # std::fs::create_dir_all(&dir).unwrap();
+/// let store = SessionStore::new(dir.join("sessions.log"));
+/// for build in ["1.0.0", "1.1.0"] {
+///     let mut timer = Timer::default().fps(1000.);
+///     for _ in 0..20 {
+///         timer.frame();
+///     }
+///     store.append(&timer.session_record(build, "Example GPU"))?;
+/// }
+///
+/// let history = store.load()?;
+/// assert_eq!(history.records().len(), 2);
+/// let builds = history.by_build();
+/// assert_eq!(builds[0].key(), "1.0.0");
+/// assert_eq!(builds[1].key(), "1.1.0");
+/// let hardware = history.by_hardware();
+/// assert_eq!(hardware[0].key(), "Example_GPU");
+/// assert_eq!(hardware[0].sessions(), 2);
+/// assert!(hardware[0].median_fps() > 100.);
+/// # std::fs::remove_dir_all(&dir)?;
+/// # }
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionStore {
+    /// path of the file
+    path: PathBuf,
+}
+
+impl SessionStore {
+    /// Creates a store backed by the file at `path`,
+    /// which is created by the first [`Self::append`].
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_owned(),
+        }
+    }
+
+    /// path of the file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Appends `record` to the file, creating it if it doesn't exist.
+    pub fn append(&self, record: &SessionRecord) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{record}")
+    }
+
+    /// Loads all records of the file in the order they were appended.
+    ///
+    /// Malformed lines, e.g. a line cut short by a crash while
+    /// appending, are skipped. A missing file yields no records.
+    pub fn load(&self) -> io::Result<SessionHistory> {
+        let contents = match fs::read_to_string(&self.path) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            contents => contents?,
+        };
+        let records = contents.lines().filter_map(parse_record).collect();
+        Ok(SessionHistory { records })
+    }
+}
+
+/// [`SessionRecord`]s loaded from a [`SessionStore`]
+///
+/// Requires the `stats` feature.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SessionHistory {
+    /// records in the order they were appended
+    records: Vec<SessionRecord>,
+}
+
+impl SessionHistory {
+    /// records in the order they were appended
+    pub fn records(&self) -> &[SessionRecord] {
+        &self.records
+    }
+
+    /// Aggregates the records per hardware,
+    /// in the order each hardware first appeared.
+    pub fn by_hardware(&self) -> Vec<SessionAggregate> {
+        self.aggregate_by(SessionRecord::hardware)
+    }
+
+    /// Aggregates the records per build, in the order each build
+    /// first appeared, i.e. the trend over builds if builds are
+    /// rolled out one after another.
+    pub fn by_build(&self) -> Vec<SessionAggregate> {
+        self.aggregate_by(SessionRecord::build)
+    }
+
+    /// Aggregates the records per `key`,
+    /// in the order each key first appeared.
+    pub fn aggregate_by(&self, key: impl Fn(&SessionRecord) -> &str) -> Vec<SessionAggregate> {
+        let mut keys: Vec<&str> = vec![];
+        for record in &self.records {
+            if !keys.contains(&key(record)) {
+                keys.push(key(record));
+            }
+        }
+        keys.into_iter()
+            .map(|k| {
+                let records: Vec<_> = self.records.iter().filter(|r| key(r) == k).collect();
+                SessionAggregate::new(k, &records)
+            })
+            .collect()
+    }
+}
+
+/// Aggregate of the [`SessionRecord`]s sharing a key,
+/// see [`SessionHistory::aggregate_by`]
+///
+/// Requires the `stats` feature.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SessionAggregate {
+    /// shared key, e.g. a build or hardware
+    key: String,
+    /// number of sessions
+    sessions: usize,
+    /// median of the average fps of the sessions
+    median_fps: f64,
+    /// median of the 99th percentile frametimes of the sessions
+    median_p99: Duration,
+    /// hitches per minute over all sessions
+    hitches_per_minute: f64,
+}
+
+impl SessionAggregate {
+    fn new(key: &str, records: &[&SessionRecord]) -> Self {
+        let mut fps: Vec<_> = records.iter().map(|r| r.fps_average()).collect();
+        fps.sort_unstable_by(f64::total_cmp);
+        let mut p99: Vec<_> = records.iter().map(|r| r.p99).collect();
+        p99.sort_unstable();
+        let hitches: u64 = records.iter().map(|r| r.hitches).sum();
+        let elapsed: Duration = records.iter().map(|r| r.elapsed).sum();
+        Self {
+            key: key.to_owned(),
+            sessions: records.len(),
+            median_fps: fps[fps.len() / 2],
+            median_p99: p99[p99.len() / 2],
+            hitches_per_minute: match elapsed {
+                Duration::ZERO => 0.,
+                elapsed => hitches as f64 * 60. / elapsed.as_secs_f64(),
+            },
+        }
+    }
+
+    /// shared key, e.g. a build or hardware
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// number of sessions
+    pub fn sessions(&self) -> usize {
+        self.sessions
+    }
+
+    /// median of the average fps of the sessions
+    pub fn median_fps(&self) -> f64 {
+        self.median_fps
+    }
+
+    /// median of the 99th percentile frametimes of the sessions
+    pub fn median_p99(&self) -> Duration {
+        self.median_p99
+    }
+
+    /// hitches per minute over all sessions
+    pub fn hitches_per_minute(&self) -> f64 {
+        self.hitches_per_minute
+    }
+}
+
+impl Timer {
+    /// Summarizes the session so far into a [`SessionRecord`],
+    /// e.g. to append to a [`SessionStore`] on exit.
+    ///
+    /// Whitespace and `=` in `build` and `hardware` are replaced
+    /// with `_` to keep the record on a single line.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Arguments
+    /// * `build` - build of the application, e.g. a version or commit
+    /// * `hardware` - hardware the session ran on, e.g. a GPU model
+    pub fn session_record(&self, build: &str, hardware: &str) -> SessionRecord {
+        let summary = self.session_summary();
+        let stats = summary.frame_time_stats();
+        SessionRecord {
+            time: SystemTime::now(),
+            build: sanitize(build),
+            hardware: sanitize(hardware),
+            frames: stats.frames(),
+            elapsed: summary.elapsed(),
+            median: stats.median(),
+            p99: stats.p99(),
+            hitches: stats.hitches(),
+            stability: summary.stability_score(),
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use std::time::{Duration, Instant};
+
+use crate::Timer;
+
+/// how often the thermal state is polled
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Thermal pressure of the system, from no pressure to critical
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ThermalState {
+    /// no thermal pressure
+    #[default]
+    Nominal,
+    /// elevated temperature, throttling is imminent
+    Fair,
+    /// the system is throttling
+    Serious,
+    /// the system is about to shut down to cool off
+    Critical,
+}
+
+/// Source of the [`ThermalState`] polled by a [`Timer`]
+/// with a [`Timer::thermal_policy`].
+///
+/// Closures returning an `Option<ThermalState>` implement this trait.
+pub trait ThermalSource: Send + Sync {
+    /// current thermal state, [`None`] if it can not be determined
+    fn thermal_state(&mut self) -> Option<ThermalState>;
+}
+
+impl<F: FnMut() -> Option<ThermalState> + Send + Sync> ThermalSource for F {
+    fn thermal_state(&mut self) -> Option<ThermalState> {
+        self()
+    }
+}
+
+/// Thermal source reading the thermal zones in `/sys/class/thermal`.
+///
+/// The hottest zone determines the state: reaching its `passive`,
+/// `hot` or `critical` trip point means [`ThermalState::Fair`],
+/// [`ThermalState::Serious`] and [`ThermalState::Critical`] respectively.
+/// Zones without trip points use 70°C, 85°C and 95°C instead.
+/// Always [`None`] on systems without thermal zones.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SysfsThermal;
+
+impl SysfsThermal {
+    /// reads a millidegree value from a sysfs file
+    fn read_millis(path: &std::path::Path) -> Option<i64> {
+        std::fs::read_to_string(path).ok()?.trim().parse().ok()
+    }
+
+    /// state of a single thermal zone
+    fn zone_state(zone: &std::path::Path) -> Option<ThermalState> {
+        let temp = Self::read_millis(&zone.join("temp"))?;
+        let (mut fair, mut serious, mut critical) = (70_000, 85_000, 95_000);
+        for trip in 0.. {
+            let Ok(kind) = std::fs::read_to_string(zone.join(format!("trip_point_{trip}_type")))
+            else {
+                break;
+            };
+            let Some(trip_temp) = Self::read_millis(&zone.join(format!("trip_point_{trip}_temp")))
+            else {
+                continue;
+            };
+            match kind.trim() {
+                "passive" => fair = fair.min(trip_temp),
+                "hot" => serious = serious.min(trip_temp),
+                "critical" => critical = critical.min(trip_temp),
+                _ => {}
+            }
+        }
+        Some(match temp {
+            t if t >= critical => ThermalState::Critical,
+            t if t >= serious => ThermalState::Serious,
+            t if t >= fair => ThermalState::Fair,
+            _ => ThermalState::Nominal,
+        })
+    }
+}
+
+impl ThermalSource for SysfsThermal {
+    fn thermal_state(&mut self) -> Option<ThermalState> {
+        std::fs::read_dir("/sys/class/thermal")
+            .ok()?
+            .flatten()
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_string_lossy()
+                    .starts_with("thermal_zone")
+            })
+            .filter_map(|entry| Self::zone_state(&entry.path()))
+            .max()
+    }
+}
+
+/// A change of the framerate target caused by a change
+/// of the [`ThermalState`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ThermalEvent {
+    /// the new thermal state
+    state: ThermalState,
+    /// the framerate target before the change
+    previous_fps: f64,
+    /// the new framerate target
+    fps: f64,
+}
+
+impl ThermalEvent {
+    /// the new thermal state
+    pub fn state(&self) -> ThermalState {
+        self.state
+    }
+
+    /// the framerate target before the change
+    pub fn previous_fps(&self) -> f64 {
+        self.previous_fps
+    }
+
+    /// the new framerate target
+    pub fn fps(&self) -> f64 {
+        self.fps
+    }
+}
+
+type ThermalPolicy = Box<dyn FnMut(ThermalState) -> f64 + Send + Sync>;
+type ThermalHook = Box<dyn FnMut(ThermalEvent) + Send + Sync>;
+
+/// thermal throttling state of a [`Timer`]
+pub(crate) struct Thermal {
+    /// where the thermal state is read from
+    source: Box<dyn ThermalSource>,
+    /// maps thermal states to framerate targets
+    policy: ThermalPolicy,
+    /// called whenever the framerate target is changed
+    hooks: Vec<ThermalHook>,
+    /// last observed thermal state
+    state: Option<ThermalState>,
+    /// next time the thermal state is polled
+    next_poll: Option<Instant>,
+}
+
+impl Timer {
+    /// Adjusts the framerate target according to the thermal pressure
+    /// of the system, for sustained performance on handhelds and laptops.
+    ///
+    /// The thermal state is polled once per second from the
+    /// [`ThermalSource`] (by default [`SysfsThermal`]). Whenever it
+    /// changes, `policy` is called with the new state and its result is
+    /// applied as the new framerate target, see [`Self::fps`].
+    ///
+    /// Requires the `thermal` feature.
+    ///
+    /// # Arguments
+    /// * `policy` - returns the framerate target for a thermal state
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    /// use fps_timer::{ThermalState, Timer};
+    ///
+    /// let state = Arc::new(Mutex::new(ThermalState::Nominal));
+    /// let source = state.clone();
+    /// let mut timer = Timer::default()
+    ///     .fps(1000.)
+    ///     .thermal_policy(|state| match state {
+    ///         ThermalState::Nominal | ThermalState::Fair => 1000.,
+    ///         ThermalState::Serious => 500.,
+    ///         ThermalState::Critical => 250.,
+    ///     })
+    ///     .thermal_source(move || Some(*source.lock().unwrap()));
+    /// let events = Arc::new(Mutex::new(vec![]));
+    /// let log = events.clone();
+    /// timer.on_thermal_change(move |event| log.lock().unwrap().push(event.fps()));
+    ///
+    /// timer.frame();
+    /// *state.lock().unwrap() = ThermalState::Serious;
+    /// // skip the poll interval of one second
+    /// timer.poll_thermal_now();
+    /// timer.frame();
+    /// assert_eq!(*events.lock().unwrap(), [1000., 500.]);
+    /// ```
+    pub fn thermal_policy(
+        mut self,
+        policy: impl FnMut(ThermalState) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        self.thermal = Some(Thermal {
+            source: Box::new(SysfsThermal),
+            policy: Box::new(policy),
+            hooks: vec![],
+            state: None,
+            next_poll: None,
+        });
+        self
+    }
+
+    /// Replaces the [`ThermalSource`] used by [`Self::thermal_policy`],
+    /// e.g. to read a platform specific thermal state.
+    /// Has no effect without a thermal policy.
+    pub fn thermal_source(mut self, source: impl ThermalSource + 'static) -> Self {
+        if let Some(thermal) = &mut self.thermal {
+            thermal.source = Box::new(source);
+        }
+        self
+    }
+
+    /// Registers a hook that is called with a [`ThermalEvent`] whenever
+    /// the thermal policy changes the framerate target.
+    /// Has no effect without a thermal policy.
+    pub fn on_thermal_change(&mut self, hook: impl FnMut(ThermalEvent) + Send + Sync + 'static) {
+        if let Some(thermal) = &mut self.thermal {
+            thermal.hooks.push(Box::new(hook));
+        }
+    }
+
+    /// Polls the thermal state on the next frame,
+    /// regardless of the poll interval.
+    pub fn poll_thermal_now(&mut self) {
+        if let Some(thermal) = &mut self.thermal {
+            thermal.next_poll = None;
+        }
+    }
+
+    /// polls the thermal state if due and applies the policy
+    pub(crate) fn update_thermal(&mut self, now: Instant) {
+        let Some(thermal) = &mut self.thermal else {
+            return;
+        };
+        if thermal.next_poll.is_some_and(|next| now < next) {
+            return;
+        }
+        thermal.next_poll = Some(now + POLL_INTERVAL);
+        let Some(state) = thermal.source.thermal_state() else {
+            return;
+        };
+        if thermal.state == Some(state) {
+            return;
+        }
+        thermal.state = Some(state);
+        let fps = (thermal.policy)(state);
+        let previous_fps = match self.delta_time {
+            Duration::ZERO => 0.,
+            delta => 1. / delta.as_secs_f64(),
+        };
+        let event = ThermalEvent {
+            state,
+            previous_fps,
+            fps,
+        };
+        for hook in &mut thermal.hooks {
+            hook(event);
+        }
+        self.set_fps(fps);
+    }
+}
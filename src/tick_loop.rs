@@ -0,0 +1,232 @@
+use std::{
+    ops::ControlFlow,
+    time::{Duration, Instant},
+};
+
+use crate::{duration_from_nanos, pacing_core::Schedule, wait_until, WaitTime};
+
+/// Information about a single tick of a [`TickLoop`]
+#[derive(Clone, Copy, Debug)]
+pub struct Tick {
+    /// index of this tick, counting dropped ticks
+    index: u64,
+    /// instant this tick was scheduled for
+    scheduled: Instant,
+    /// fixed duration of a tick
+    delta: Duration,
+    /// whether a snapshot is due on this tick
+    snapshot: bool,
+    /// how late this tick started
+    late: Duration,
+}
+
+impl Tick {
+    /// index of this tick since the start of the loop
+    ///
+    /// Dropped ticks are counted, so the index always
+    /// corresponds to `start + index * tick duration`.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// instant this tick was scheduled for
+    pub fn scheduled(&self) -> Instant {
+        self.scheduled
+    }
+
+    /// fixed simulation timestep of the loop
+    pub fn delta_time(&self) -> Duration {
+        self.delta
+    }
+
+    /// whether a snapshot should be broadcast after this tick
+    pub fn is_snapshot(&self) -> bool {
+        self.snapshot
+    }
+
+    /// how late this tick started compared to its schedule;
+    /// non-zero while the loop is catching up
+    pub fn late_by(&self) -> Duration {
+        self.late
+    }
+}
+
+/// Fixed rate tick loop for authoritative game servers.
+///
+/// Unlike [`crate::Timer`], which is geared towards rendering and
+/// returns variable frametimes, a [`TickLoop`] runs a fixed timestep.
+/// Ticks that are late are run back to back to catch up, but at most
+/// `max_catch_up` of them; ticks beyond that are dropped and counted.
+/// Ticks taking longer than the tick duration are counted as overruns.
+///
+/// Tick targets come from a [`Schedule`] anchored at the start of the
+/// loop, so they do not drift even if the tick duration is not a whole
+/// number of nanoseconds.
+///
+/// # Example
+/// ```rust
+/// use std::ops::ControlFlow;
+/// use fps_timer::TickLoop;
+///
+/// let mut server = TickLoop::new(1000.)
+///     .snapshot_every(4)
+///     .max_catch_up(5);
+///
+/// let mut snapshots = 0;
+/// server.run(|tick| {
+///     // simulate the world by tick.delta_time()
+///     if tick.is_snapshot() {
+///         snapshots += 1;
+///     }
+///     match tick.index() {
+///         0..=15 => ControlFlow::Continue(()),
+///         _ => ControlFlow::Break(()),
+///     }
+/// });
+/// assert!(snapshots >= 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct TickLoop {
+    /// tick targets in nanoseconds since `epoch`
+    schedule: Schedule,
+    /// instant the loop started at
+    epoch: Instant,
+    /// a snapshot is due every `snapshot_every` ticks
+    snapshot_every: u64,
+    /// maximum number of late ticks to run back to back
+    max_catch_up: u64,
+    /// improved accuracy
+    high_precision: bool,
+    /// index of the next tick
+    index: u64,
+    /// instant the previous tick was handed out
+    tick_start: Option<Instant>,
+    /// number of ticks that exceeded the tick duration
+    overruns: u64,
+    /// number of ticks that were dropped
+    dropped: u64,
+}
+
+impl TickLoop {
+    /// Creates a tick loop running at `tick_rate` ticks per second.
+    ///
+    /// # Panics
+    /// if `tick_rate` is not positive or a tick would be shorter
+    /// than a nanosecond (including infinite rates)
+    ///
+    /// # Example
+    /// ```rust,should_panic
+    /// use fps_timer::TickLoop;
+    ///
+    /// let server = TickLoop::new(f64::INFINITY);
+    /// ```
+    pub fn new(tick_rate: f64) -> Self {
+        assert!(tick_rate > 0., "tick rate must be positive");
+        let mut schedule = Schedule::from_fps(tick_rate);
+        assert!(
+            !schedule.frame_time().is_zero(),
+            "tick rate must be at most 1GHz"
+        );
+        schedule.start(0);
+        Self {
+            schedule,
+            epoch: Instant::now(),
+            snapshot_every: 1,
+            max_catch_up: 4,
+            high_precision: false,
+            index: 0,
+            tick_start: None,
+            overruns: 0,
+            dropped: 0,
+        }
+    }
+
+    /// Marks every `ticks`-th tick as snapshot tick.
+    ///
+    /// Defaults to `1` (every tick)
+    ///
+    /// # Panics
+    /// if `ticks` is zero
+    pub fn snapshot_every(mut self, ticks: u64) -> Self {
+        assert!(ticks > 0, "snapshot interval must be at least 1");
+        self.snapshot_every = ticks;
+        self
+    }
+
+    /// Sets the maximum number of late ticks that are run back to back
+    /// to catch up. Additional late ticks are dropped.
+    ///
+    /// Defaults to `4`
+    pub fn max_catch_up(mut self, ticks: u64) -> Self {
+        self.max_catch_up = ticks;
+        self
+    }
+
+    /// Enable or disable improved accuracy (see [`crate::Timer::high_precision`]).
+    ///
+    /// Defaults to `false`
+    pub fn high_precision(mut self, enabled: bool) -> Self {
+        self.high_precision = enabled;
+        self
+    }
+
+    /// instant the next tick is scheduled for
+    fn next(&self) -> Instant {
+        self.epoch + duration_from_nanos(self.schedule.target())
+    }
+
+    /// Waits until the next tick is due and returns it.
+    pub fn next_tick(&mut self) -> Tick {
+        let mut now = Instant::now();
+        let delta = self.schedule.frame_time();
+
+        // the previous tick took longer than a tick
+        if let Some(start) = self.tick_start {
+            if now.saturating_duration_since(start) > delta {
+                self.overruns += 1;
+            }
+        }
+
+        let mut next = self.next();
+        if now < next {
+            now = wait_until(next, self.high_precision, &mut WaitTime::default());
+        } else {
+            // drop ticks beyond the catch-up limit
+            let due = self.schedule.frames_in((now - next).as_nanos());
+            if due > self.max_catch_up {
+                let drop = due - self.max_catch_up;
+                self.dropped += drop;
+                self.index += drop;
+                self.schedule.advance_by(drop);
+                next = self.next();
+            }
+        }
+
+        let tick = Tick {
+            index: self.index,
+            scheduled: next,
+            delta,
+            snapshot: self.index.is_multiple_of(self.snapshot_every),
+            late: now.saturating_duration_since(next),
+        };
+        self.index += 1;
+        self.schedule.advance();
+        self.tick_start = Some(now);
+        tick
+    }
+
+    /// Runs `tick` on every tick until it returns [`ControlFlow::Break`].
+    pub fn run(&mut self, mut tick: impl FnMut(&Tick) -> ControlFlow<()>) {
+        while tick(&self.next_tick()).is_continue() {}
+    }
+
+    /// number of ticks that took longer than the tick duration
+    pub fn overruns(&self) -> u64 {
+        self.overruns
+    }
+
+    /// number of ticks dropped because the catch-up limit was exceeded
+    pub fn dropped_ticks(&self) -> u64 {
+        self.dropped
+    }
+}
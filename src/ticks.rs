@@ -0,0 +1,312 @@
+use core::time::Duration;
+
+use crate::pacing_core::Schedule;
+
+/// Integer tick clock of a hardware timer, the backend of a [`TickTimer`].
+///
+/// Implement this for the hardware timer of a microcontroller to reuse
+/// the pacing logic of this crate, or use one of the provided backends:
+/// `HalTickClock` (`embedded-hal` feature) for a free-running counter
+/// waiting through an `embedded-hal` delay and `EmbassyTickClock`
+/// (`embassy` feature) for Embassy's time driver.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::TickClock;
+///
+/// /// simulated 1MHz timer
+/// struct Counter(u64);
+///
+/// impl TickClock for Counter {
+///     const TICK_HZ: u64 = 1_000_000;
+///
+///     fn now(&mut self) -> u64 {
+///         self.0
+///     }
+///
+///     fn wait_until(&mut self, ticks: u64) {
+///         self.0 = self.0.max(ticks);
+///     }
+/// }
+/// ```
+pub trait TickClock {
+    /// number of ticks per second
+    const TICK_HZ: u64;
+
+    /// current tick count, monotonically increasing
+    fn now(&mut self) -> u64;
+
+    /// blocks until the tick count reaches `ticks`
+    fn wait_until(&mut self, ticks: u64);
+}
+
+/// Frame pacer on top of a [`TickClock`].
+///
+/// Frame targets come from a [`Schedule`] in nanoseconds since the
+/// start and are converted to ticks, so they do not drift, even if the
+/// frame period is not a whole number of ticks. Like [`crate::Timer`],
+/// the schedule is re-based if the pacer lags behind by more than two
+/// frames.
+///
+/// # Example
+/// ```rust
+/// # use fps_timer::TickClock;
+/// # struct Counter(u64);
+/// # impl TickClock for Counter {
+/// #     const TICK_HZ: u64 = 1_000_000;
+/// #     fn now(&mut self) -> u64 { self.0 }
+/// #     fn wait_until(&mut self, ticks: u64) { self.0 = self.0.max(ticks); }
+/// # }
+/// use fps_timer::TickTimer;
+///
+/// // refresh an SPI display at 30 fps
+/// let mut timer = TickTimer::new(Counter(0), 30);
+/// let frame = timer.frame();
+/// assert_eq!(frame, 33_333);
+/// assert_eq!(timer.frame(), 33_333);
+/// // the remainder is carried over instead of drifting
+/// assert_eq!(timer.frame(), 33_334);
+/// assert_eq!(TickTimer::<Counter>::to_duration(frame).as_micros(), 33_333);
+/// ```
+#[derive(Debug)]
+pub struct TickTimer<C: TickClock> {
+    /// the underlying clock
+    clock: C,
+    /// frame targets in nanoseconds since `epoch`
+    schedule: Schedule,
+    /// tick count the schedule is anchored at
+    epoch: u64,
+    /// tick count of the previous frame
+    previous: u64,
+    /// maximum amount of frames to lag behind
+    max_delay_frames: u32,
+}
+
+impl<C: TickClock> TickTimer<C> {
+    /// Creates a pacer for `fps` frames per second, `0` meaning uncapped.
+    pub fn new(clock: C, fps: u32) -> Self {
+        Self::with_schedule(clock, Schedule::from_rate(fps as u64, 1))
+    }
+
+    /// Creates a pacer for the frametime of `schedule`,
+    /// e.g. [`Schedule::from_rate`] for rational framerates.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use fps_timer::TickClock;
+    /// # struct Counter(u64);
+    /// # impl TickClock for Counter {
+    /// #     const TICK_HZ: u64 = 90_000;
+    /// #     fn now(&mut self) -> u64 { self.0 }
+    /// #     fn wait_until(&mut self, ticks: u64) { self.0 = self.0.max(ticks); }
+    /// # }
+    /// use fps_timer::{pacing_core::Schedule, TickTimer};
+    ///
+    /// // 29.97 fps on a 90kHz clock
+    /// let mut timer = TickTimer::with_schedule(Counter(0), Schedule::from_rate(30_000, 1001));
+    /// let ticks: u64 = (0..30_000).map(|_| timer.frame()).sum();
+    /// assert_eq!(ticks, 1001 * 90_000);
+    /// ```
+    pub fn with_schedule(mut clock: C, mut schedule: Schedule) -> Self {
+        let now = clock.now();
+        schedule.restart(0);
+        Self {
+            clock,
+            schedule,
+            epoch: now,
+            previous: now,
+            max_delay_frames: 2,
+        }
+    }
+
+    /// schedules the next frame at `now` and returns its target,
+    /// if the pacer has to wait for it
+    fn next_target(&mut self, now: u64) -> Option<u64> {
+        if self.schedule.fixed_period() == 0 {
+            return None;
+        }
+        let target = self.schedule.advance();
+        let elapsed = Self::to_nanos(now.saturating_sub(self.epoch));
+        let slack = self.schedule.frame_time() * self.max_delay_frames;
+        if elapsed.saturating_sub(target) > slack.as_nanos() {
+            // re-base the schedule if we lag behind too much
+            self.epoch = now;
+            self.schedule.restart(0);
+            return None;
+        }
+        let target = self.epoch.saturating_add(Self::to_ticks(target));
+        (now < target).then_some(target)
+    }
+
+    /// ends the frame at `now`, returning the ticks since the previous frame
+    fn finish(&mut self, now: u64) -> u64 {
+        let frame_ticks = now.saturating_sub(self.previous);
+        self.previous = now;
+        frame_ticks
+    }
+
+    /// Waits until the next frame target is reached and returns
+    /// the number of ticks since the previous frame.
+    pub fn frame(&mut self) -> u64 {
+        let mut now = self.clock.now();
+        if let Some(target) = self.next_target(now) {
+            self.clock.wait_until(target);
+            now = self.clock.now();
+        }
+        self.finish(now)
+    }
+
+    /// whole ticks in `nanos` nanoseconds, rounded down
+    fn to_ticks(nanos: u128) -> u64 {
+        let ticks = nanos.saturating_mul(C::TICK_HZ as u128) / 1_000_000_000;
+        u64::try_from(ticks).unwrap_or(u64::MAX)
+    }
+
+    /// nanoseconds in `ticks` ticks, rounded down
+    fn to_nanos(ticks: u64) -> u128 {
+        ticks as u128 * 1_000_000_000 / C::TICK_HZ as u128
+    }
+
+    /// Converts a number of ticks of the clock into a [`Duration`].
+    pub fn to_duration(ticks: u64) -> Duration {
+        let nanos = Self::to_nanos(ticks);
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+
+    /// the underlying clock
+    pub fn clock(&mut self) -> &mut C {
+        &mut self.clock
+    }
+}
+
+/// [`TickClock`] of a free-running hardware counter at `HZ` ticks per
+/// second, waiting through an `embedded-hal` [`DelayNs`](embedded_hal::delay::DelayNs).
+///
+/// The counter is read through a closure, since `embedded-hal` has no
+/// trait for it, e.g. `|| timer.counter() as u64` for a timer
+/// peripheral of the HAL. It has to be monotonic and must not wrap
+/// around within the lifetime of the [`TickTimer`].
+///
+/// Requires the `embedded-hal` feature.
+///
+/// # Example
+/// ```rust
+/// use std::cell::Cell;
+/// use embedded_hal::delay::DelayNs;
+/// use fps_timer::{HalTickClock, TickTimer};
+///
+/// /// simulated 1MHz counter advanced by the delay
+/// struct Delay<'a>(&'a Cell<u64>);
+///
+/// impl DelayNs for Delay<'_> {
+///     fn delay_ns(&mut self, ns: u32) {
+///         self.0.set(self.0.get() + ns as u64 / 1000);
+///     }
+/// }
+///
+/// let counter = Cell::new(0);
+/// let clock = HalTickClock::<_, _, 1_000_000>::new(Delay(&counter), || counter.get());
+/// let mut timer = TickTimer::new(clock, 50);
+/// assert_eq!(timer.frame(), 20_000);
+/// assert_eq!(counter.get(), 20_000);
+/// ```
+#[cfg(feature = "embedded-hal")]
+#[derive(Debug)]
+pub struct HalTickClock<D, F, const HZ: u64> {
+    /// delay waiting for the targets
+    delay: D,
+    /// reads the tick count of the counter
+    counter: F,
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<D, F, const HZ: u64> HalTickClock<D, F, HZ> {
+    /// Creates a clock reading the ticks from `counter`
+    /// and waiting with `delay`.
+    ///
+    /// Requires the `embedded-hal` feature.
+    pub fn new(delay: D, counter: F) -> Self {
+        Self { delay, counter }
+    }
+}
+
+#[cfg(feature = "embedded-hal")]
+impl<D, F, const HZ: u64> TickClock for HalTickClock<D, F, HZ>
+where
+    D: embedded_hal::delay::DelayNs,
+    F: FnMut() -> u64,
+{
+    const TICK_HZ: u64 = HZ;
+
+    fn now(&mut self) -> u64 {
+        (self.counter)()
+    }
+
+    fn wait_until(&mut self, ticks: u64) {
+        loop {
+            let now = (self.counter)();
+            if now >= ticks {
+                break;
+            }
+            // delays may be cut short, so check the counter again
+            let nanos = ((ticks - now) as u128 * 1_000_000_000).div_ceil(HZ as u128);
+            self.delay
+                .delay_ns(u32::try_from(nanos).unwrap_or(u32::MAX));
+        }
+    }
+}
+
+/// [`TickClock`] of Embassy's time driver, ticking at
+/// [`embassy_time::TICK_HZ`].
+///
+/// [`TickTimer::frame`] busy-waits like [`embassy_time::block_for`],
+/// use [`TickTimer::frame_async`] in async tasks instead.
+///
+/// Requires the `embassy` feature.
+#[cfg(feature = "embassy")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct EmbassyTickClock;
+
+#[cfg(feature = "embassy")]
+impl TickClock for EmbassyTickClock {
+    const TICK_HZ: u64 = embassy_time::TICK_HZ;
+
+    fn now(&mut self) -> u64 {
+        embassy_time::Instant::now().as_ticks()
+    }
+
+    fn wait_until(&mut self, ticks: u64) {
+        while embassy_time::Instant::now().as_ticks() < ticks {}
+    }
+}
+
+#[cfg(feature = "embassy")]
+impl TickTimer<EmbassyTickClock> {
+    /// Same as [`Self::frame`], awaiting the frame target with an
+    /// [`embassy_time::Timer`] instead of busy-waiting, so other
+    /// tasks run (or the core sleeps) in the meantime.
+    ///
+    /// Requires the `embassy` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{EmbassyTickClock, TickTimer};
+    ///
+    /// async fn render_loop() {
+    ///     let mut timer = TickTimer::new(EmbassyTickClock, 30);
+    ///     for _ in 0..3 {
+    ///         let ticks = timer.frame_async().await;
+    ///         assert!(TickTimer::<EmbassyTickClock>::to_duration(ticks).as_millis() >= 30);
+    ///     }
+    /// }
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(render_loop());
+    /// ```
+    pub async fn frame_async(&mut self) -> u64 {
+        let mut now = self.clock.now();
+        if let Some(target) = self.next_target(now) {
+            embassy_time::Timer::at(embassy_time::Instant::from_ticks(target)).await;
+            now = self.clock.now();
+        }
+        self.finish(now)
+    }
+}
@@ -0,0 +1,75 @@
+use std::time::Instant;
+
+use crate::{duration_from_nanos, Timer};
+
+impl Timer {
+    /// Reference instant of the timer's nanosecond timebase.
+    ///
+    /// Frame targets are computed as integer nanosecond offsets from
+    /// this instant, with the frametime kept to a fraction of a
+    /// nanosecond, so rounding errors do not accumulate even for
+    /// very high framerates and very long sessions. The epoch only
+    /// changes when set with [`Self::epoch_at`], which makes it a
+    /// fixed point for correlating timestamps with external clocks,
+    /// see [`Self::to_system_time`].
+    pub fn timebase_epoch(&self) -> Instant {
+        self.epoch
+    }
+
+    /// nanoseconds from [`Self::timebase_epoch`] to `instant`,
+    /// zero for instants before the epoch
+    pub fn nanos_since_epoch(&self, instant: Instant) -> u128 {
+        instant.saturating_duration_since(self.epoch).as_nanos()
+    }
+
+    /// Instant the next frame is scheduled for.
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::Timer;
+    ///
+    /// // 3 fps, i.e. a frametime of 333333333.3ns
+    /// let mut timer = Timer::default().fps(3.);
+    /// for _ in 0..3 {
+    ///     timer.frame_at(timer.next_target());
+    /// }
+    /// // exactly one second after the first target, without accumulated rounding
+    /// let target = timer.nanos_since_epoch(timer.next_target());
+    /// assert_eq!(target, 1_333_333_333);
+    /// ```
+    pub fn next_target(&self) -> Instant {
+        self.target
+    }
+
+    /// target of the most recently scheduled frame
+    fn scheduled_target(&self) -> Option<Instant> {
+        let nanos = self.schedule.target();
+        self.epoch.checked_add(duration_from_nanos(nanos))
+    }
+
+    /// starts a new schedule at the current target
+    pub(crate) fn restart_schedule(&mut self) {
+        let origin = self.nanos_since_epoch(self.target);
+        self.schedule.restart(origin);
+    }
+
+    /// starts a new schedule at `start` and sets the target to the
+    /// first frame, without rounding the frametime
+    pub(crate) fn start_schedule(&mut self, start: Instant) {
+        self.schedule.start(self.nanos_since_epoch(start));
+        if let Some(target) = self.scheduled_target() {
+            self.target = target;
+        }
+    }
+
+    /// advances the frame schedule by one frame and returns the next target
+    pub(crate) fn advance_schedule(&mut self) -> Instant {
+        // the target was moved (e.g. re-based), start over from there
+        if self.scheduled_target() != Some(self.target) {
+            self.restart_schedule();
+        }
+        self.schedule.advance();
+        self.scheduled_target()
+            .unwrap_or(self.target + self.delta_time)
+    }
+}
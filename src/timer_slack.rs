@@ -0,0 +1,62 @@
+use std::time::Duration;
+
+#[cfg(target_os = "linux")]
+mod sys {
+    use libc::c_ulong;
+
+    pub(super) fn get() -> Option<u64> {
+        // SAFETY: PR_GET_TIMERSLACK takes no arguments
+        let slack = unsafe { libc::prctl(libc::PR_GET_TIMERSLACK, 0 as c_ulong) };
+        (slack >= 0).then_some(slack as u64)
+    }
+
+    pub(super) fn set(nanos: u64) -> bool {
+        // SAFETY: PR_SET_TIMERSLACK takes the slack as unsigned long
+        unsafe { libc::prctl(libc::PR_SET_TIMERSLACK, nanos as c_ulong) == 0 }
+    }
+}
+
+/// Timer slack of the current thread, restored to its
+/// previous value when dropped.
+#[derive(Debug)]
+pub(crate) struct TimerSlackGuard {
+    /// timer slack before it was changed, in nanoseconds
+    #[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+    previous: u64,
+}
+
+impl TimerSlackGuard {
+    /// sets the timer slack of the current thread to `slack`,
+    /// returns [`None`] if not supported
+    #[cfg(target_os = "linux")]
+    pub(crate) fn set(slack: Duration) -> Option<Self> {
+        let previous = sys::get()?;
+        // a slack of 0 would reset to the default slack
+        let nanos = slack.as_nanos().clamp(1, u64::MAX as u128) as u64;
+        sys::set(nanos).then_some(Self { previous })
+    }
+
+    /// sets the timer slack of the current thread to `slack`,
+    /// returns [`None`] if not supported
+    #[cfg(not(target_os = "linux"))]
+    pub(crate) fn set(_slack: Duration) -> Option<Self> {
+        None
+    }
+}
+
+/// timer slack of the current thread,
+/// [`None`] if not supported
+#[cfg_attr(not(feature = "stats"), allow(dead_code))]
+pub(crate) fn current() -> Option<Duration> {
+    #[cfg(target_os = "linux")]
+    return sys::get().map(Duration::from_nanos);
+    #[cfg(not(target_os = "linux"))]
+    None
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for TimerSlackGuard {
+    fn drop(&mut self) {
+        sys::set(self.previous);
+    }
+}
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use crate::Timer;
+
+/// Easing curve of a [`Transition`], mapping linear progress
+/// in `[0, 1]` to eased progress in `[0, 1]`
+#[derive(Clone, Copy, Debug, Default)]
+pub enum Easing {
+    /// constant speed
+    #[default]
+    Linear,
+    /// accelerating from zero speed (quadratic)
+    EaseIn,
+    /// decelerating to zero speed (quadratic)
+    EaseOut,
+    /// accelerating, then decelerating (smoothstep)
+    EaseInOut,
+    /// custom curve, which should map `0` to `0` and `1` to `1`
+    Custom(fn(f64) -> f64),
+}
+
+impl Easing {
+    /// eased progress for linear progress `t` in `[0, 1]`
+    pub fn apply(self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => t,
+            Easing::EaseIn => t * t,
+            Easing::EaseOut => t * (2. - t),
+            Easing::EaseInOut => t * t * (3. - 2. * t),
+            Easing::Custom(curve) => curve(t),
+        }
+    }
+}
+
+/// Fixed-duration transition (e.g. a fade or a camera move) advancing
+/// with the frames of a [`Timer`], created through [`Timer::transition`].
+///
+/// The progress is measured in [`Timer::session_time`] since the
+/// transition was created, so it follows the session time of the
+/// pacer, including pauses and the time scale, instead of a separate
+/// [`std::time::Instant`].
+///
+/// # Example
+/// ```rust
+/// use std::time::Duration;
+/// use fps_timer::{Easing, Timer};
+///
+/// let mut timer = Timer::default().fps(200.);
+/// let mut fade = timer.transition(Duration::from_millis(50), Easing::EaseInOut);
+/// let mut frames = 0;
+/// while !fade.is_finished() {
+///     timer.frame();
+///     let alpha = fade.progress(&timer);
+///     assert!((0. ..=1.).contains(&alpha));
+///     frames += 1;
+/// }
+/// assert!(frames <= 11);
+/// assert_eq!(fade.progress(&timer), 1.);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Transition {
+    /// total duration
+    duration: Duration,
+    /// session time at which the transition started
+    start: Duration,
+    /// time advanced as of the last call to progress
+    elapsed: Duration,
+    /// easing curve of the progress
+    easing: Easing,
+}
+
+impl Transition {
+    /// Updates the transition to the session time of `timer` and
+    /// returns the eased progress in `[0, 1]`, i.e. the session time
+    /// since the start divided by the duration.
+    ///
+    /// The progress only depends on the session time, so calling it
+    /// repeatedly within a frame returns the same progress and
+    /// skipping frames does not slow the transition down.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{Clock, Easing, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(100.).clock(Clock::Synthetic);
+    /// let epoch = timer.timebase_epoch();
+    /// timer.frame_at(epoch);
+    /// let mut fade = timer.transition(Duration::from_millis(100), Easing::Linear);
+    /// for _ in 0..5 {
+    ///     timer.frame_at(epoch);
+    /// }
+    /// // only checked after 5 frames of 10ms
+    /// assert!((fade.progress(&timer) - 0.5).abs() < 1e-6);
+    /// assert!((fade.progress(&timer) - 0.5).abs() < 1e-6);
+    /// ```
+    pub fn progress(&mut self, timer: &Timer) -> f64 {
+        let elapsed = timer.session_time().saturating_sub(self.start);
+        self.elapsed = elapsed.min(self.duration);
+        self.easing.apply(self.linear_progress())
+    }
+
+    /// progress in `[0, 1]` without easing, as of the last call
+    /// to [`Self::progress`]
+    pub fn linear_progress(&self) -> f64 {
+        match self.duration {
+            Duration::ZERO => 1.,
+            duration => self.elapsed.as_secs_f64() / duration.as_secs_f64(),
+        }
+    }
+
+    /// `true` once the transition has completed its duration
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+
+    /// time the transition has advanced
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// total duration of the transition
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+}
+
+impl Timer {
+    /// Creates a [`Transition`] of `duration`, starting at the
+    /// current [`Self::session_time`], i.e. after the previous frame.
+    ///
+    /// # Arguments
+    /// * `duration` - total duration of the transition
+    /// * `easing` - easing curve of the progress
+    pub fn transition(&self, duration: Duration, easing: Easing) -> Transition {
+        Transition {
+            duration,
+            start: self.session_time(),
+            elapsed: Duration::ZERO,
+            easing,
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use crate::{Pacing, Timer};
+
+/// Reason for returning from [`Timer::frame_or_input`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Wakeup {
+    /// the frame target was reached, holds the frametime
+    /// as returned by [`Timer::frame`]
+    Frame(Duration),
+    /// input became ready before the frame target was reached
+    Input,
+}
+
+impl Timer {
+    /// Waits until either the frame target is reached or input is ready.
+    ///
+    /// Intended for terminal applications that need a capped refresh
+    /// rate while staying responsive to input without busy-polling it.
+    /// `poll` is called with the time remaining until the frame target
+    /// and should block for at most that long, returning `true` if input
+    /// is ready (e.g. `crossterm::event::poll`). The remaining time is
+    /// measured on the clock of the timer, up to the instant
+    /// [`Self::frame`] would wait for (including the hard cap, the
+    /// swapchain and quantization). On [`crate::Clock::Synthetic`],
+    /// `poll` is not called.
+    ///
+    /// If input is ready, [`Wakeup::Input`] is returned and the frame
+    /// schedule is left untouched, so the caller can handle the input
+    /// and call this method again. Otherwise the frame is completed
+    /// like with [`Self::frame`] and [`Wakeup::Frame`] is returned.
+    ///
+    /// # Arguments
+    /// * `poll` - polls the input source with the given timeout
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{Timer, Wakeup};
+    ///
+    /// let mut timer = Timer::default().fps(30.);
+    /// // no input source, simply wait for the timeout
+    /// let poll = |timeout| {
+    ///     std::thread::sleep(timeout);
+    ///     false
+    /// };
+    /// match timer.frame_or_input(poll) {
+    ///     Wakeup::Frame(_dt) => { /* redraw */ }
+    ///     Wakeup::Input => { /* handle input */ }
+    /// }
+    ///
+    /// // while catching up after a stall, input is polled until the hard cap
+    /// let mut timer = Timer::default().fps(60.).hard_cap(240.);
+    /// timer.frame();
+    /// std::thread::sleep(std::time::Duration::from_millis(30));
+    /// timer.frame();
+    /// let mut polled = Default::default();
+    /// timer.frame_or_input(|timeout| {
+    ///     polled = timeout;
+    ///     false
+    /// });
+    /// assert!(polled > std::time::Duration::from_millis(3));
+    /// ```
+    pub fn frame_or_input(&mut self, mut poll: impl FnMut(Duration) -> bool) -> Wakeup {
+        let now = self.clock_now();
+        let waits = self.pacing == Pacing::Internal && !self.skip_next_wait;
+        if waits && !self.is_synthetic() {
+            let (wait_target, _) = self.wait_target(now);
+            let remaining = wait_target.saturating_duration_since(now);
+            if !remaining.is_zero() && poll(remaining) {
+                return Wakeup::Input;
+            }
+        }
+        // poll timeouts are coarse, wait for the rest with full precision
+        Wakeup::Frame(self.frame())
+    }
+}
@@ -0,0 +1,122 @@
+use std::{sync::OnceLock, time::Duration};
+
+use crate::{context, Timer};
+
+/// oversleep of short sleeps beyond which the system is treated
+/// as virtualized, regardless of other hints
+const OVERSLEEP_THRESHOLD: Duration = Duration::from_millis(2);
+
+/// `true` if the CPU reports running under a hypervisor
+#[cfg(target_os = "linux")]
+fn hypervisor_flag() -> bool {
+    std::fs::read_to_string("/proc/cpuinfo").is_ok_and(|cpuinfo| {
+        cpuinfo
+            .lines()
+            .filter(|line| line.starts_with("flags"))
+            .any(|line| line.split_whitespace().any(|flag| flag == "hypervisor"))
+    })
+}
+
+/// `true` if the hypervisor stole CPU time from the system
+#[cfg(target_os = "linux")]
+fn steal_time() -> bool {
+    // cpu user nice system idle iowait irq softirq steal ...
+    std::fs::read_to_string("/proc/stat").is_ok_and(|stat| {
+        stat.lines()
+            .next()
+            .and_then(|cpu| cpu.split_whitespace().nth(8))
+            .and_then(|steal| steal.parse::<u64>().ok())
+            .is_some_and(|steal| steal > 0)
+    })
+}
+
+/// `true` if the process runs under a CPU quota, e.g. in a container
+#[cfg(target_os = "linux")]
+fn cpu_quota() -> bool {
+    std::fs::read_to_string("/sys/fs/cgroup/cpu.max").is_ok_and(|max| {
+        max.split_whitespace()
+            .next()
+            .is_some_and(|quota| quota != "max")
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn hypervisor_flag() -> bool {
+    false
+}
+
+#[cfg(not(target_os = "linux"))]
+fn steal_time() -> bool {
+    false
+}
+
+#[cfg(not(target_os = "linux"))]
+fn cpu_quota() -> bool {
+    false
+}
+
+/// Detects whether the process runs in a virtual machine or a
+/// CPU-limited container, where sleeps overshoot unpredictably and
+/// busy waiting competes with the hypervisor.
+///
+/// On Linux, the hypervisor CPU flag, steal time and cgroup CPU
+/// quotas are checked. On all platforms, a system whose short sleeps
+/// overshoot by more than 2ms is treated as virtualized as well.
+/// The result is determined on first use, which takes about 25ms,
+/// and cached for the lifetime of the process.
+pub fn is_virtualized() -> bool {
+    static VIRTUALIZED: OnceLock<bool> = OnceLock::new();
+    *VIRTUALIZED.get_or_init(|| {
+        hypervisor_flag() || steal_time() || cpu_quota() || {
+            let oversleeps = context::measure_oversleeps();
+            oversleeps[oversleeps.len() * 9 / 10] > OVERSLEEP_THRESHOLD
+        }
+    })
+}
+
+impl Timer {
+    /// Enables or disables the wait profile for virtualized
+    /// environments, see [`is_virtualized`].
+    ///
+    /// In virtual machines and containers with steal time, sleeps
+    /// overshoot wildly, and busy waiting only burns CPU time the
+    /// hypervisor accounts against the guest. The virtualized profile
+    /// * disables high precision (see [`Self::high_precision`]) and
+    ///   high-rate mode, so the timer never spins,
+    /// * leaves the OS timer slack at its default, and
+    /// * re-bases the schedule once a frame is more than one frametime
+    ///   late, instead of catching up a burst of frames after every
+    ///   stolen time slice.
+    ///
+    /// Disabling the profile restores high precision and the default
+    /// catch-up slack of two frames.
+    ///
+    /// # Arguments
+    /// * `enabled` - whether to use the virtualized profile
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::{is_virtualized, Timer};
+    ///
+    /// let mut timer = Timer::default().fps(60.).virtualized(is_virtualized());
+    /// timer.frame();
+    /// ```
+    pub fn virtualized(mut self, enabled: bool) -> Self {
+        match enabled {
+            true => {
+                self.high_precision = false;
+                self.high_rate = false;
+                self.timer_slack = None;
+                self.max_delay_frames = 1;
+            }
+            false => {
+                self.high_precision = true;
+                self.max_delay_frames = 2;
+            }
+        }
+        self
+    }
+}
@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use crate::{average_rate::AverageRate, pacing_core::Schedule, Timer, UnknownProfile};
+
+/// Visibility of the window paced by a [`Timer`],
+/// see [`Timer::set_visibility`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Visibility {
+    /// the window is (at least partially) visible
+    #[default]
+    Visible,
+    /// the window is fully covered by other windows
+    /// or on another workspace
+    Occluded,
+    /// the window is minimized
+    Minimized,
+}
+
+impl Visibility {
+    /// `true` unless the window is visible
+    pub fn is_hidden(&self) -> bool {
+        *self != Visibility::Visible
+    }
+}
+
+/// How a [`Timer`] paces frames while its window is hidden,
+/// see [`Timer::background_policy`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BackgroundPolicy {
+    /// activate the profile `idle` while hidden and the profile
+    /// `active` once visible again, see [`Timer::profile`]
+    Profiles {
+        /// profile used while the window is visible
+        active: String,
+        /// profile used while the window is hidden
+        idle: String,
+    },
+    /// pause while hidden: frames only complete every `poll`,
+    /// sleeping without spinning, and [`Timer::is_paused`] tells the
+    /// application to skip its work
+    Pause {
+        /// time between two frames while paused
+        poll: Duration,
+    },
+}
+
+impl BackgroundPolicy {
+    /// switch between the profiles `active` and `idle`
+    pub fn profiles(active: impl Into<String>, idle: impl Into<String>) -> Self {
+        Self::Profiles {
+            active: active.into(),
+            idle: idle.into(),
+        }
+    }
+
+    /// pause while hidden, completing a frame every `poll`
+    pub fn pause(poll: Duration) -> Self {
+        Self::Pause { poll }
+    }
+}
+
+/// pacing settings restored after a pause
+#[derive(Clone, Debug)]
+struct SavedPacing {
+    delta_time: Duration,
+    schedule: Schedule,
+    high_precision: bool,
+    average_rate: Option<AverageRate>,
+}
+
+/// visibility state of a [`Timer`]
+#[derive(Clone, Debug)]
+pub(crate) struct Background {
+    /// what to do while hidden
+    policy: BackgroundPolicy,
+    /// current visibility of the window
+    visibility: Visibility,
+    /// pacing before the pause, while paused
+    saved: Option<SavedPacing>,
+}
+
+impl Timer {
+    /// Makes the timer stop burning CPU while its window is hidden.
+    ///
+    /// Feed occlusion and minimize events of the windowing library to
+    /// [`Self::set_visibility`], e.g. for winit:
+    ///
+    /// ```ignore
+    /// WindowEvent::Occluded(occluded) => {
+    ///     let visibility = match occluded {
+    ///         true => Visibility::Occluded,
+    ///         false => Visibility::Visible,
+    ///     };
+    ///     timer.set_visibility(visibility)?;
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    /// * `policy` - pacing while the window is hidden
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::Duration;
+    /// use fps_timer::{BackgroundPolicy, Profile, Timer, Visibility};
+    ///
+    /// let mut timer = Timer::default()
+    ///     .profile("active", Profile::fps(144.))
+    ///     .profile("idle", Profile::fps(5.).high_precision(false))
+    ///     .background_policy(BackgroundPolicy::profiles("active", "idle"));
+    /// timer.set_visibility(Visibility::Minimized)?;
+    /// assert_eq!(timer.active_profile(), Some("idle"));
+    /// timer.set_visibility(Visibility::Visible)?;
+    /// assert_eq!(timer.active_profile(), Some("active"));
+    ///
+    /// let mut timer = Timer::default()
+    ///     .fps(144.)
+    ///     .background_policy(BackgroundPolicy::pause(Duration::from_millis(100)));
+    /// timer.set_visibility(Visibility::Occluded)?;
+    /// assert!(timer.is_paused());
+    /// timer.set_visibility(Visibility::Visible)?;
+    /// assert!(!timer.is_paused());
+    /// # Ok::<(), fps_timer::UnknownProfile>(())
+    /// ```
+    pub fn background_policy(mut self, policy: BackgroundPolicy) -> Self {
+        self.background = Some(Background {
+            policy,
+            visibility: Visibility::Visible,
+            saved: None,
+        });
+        self
+    }
+
+    /// Updates the visibility of the window, applying the
+    /// [`BackgroundPolicy`] when the window is hidden or shown.
+    /// Has no effect without a background policy.
+    ///
+    /// # Errors
+    /// [`UnknownProfile`] if the policy refers to an undefined profile
+    pub fn set_visibility(&mut self, visibility: Visibility) -> Result<(), UnknownProfile> {
+        let Some(background) = &mut self.background else {
+            return Ok(());
+        };
+        let was_hidden = background.visibility.is_hidden();
+        background.visibility = visibility;
+        if was_hidden == visibility.is_hidden() {
+            return Ok(());
+        }
+        match background.policy.clone() {
+            BackgroundPolicy::Profiles { active, idle } => match visibility.is_hidden() {
+                true => self.set_profile(&idle),
+                false => self.set_profile(&active),
+            },
+            BackgroundPolicy::Pause { poll } => {
+                match visibility.is_hidden() {
+                    true => self.pause(poll),
+                    false => self.unpause(),
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// visibility of the window as last set with [`Self::set_visibility`]
+    pub fn visibility(&self) -> Visibility {
+        self.background
+            .as_ref()
+            .map_or(Visibility::Visible, |background| background.visibility)
+    }
+
+    /// `true` while paused by [`BackgroundPolicy::Pause`], i.e. the
+    /// application can skip rendering and other work of the frame
+    pub fn is_paused(&self) -> bool {
+        self.background
+            .as_ref()
+            .is_some_and(|background| background.saved.is_some())
+    }
+
+    fn pause(&mut self, poll: Duration) {
+        let saved = SavedPacing {
+            delta_time: self.delta_time,
+            schedule: self.schedule,
+            high_precision: self.high_precision,
+            average_rate: self.average_rate.take(),
+        };
+        if let Some(background) = &mut self.background {
+            background.saved.get_or_insert(saved);
+        }
+        self.high_precision = false;
+        self.set_frame_time(poll);
+    }
+
+    fn unpause(&mut self) {
+        let Some(saved) = self.background.as_mut().and_then(|b| b.saved.take()) else {
+            return;
+        };
+        self.high_precision = saved.high_precision;
+        self.set_frame_time(saved.delta_time);
+        match saved.average_rate {
+            Some(mut rate) => {
+                self.target = rate.restart(self.previous);
+                self.average_rate = Some(rate);
+            }
+            // keep the sub-nanosecond frametime, unless it was adjusted
+            None if self.delta_time == saved.delta_time && self.anchor.is_none() => {
+                self.schedule = saved.schedule;
+                self.start_schedule(self.previous);
+            }
+            None => {}
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::time::Instant;
+
+use crate::{Timer, WaitTime};
+
+/// Strategy used by a [`Timer`] to wait until a frame target.
+///
+/// Implement this for platform-specific waits (e.g. waitable timers,
+/// `clock_nanosleep` with `TIMER_ABSTIME`, vsync fences) without
+/// patching the crate, and install it with [`Timer::wait_strategy`].
+/// The time spent inside the strategy is accounted as sleeping time
+/// in [`WaitTime`].
+///
+/// Closures taking the target and returning the wake-up instant
+/// implement this trait as well.
+///
+/// # Example
+/// ```rust
+/// use std::{thread, time::Instant};
+/// use fps_timer::{Timer, WaitStrategy};
+///
+/// /// plain sleep without busy waiting
+/// struct Sleep;
+///
+/// impl WaitStrategy for Sleep {
+///     fn wait_until(&mut self, target: Instant) -> Instant {
+///         thread::sleep(target.saturating_duration_since(Instant::now()));
+///         Instant::now()
+///     }
+/// }
+///
+/// let mut timer = Timer::default().fps(100.).wait_strategy(Sleep);
+/// timer.frame();
+/// ```
+pub trait WaitStrategy: Send + Sync {
+    /// Waits until `target` is reached and returns the instant
+    /// at which the wait finished.
+    fn wait_until(&mut self, target: Instant) -> Instant;
+}
+
+impl<F: FnMut(Instant) -> Instant + Send + Sync> WaitStrategy for F {
+    fn wait_until(&mut self, target: Instant) -> Instant {
+        self(target)
+    }
+}
+
+/// waits until `target` with `strategy`, accounting the time
+/// spent in `spent`, starting from the fresh clock reading `sample`
+/// if available
+pub(crate) fn wait_with(
+    strategy: &mut dyn WaitStrategy,
+    target: Instant,
+    spent: &mut WaitTime,
+    clock: fn() -> Instant,
+    sample: Option<Instant>,
+) -> Instant {
+    let start = sample.unwrap_or_else(clock);
+    let woke = strategy.wait_until(target);
+    spent.sleeping += woke.saturating_duration_since(start);
+    woke
+}
+
+impl Timer {
+    /// Replaces the built-in sleep / busy wait with a custom
+    /// [`WaitStrategy`]. The high precision setting is ignored
+    /// while a strategy is installed.
+    ///
+    /// # Arguments
+    /// * `strategy` - strategy used to wait for frame targets
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    pub fn wait_strategy(mut self, strategy: impl WaitStrategy + 'static) -> Self {
+        self.wait_strategy = Some(Box::new(strategy));
+        self
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+#[cfg(feature = "stats")]
+use crate::Timer;
+
+/// number of buckets, the last one collecting errors of 2^38ns (~4.6min)
+/// and more
+const WAKEUP_BUCKETS: usize = 40;
+
+/// Histogram of wakeup errors, i.e. how long after its target a wait
+/// actually returned.
+///
+/// Unlike frametime statistics, wakeup errors are independent of the
+/// workload of the application and only measure the precision of the
+/// sleep / spin implementation, so regressions across OS versions or
+/// configurations show up clearly. Buckets are powers of two in
+/// nanoseconds: bucket `0` counts exact wakeups, bucket `i` errors in
+/// `[2^(i-1), 2^i)` nanoseconds.
+///
+/// The [`Display`] implementation prints one row per non-empty bucket.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct WakeupHistogram {
+    /// number of wakeups per bucket
+    buckets: Vec<u64>,
+    /// number of recorded wakeups
+    samples: u64,
+    /// largest wakeup error
+    max: Duration,
+}
+
+/// bucket of a wakeup error
+fn bucket(error: Duration) -> usize {
+    let nanos = u64::try_from(error.as_nanos()).unwrap_or(u64::MAX);
+    ((u64::BITS - nanos.leading_zeros()) as usize).min(WAKEUP_BUCKETS - 1)
+}
+
+/// exclusive upper bound of the errors in `bucket`
+fn upper_bound(bucket: usize) -> Duration {
+    Duration::from_nanos(1 << bucket)
+}
+
+impl WakeupHistogram {
+    /// records a wakeup `error` after the target
+    pub(crate) fn record(&mut self, error: Duration) {
+        if self.buckets.is_empty() {
+            self.buckets = vec![0; WAKEUP_BUCKETS];
+        }
+        self.buckets[bucket(error)] += 1;
+        self.samples += 1;
+        self.max = self.max.max(error);
+    }
+
+    /// number of recorded wakeups
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+
+    /// largest wakeup error
+    pub fn max(&self) -> Duration {
+        self.max
+    }
+
+    /// Approximate wakeup error percentile, i.e. the upper bound of the
+    /// bucket containing the `p`-quantile, zero without samples.
+    ///
+    /// # Arguments
+    /// * `p` - quantile in `[0, 1]`
+    pub fn percentile(&self, p: f64) -> Duration {
+        let rank = ((self.samples as f64 * p.clamp(0., 1.)).ceil() as u64).max(1);
+        let mut count = 0;
+        for (bucket, n) in self.buckets.iter().enumerate() {
+            count += n;
+            if count >= rank {
+                return upper_bound(bucket).min(self.max);
+            }
+        }
+        self.max
+    }
+
+    /// Non-empty buckets as `(upper bound, wakeups)` pairs,
+    /// ordered by error.
+    pub fn buckets(&self) -> impl Iterator<Item = (Duration, u64)> + '_ {
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, &n)| n > 0)
+            .map(|(bucket, &n)| (upper_bound(bucket), n))
+    }
+}
+
+impl Display for WakeupHistogram {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        /// width of the bar of the most frequent bucket
+        const BAR: u64 = 40;
+        let most = self.buckets().map(|(_, n)| n).max().unwrap_or(0);
+        for (bound, n) in self.buckets() {
+            let bar = "#".repeat((n * BAR).div_ceil(most) as usize);
+            writeln!(f, "< {:>12?} {n:>8} {bar}", bound)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "stats")]
+impl Timer {
+    /// Histogram of the wakeup errors of all waits for frame targets
+    /// since the timer was created.
+    ///
+    /// Requires the `stats` feature.
+    ///
+    /// # Example
+    /// ```rust
+    /// # #[cfg(feature = "stats")]
+    /// # {
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(500.);
+    /// for _ in 0..10 {
+    ///     timer.frame();
+    /// }
+    /// let wakeups = timer.wakeup_histogram();
+    /// assert!(wakeups.samples() > 0);
+    /// assert!(wakeups.percentile(0.5) <= wakeups.max());
+    /// println!("{wakeups}");
+    /// # }
+    /// ```
+    pub fn wakeup_histogram(&self) -> &WakeupHistogram {
+        &self.wakeups
+    }
+}
@@ -0,0 +1,110 @@
+use std::time::{Instant, SystemTime};
+
+use crate::Timer;
+
+/// wall-clock time at `at`, reading both clocks as close together
+/// as possible
+pub(crate) fn wall_clock_at(at: Instant) -> SystemTime {
+    let before = Instant::now();
+    let wall = SystemTime::now();
+    let after = Instant::now();
+    let sampled = before + (after - before) / 2;
+    match sampled.checked_duration_since(at) {
+        Some(since) => wall - since,
+        None => wall + (at - sampled),
+    }
+}
+
+/// instant of the wall-clock time `wall`, [`None`] if it is not
+/// representable, e.g. before the boot of the system
+fn instant_at(wall: SystemTime) -> Option<Instant> {
+    let before = Instant::now();
+    let wall_now = SystemTime::now();
+    let after = Instant::now();
+    let sampled = before + (after - before) / 2;
+    match wall_now.duration_since(wall) {
+        Ok(ago) => sampled.checked_sub(ago),
+        Err(ahead) => sampled.checked_add(ahead.duration()),
+    }
+}
+
+impl Timer {
+    /// Sets the epoch of the timebase (see [`Self::timebase_epoch`])
+    /// to the wall-clock time `epoch`.
+    ///
+    /// Processes that agree on an epoch, e.g. by passing it on the
+    /// command line or in a handshake, compute the same
+    /// [`Self::nanos_since_epoch`] for the same moment (up to the
+    /// offset of their wall clocks), so frame timestamps can be
+    /// correlated across processes and with external captures like
+    /// videos, logs or network traces.
+    ///
+    /// The epoch must not be later than the previous frame, later
+    /// epochs are clamped to it. The frame schedule is unaffected.
+    ///
+    /// # Arguments
+    /// * `epoch` - wall-clock time of the epoch
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use fps_timer::Timer;
+    ///
+    /// let epoch = SystemTime::now() - Duration::from_secs(1);
+    /// let render = Timer::default().fps(60.).epoch_at(epoch);
+    /// // e.g. in another process
+    /// let capture = Timer::default().fps(30.).epoch_at(epoch);
+    ///
+    /// let now = std::time::Instant::now();
+    /// let offset = render.nanos_since_epoch(now).abs_diff(capture.nanos_since_epoch(now));
+    /// assert!(offset < 1_000_000);
+    /// assert!(render.nanos_since_epoch(now) >= 1_000_000_000);
+    /// ```
+    pub fn epoch_at(mut self, epoch: SystemTime) -> Self {
+        let Some(instant) = instant_at(epoch) else {
+            return self;
+        };
+        let instant = instant.min(self.previous);
+        self.epoch = instant;
+        self.wall_epoch = wall_clock_at(instant);
+        self.restart_schedule();
+        self
+    }
+
+    /// Wall-clock time of [`Self::timebase_epoch`], as sampled when the
+    /// timer was created or set with [`Self::epoch_at`].
+    pub fn wall_clock_epoch(&self) -> SystemTime {
+        self.wall_epoch
+    }
+
+    /// Converts `instant`, e.g. a [`crate::FrameInfo::timestamp`], to
+    /// wall-clock time relative to [`Self::wall_clock_epoch`].
+    ///
+    /// Unlike reading [`SystemTime::now`] per frame, the conversion is
+    /// unaffected by later adjustments of the wall clock, so converted
+    /// timestamps keep the spacing of the frames.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::time::{Duration, SystemTime};
+    /// use fps_timer::Timer;
+    ///
+    /// let mut timer = Timer::default().fps(100.);
+    /// timer.frame();
+    /// let wall = timer.to_system_time(timer.frame_info().timestamp());
+    /// let error = match SystemTime::now().duration_since(wall) {
+    ///     Ok(behind) => behind,
+    ///     Err(ahead) => ahead.duration(),
+    /// };
+    /// assert!(error < Duration::from_millis(100));
+    /// ```
+    pub fn to_system_time(&self, instant: Instant) -> SystemTime {
+        match instant.checked_duration_since(self.epoch) {
+            Some(since) => self.wall_epoch + since,
+            None => self.wall_epoch - (self.epoch - instant),
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    io, process,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use crate::Timer;
+
+/// minimum time between two checks of the watchdog thread, in nanoseconds
+const MIN_POLL: u64 = 1_000_000;
+
+type StallHook = Box<dyn FnMut(Duration) + Send + Sync>;
+
+/// state shared between a [`Timer`] and its watchdog thread
+struct Shared {
+    /// reference instant of `last_frame`
+    base: Instant,
+    /// end of the most recent frame in nanoseconds since `base`
+    last_frame: AtomicU64,
+    /// time without a frame after which the watchdog fires, in nanoseconds
+    timeout: AtomicU64,
+    /// set when the timer is dropped
+    stop: AtomicBool,
+}
+
+impl Shared {
+    fn nanos(&self, instant: Instant) -> u64 {
+        let nanos = instant.saturating_duration_since(self.base).as_nanos();
+        u64::try_from(nanos).unwrap_or(u64::MAX)
+    }
+}
+
+/// watchdog thread of a [`Timer`], stopped when dropped
+pub(crate) struct Watchdog {
+    shared: Arc<Shared>,
+    /// multiple of the frametime without a frame that counts as stall
+    multiple: u32,
+    /// minimum time without a frame that counts as stall
+    min_timeout: Duration,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Watchdog {
+    fn spawn(multiple: u32, min_timeout: Duration, mut on_stall: StallHook) -> io::Result<Self> {
+        let base = Instant::now();
+        let timeout = u64::try_from(min_timeout.as_nanos()).unwrap_or(u64::MAX);
+        let shared = Arc::new(Shared {
+            base,
+            last_frame: AtomicU64::new(0),
+            timeout: AtomicU64::new(timeout),
+            stop: AtomicBool::new(false),
+        });
+        let watched = shared.clone();
+        let thread = thread::Builder::new()
+            .name("fps-timer-watchdog".into())
+            .spawn(move || {
+                // frame the watchdog last fired for
+                let mut fired = None;
+                while !watched.stop.load(Ordering::Relaxed) {
+                    let last = watched.last_frame.load(Ordering::Relaxed);
+                    let timeout = watched.timeout.load(Ordering::Relaxed);
+                    let deadline = last.saturating_add(timeout);
+                    let now = watched.nanos(Instant::now());
+                    if now < deadline || fired == Some(last) {
+                        // check again at the deadline or, after firing,
+                        // poll for the next frame
+                        let wait = match fired == Some(last) {
+                            true => timeout,
+                            false => deadline - now,
+                        };
+                        thread::park_timeout(Duration::from_nanos(wait.max(MIN_POLL)));
+                        continue;
+                    }
+                    fired = Some(last);
+                    on_stall(Duration::from_nanos(now - last));
+                }
+            })?;
+        Ok(Self {
+            shared,
+            multiple,
+            min_timeout,
+            thread: Some(thread),
+        })
+    }
+
+    /// records the end of a frame and the current frametime
+    pub(crate) fn feed(&self, frame_end: Instant, frame_time: Duration) {
+        let timeout = frame_time
+            .saturating_mul(self.multiple)
+            .max(self.min_timeout);
+        let timeout = u64::try_from(timeout.as_nanos()).unwrap_or(u64::MAX);
+        self.shared.timeout.store(timeout, Ordering::Relaxed);
+        let last = self.shared.nanos(frame_end);
+        self.shared.last_frame.store(last, Ordering::Relaxed);
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            thread.thread().unpark();
+            let _ = thread.join();
+        }
+    }
+}
+
+impl Timer {
+    /// Starts a watchdog thread calling `on_stall` if no frame completes
+    /// within `multiple` times the frametime, to catch deadlocks and
+    /// runaway frames in unattended deployments.
+    ///
+    /// `on_stall` runs on the watchdog thread and receives the time since
+    /// the last frame. It is called once per stall, i.e. again only after
+    /// another frame completed. For uncapped timers, the average
+    /// frametime is used. The timeout is at least `min_timeout`, so
+    /// uncapped timers running short frames (or a `multiple` of 0) do
+    /// not report a stall every frame. The thread stops when the timer
+    /// is dropped.
+    ///
+    /// # Arguments
+    /// * `multiple` - frametimes without a frame that count as a stall
+    /// * `min_timeout` - minimum time without a frame that counts as a
+    ///   stall, e.g. 100ms, or zero for a timeout of exactly `multiple`
+    ///   frametimes
+    /// * `on_stall` - called with the time since the last frame
+    ///
+    /// # Returns
+    /// [`Self`] the (modified) timer, or the error spawning the
+    /// watchdog thread
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::{sync::mpsc, thread, time::Duration};
+    /// use fps_timer::Timer;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut timer = Timer::default()
+    ///     .fps(1000.)
+    ///     .watchdog(5, Duration::ZERO, move |stalled| {
+    ///         let _ = tx.send(stalled);
+    ///     })
+    ///     .unwrap();
+    /// timer.frame();
+    /// // a frame taking far longer than 5 frametimes
+    /// let stalled = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+    /// assert!(stalled >= Duration::from_millis(5));
+    ///
+    /// // uncapped frames taking 1ms are not stalls
+    /// let (tx, rx) = mpsc::channel();
+    /// let mut timer = Timer::default()
+    ///     .fps(0.)
+    ///     .watchdog(5, Duration::from_millis(100), move |stalled| {
+    ///         let _ = tx.send(stalled);
+    ///     })
+    ///     .unwrap();
+    /// for _ in 0..50 {
+    ///     timer.frame();
+    ///     thread::sleep(Duration::from_millis(1));
+    /// }
+    /// assert!(rx.try_recv().is_err());
+    /// ```
+    pub fn watchdog(
+        mut self,
+        multiple: u32,
+        min_timeout: Duration,
+        on_stall: impl FnMut(Duration) + Send + Sync + 'static,
+    ) -> io::Result<Self> {
+        let watchdog = Watchdog::spawn(multiple, min_timeout, Box::new(on_stall))?;
+        watchdog.feed(self.previous, self.watchdog_base());
+        self.watchdog = Some(watchdog);
+        Ok(self)
+    }
+
+    /// Same as [`Self::watchdog`], aborting the process on a stall,
+    /// e.g. to have a supervisor restart it.
+    pub fn watchdog_abort(self, multiple: u32, min_timeout: Duration) -> io::Result<Self> {
+        self.watchdog(multiple, min_timeout, |stalled| {
+            eprintln!("fps-timer watchdog: no frame for {stalled:?}, aborting");
+            process::abort();
+        })
+    }
+
+    /// frametime the watchdog timeout is a multiple of
+    fn watchdog_base(&self) -> Duration {
+        self.delta_time.max(self.work_avg)
+    }
+
+    /// resets the watchdog at the end of a frame
+    pub(crate) fn feed_watchdog(&self) {
+        if let Some(watchdog) = &self.watchdog {
+            watchdog.feed(self.previous, self.watchdog_base());
+        }
+    }
+}
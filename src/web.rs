@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+use wasm_bindgen::prelude::wasm_bindgen;
+
+use crate::{
+    pacing_core::{FrameTimeStats, Schedule},
+    DEFAULT_LOG_INTERVAL,
+};
+
+/// maximum number of frametimes kept per logging interval,
+/// in case [`WebTimer::log`] is never called
+const MAX_LOGGED_FRAMES: usize = 1 << 16;
+
+/// Timer driven by JavaScript timestamps, exported with wasm-bindgen.
+///
+/// A page can not block its thread, so instead of waiting inside
+/// [`Self::frame`], the JavaScript side asks for the remaining delay
+/// with [`Self::delay_ms`], awaits it (e.g. with `setTimeout`) and
+/// then completes the frame with [`Self::frame`]. All timestamps are
+/// milliseconds as returned by `performance.now()`, which is the only
+/// clock used: targets come from a [`Schedule`] and no
+/// [`std::time::Instant`] is ever read, as `Instant::now` panics on
+/// `wasm32-unknown-unknown`.
+///
+/// The TypeScript wrapper in `bindings/web/fps_timer.ts` turns this
+/// into a `Timer` whose `frame()` returns a `Promise`. It expects the
+/// generated bindings in `bindings/web/pkg`, built with
+/// `cargo rustc --lib --release --target wasm32-unknown-unknown --features wasm --crate-type cdylib`
+/// and `wasm-bindgen --target web --out-dir bindings/web/pkg target/wasm32-unknown-unknown/release/fps_timer.wasm`.
+///
+/// Requires the `wasm` feature.
+///
+/// # Example
+/// ```rust
+/// use fps_timer::WebTimer;
+///
+/// // performance.now() when the timer was created
+/// let mut timer = WebTimer::new(50., 1000.);
+/// assert_eq!(timer.delay_ms(1005.), 15.);
+/// // the frame completes after awaiting the delay
+/// assert_eq!(timer.frame(1020.), 20.);
+/// assert_eq!(timer.delay_ms(1030.), 10.);
+/// ```
+#[wasm_bindgen]
+pub struct WebTimer {
+    /// frame targets in nanoseconds since `origin_ms`
+    schedule: Schedule,
+    /// timestamp the schedule is anchored at
+    origin_ms: f64,
+    /// timestamp of the next frame target
+    target_ms: f64,
+    /// timestamp of the previous frame
+    previous_ms: f64,
+    /// maximum amount of frames to lag behind
+    max_delay_frames: u32,
+    /// frametimes of the logging interval
+    frame_times: Vec<Duration>,
+    /// timestamp the logging interval started at
+    log_start_ms: f64,
+    /// duration of a logging interval
+    log_interval_ms: f64,
+}
+
+#[wasm_bindgen]
+impl WebTimer {
+    /// Creates a timer with a framerate target of `fps`,
+    /// `now_ms` being the current time.
+    #[wasm_bindgen(constructor)]
+    pub fn new(fps: f64, now_ms: f64) -> Self {
+        let mut schedule = Schedule::from_fps(fps);
+        let target = schedule.start(0);
+        Self {
+            schedule,
+            origin_ms: now_ms,
+            target_ms: now_ms + nanos_to_ms(target),
+            previous_ms: now_ms,
+            max_delay_frames: 2,
+            frame_times: Vec::new(),
+            log_start_ms: now_ms,
+            log_interval_ms: DEFAULT_LOG_INTERVAL.as_secs_f64() * 1000.,
+        }
+    }
+
+    /// milliseconds left at `now_ms` until the next frame is due
+    pub fn delay_ms(&self, now_ms: f64) -> f64 {
+        (self.target_ms - now_ms).max(0.)
+    }
+
+    /// Completes the frame at `now_ms` and returns the frametime
+    /// in milliseconds. Frames completed before their target are not
+    /// delayed, await [`Self::delay_ms`] first.
+    pub fn frame(&mut self, now_ms: f64) -> f64 {
+        // re-base the schedule if we lag behind too much
+        let slack = self.schedule.frame_time() * self.max_delay_frames;
+        if now_ms - self.target_ms > slack.as_secs_f64() * 1000. {
+            self.origin_ms = now_ms;
+            self.schedule.restart(0);
+        }
+        let target = self.schedule.advance();
+        self.target_ms = self.origin_ms + nanos_to_ms(target);
+
+        let frame_time = (now_ms - self.previous_ms).max(0.);
+        self.previous_ms = self.previous_ms.max(now_ms);
+        if self.frame_times.len() < MAX_LOGGED_FRAMES {
+            let duration = Duration::try_from_secs_f64(frame_time / 1000.);
+            self.frame_times.push(duration.unwrap_or_default());
+        }
+        frame_time
+    }
+
+    /// Sets the framerate target to `fps`, see [`crate::Timer::fps`].
+    /// The frame that is already due keeps its target.
+    pub fn set_fps(&mut self, fps: f64) {
+        self.schedule = Schedule::from_fps(fps);
+        self.origin_ms = self.target_ms;
+    }
+
+    /// Sets the duration of a logging interval in milliseconds,
+    /// see [`crate::Timer::log_interval`]. Negative and NaN durations
+    /// end an interval on every call to [`Self::log`].
+    ///
+    /// Defaults to 1 second
+    pub fn set_log_interval_ms(&mut self, interval_ms: f64) {
+        self.log_interval_ms = interval_ms.max(0.);
+    }
+
+    /// Statistics of the logging interval if it has ended at `now_ms`,
+    /// as a line of `key=value` pairs with durations in nanoseconds,
+    /// like [`crate::Log`].
+    ///
+    /// # Example
+    /// ```rust
+    /// use fps_timer::WebTimer;
+    ///
+    /// let mut timer = WebTimer::new(100., 0.);
+    /// for frame in 1..=10 {
+    ///     timer.frame(frame as f64 * 10.);
+    /// }
+    /// assert_eq!(
+    ///     timer.log(100.).unwrap(),
+    ///     "frames=10 avg=10000000 min=10000000 median=10000000 p99=10000000 max=10000000 jitter=0 hitches=0",
+    /// );
+    /// assert_eq!(timer.log(150.), None);
+    ///
+    /// timer.set_log_interval_ms(20.);
+    /// timer.frame(160.);
+    /// assert!(timer.log(160.).unwrap().starts_with("frames=1 "));
+    /// timer.frame(170.);
+    /// assert_eq!(timer.log(170.), None);
+    /// timer.frame(180.);
+    /// assert!(timer.log(180.).unwrap().starts_with("frames=2 "));
+    /// ```
+    pub fn log(&mut self, now_ms: f64) -> Option<String> {
+        if now_ms - self.log_start_ms < self.log_interval_ms || self.frame_times.is_empty() {
+            return None;
+        }
+        self.log_start_ms = now_ms;
+        let frame_times = &mut self.frame_times;
+        let avg = frame_times.iter().sum::<Duration>() / frame_times.len() as u32;
+        let hitch_threshold = self.schedule.frame_time().max(avg).saturating_mul(2);
+        let stats = FrameTimeStats::from_frame_times(frame_times, hitch_threshold);
+        frame_times.clear();
+        Some(format!(
+            "frames={} avg={} min={} median={} p99={} max={} jitter={} hitches={}",
+            stats.frames(),
+            avg.as_nanos(),
+            stats.min().as_nanos(),
+            stats.median().as_nanos(),
+            stats.p99().as_nanos(),
+            stats.max().as_nanos(),
+            stats.jitter().as_nanos(),
+            stats.hitches(),
+        ))
+    }
+}
+
+/// converts nanoseconds since the origin of a schedule to milliseconds
+fn nanos_to_ms(nanos: u128) -> f64 {
+    nanos as f64 / 1e6
+}